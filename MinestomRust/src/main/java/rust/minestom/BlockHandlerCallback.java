@@ -0,0 +1,73 @@
+package rust.minestom;
+
+import net.kyori.adventure.key.Key;
+import net.minestom.server.instance.block.BlockHandler;
+import org.jetbrains.annotations.NotNull;
+
+import java.util.function.Supplier;
+
+/**
+ * Generic callback wrapper for Rust implementations of BlockHandler.
+ * Also acts as its own supplier so it can be registered in the BlockManager.
+ */
+public class BlockHandlerCallback implements BlockHandler, Supplier<BlockHandler> {
+    private final long callbackId;
+    private final Key key;
+    private final boolean tickable;
+
+    /** Called from Rust to create a new BlockHandler instance */
+    public BlockHandlerCallback(long callbackId, @NotNull String key, boolean tickable) {
+        this.callbackId = callbackId;
+        this.key = Key.key(key);
+        this.tickable = tickable;
+        // Frees the Rust handler once no block uses it anymore
+        NativeCleaner.CLEANER.register(this, () -> nativeFree(callbackId));
+    }
+
+    private static native void nativeOnPlace(long callbackId, @NotNull Placement placement);
+    private static native void nativeOnDestroy(long callbackId, @NotNull Destroy destroy);
+    private static native boolean nativeOnInteract(long callbackId, @NotNull Interaction interaction);
+    private static native void nativeOnTouch(long callbackId, @NotNull Touch touch);
+    private static native void nativeTick(long callbackId, @NotNull Tick tick);
+    private static native void nativeFree(long callbackId);
+
+    @Override
+    public void onPlace(@NotNull Placement placement) {
+        nativeOnPlace(callbackId, placement);
+    }
+
+    @Override
+    public void onDestroy(@NotNull Destroy destroy) {
+        nativeOnDestroy(callbackId, destroy);
+    }
+
+    @Override
+    public boolean onInteract(@NotNull Interaction interaction) {
+        return nativeOnInteract(callbackId, interaction);
+    }
+
+    @Override
+    public void onTouch(@NotNull Touch touch) {
+        nativeOnTouch(callbackId, touch);
+    }
+
+    @Override
+    public void tick(@NotNull Tick tick) {
+        nativeTick(callbackId, tick);
+    }
+
+    @Override
+    public boolean isTickable() {
+        return tickable;
+    }
+
+    @Override
+    public @NotNull Key getKey() {
+        return key;
+    }
+
+    @Override
+    public BlockHandler get() {
+        return this;
+    }
+}
@@ -0,0 +1,80 @@
+package rust.minestom;
+
+import net.minestom.server.coordinate.Pos;
+import net.minestom.server.coordinate.Vec;
+import net.minestom.server.entity.Entity;
+import net.minestom.server.entity.EntityType;
+import net.minestom.server.entity.Player;
+import net.minestom.server.network.packet.server.SendablePacket;
+import net.minestom.server.network.packet.server.play.DestroyEntitiesPacket;
+import net.minestom.server.network.packet.server.play.EntityTeleportPacket;
+import net.minestom.server.network.packet.server.play.SpawnEntityPacket;
+import org.jetbrains.annotations.NotNull;
+
+import java.util.Set;
+import java.util.concurrent.ConcurrentHashMap;
+
+/**
+ * Entity which is never added to an instance, and only exists as packets
+ * sent to the players it is shown to. Its metadata is kept in the entity meta.
+ */
+public class ClientSideEntity extends Entity {
+    private final Set<Player> shownTo = ConcurrentHashMap.newKeySet();
+    private volatile Pos clientPosition = Pos.ZERO;
+
+    /** Called from Rust to create a new client-side entity */
+    public ClientSideEntity(@NotNull EntityType type) {
+        super(type);
+    }
+
+    public boolean show(@NotNull Player player) {
+        if (!shownTo.add(player)) {
+            return false;
+        }
+        final Pos pos = clientPosition;
+        player.sendPacket(new SpawnEntityPacket(getEntityId(), getUuid(), getEntityType().id(),
+                pos, pos.yaw(), 0, (short) 0, (short) 0, (short) 0));
+        player.sendPacket(getMetadataPacket());
+        return true;
+    }
+
+    public boolean hide(@NotNull Player player) {
+        if (!shownTo.remove(player)) {
+            return false;
+        }
+        player.sendPacket(new DestroyEntitiesPacket(getEntityId()));
+        return true;
+    }
+
+    public void hideAll() {
+        for (Player player : Set.copyOf(shownTo)) {
+            hide(player);
+        }
+    }
+
+    public @NotNull Set<Player> getShownTo() {
+        return Set.copyOf(shownTo);
+    }
+
+    public @NotNull Pos getClientPosition() {
+        return clientPosition;
+    }
+
+    public void moveTo(@NotNull Pos position) {
+        clientPosition = position;
+        sendToShown(new EntityTeleportPacket(getEntityId(), position, Vec.ZERO, 0, false));
+    }
+
+    /** Sends the current entity meta, after it was changed */
+    public void refreshMetadata() {
+        sendToShown(getMetadataPacket());
+    }
+
+    private void sendToShown(@NotNull SendablePacket packet) {
+        // Players who left are forgotten
+        shownTo.removeIf(player -> !player.isOnline());
+        for (Player player : shownTo) {
+            player.sendPacket(packet);
+        }
+    }
+}
@@ -4,11 +4,9 @@ import net.minestom.server.coordinate.Point;
 import net.minestom.server.entity.EntityCreature;
 import net.minestom.server.entity.EntityType;
 import net.minestom.server.entity.Player;
-import net.minestom.server.entity.damage.DamageType;
-import net.minestom.server.entity.damage.EntityDamage;
+import net.minestom.server.entity.damage.Damage;
 import net.minestom.server.instance.Instance;
 import net.minestom.server.coordinate.Pos;
-import net.minestom.server.registry.DynamicRegistry;
 import org.jetbrains.annotations.NotNull;
 import org.jetbrains.annotations.Nullable;
 
@@ -28,9 +26,7 @@ public class EntityCreatureCallback extends EntityCreature {
     private static native void nativeUpdateNewViewer(long callbackId, @NotNull Player player);
     private static native void nativeUpdateOldViewer(long callbackId, @NotNull Player player);
     private static native void nativeTick(long callbackId, long time);
-    private static native boolean nativeDamage(long callbackId,
-                                               @NotNull DynamicRegistry.Key<DamageType> type,
-                                               float amount);
+    private static native boolean nativeDamage(long callbackId, @NotNull Damage damage);
     private static native void nativeRemove(long callbackId);
 
     @Override
@@ -52,16 +48,14 @@ public class EntityCreatureCallback extends EntityCreature {
         super.tick(time);
     }
 
-    /*@Override // TODO
-    public boolean damage(@NotNull DynamicRegistry.Key<DamageType> type, float amount) {
-        // Let Rust decide if it wants to cancel or augment damage
-        boolean rustResult = nativeDamage(callbackId, type, amount);
-        // Always pass through to the regular damage logic (unless Rust returned false to indicate “cancel”)
-        if (!rustResult) {
+    @Override
+    public boolean damage(@NotNull Damage damage) {
+        // Let Rust cancel the damage or change its amount before the regular damage logic
+        if (!nativeDamage(callbackId, damage)) {
             return false;
         }
-        return super.damage(type, amount);
-    }*/
+        return super.damage(damage);
+    }
 
     @Override
     public void remove() {
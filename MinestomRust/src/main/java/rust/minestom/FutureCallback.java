@@ -0,0 +1,22 @@
+package rust.minestom;
+
+import java.util.function.BiConsumer;
+
+/**
+ * Notifies Rust of the completion of a CompletableFuture.
+ */
+public class FutureCallback implements BiConsumer<Object, Throwable> {
+    private final long callbackId;
+
+    /** Called from Rust before registering the callback with whenComplete */
+    public FutureCallback(long callbackId) {
+        this.callbackId = callbackId;
+    }
+
+    private static native void nativeComplete(long callbackId, Object result, Throwable error);
+
+    @Override
+    public void accept(Object result, Throwable error) {
+        nativeComplete(callbackId, result, error);
+    }
+}
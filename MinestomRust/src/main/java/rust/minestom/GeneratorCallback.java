@@ -0,0 +1,27 @@
+package rust.minestom;
+
+import net.minestom.server.instance.generator.GenerationUnit;
+import net.minestom.server.instance.generator.Generator;
+import org.jetbrains.annotations.NotNull;
+
+/**
+ * Generic callback wrapper for Rust implementations of Generator.
+ */
+public class GeneratorCallback implements Generator {
+    private final long callbackId;
+
+    /** Called from Rust to create a new Generator instance */
+    public GeneratorCallback(long callbackId) {
+        this.callbackId = callbackId;
+        // Frees the Rust generator once no instance uses it anymore
+        NativeCleaner.CLEANER.register(this, () -> nativeFree(callbackId));
+    }
+
+    private static native void nativeGenerate(long callbackId, @NotNull GenerationUnit unit);
+    private static native void nativeFree(long callbackId);
+
+    @Override
+    public void generate(@NotNull GenerationUnit unit) {
+        nativeGenerate(callbackId, unit);
+    }
+}
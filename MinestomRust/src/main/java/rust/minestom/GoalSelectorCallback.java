@@ -0,0 +1,53 @@
+package rust.minestom;
+
+import net.minestom.server.entity.EntityCreature;
+import net.minestom.server.entity.ai.GoalSelector;
+import org.jetbrains.annotations.NotNull;
+
+/**
+ * Generic callback wrapper for Rust implementations of GoalSelector.
+ */
+public class GoalSelectorCallback extends GoalSelector {
+    private final long callbackId;
+
+    /** Called from Rust to create a new GoalSelector instance */
+    public GoalSelectorCallback(long callbackId, @NotNull EntityCreature entityCreature) {
+        super(entityCreature);
+        this.callbackId = callbackId;
+        // Captures the id only, capturing `this` would keep the goal alive
+        NativeCleaner.CLEANER.register(this, () -> nativeFree(callbackId));
+    }
+
+    private static native void nativeFree(long callbackId);
+
+    private static native boolean nativeShouldStart(long callbackId, @NotNull EntityCreature creature);
+    private static native void nativeStart(long callbackId, @NotNull EntityCreature creature);
+    private static native void nativeTick(long callbackId, @NotNull EntityCreature creature, long time);
+    private static native boolean nativeShouldEnd(long callbackId, @NotNull EntityCreature creature);
+    private static native void nativeEnd(long callbackId, @NotNull EntityCreature creature);
+
+    @Override
+    public boolean shouldStart() {
+        return nativeShouldStart(callbackId, getEntityCreature());
+    }
+
+    @Override
+    public void start() {
+        nativeStart(callbackId, getEntityCreature());
+    }
+
+    @Override
+    public void tick(long time) {
+        nativeTick(callbackId, getEntityCreature(), time);
+    }
+
+    @Override
+    public boolean shouldEnd() {
+        return nativeShouldEnd(callbackId, getEntityCreature());
+    }
+
+    @Override
+    public void end() {
+        nativeEnd(callbackId, getEntityCreature());
+    }
+}
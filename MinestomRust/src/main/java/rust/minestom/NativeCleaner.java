@@ -0,0 +1,14 @@
+package rust.minestom;
+
+import java.lang.ref.Cleaner;
+
+/**
+ * Frees the Rust closures of callback objects once Java no longer references them,
+ * e.g. when the creature owning a goal is removed and collected.
+ */
+public final class NativeCleaner {
+    public static final Cleaner CLEANER = Cleaner.create();
+
+    private NativeCleaner() {
+    }
+}
@@ -0,0 +1,111 @@
+package rust.minestom;
+
+import net.kyori.adventure.text.Component;
+import net.minestom.server.coordinate.Pos;
+import net.minestom.server.entity.EntityCreature;
+import net.minestom.server.entity.EntityType;
+import net.minestom.server.entity.GameMode;
+import net.minestom.server.entity.Player;
+import net.minestom.server.entity.PlayerSkin;
+import net.minestom.server.entity.metadata.PlayerMeta;
+import net.minestom.server.network.packet.server.play.EntityHeadLookPacket;
+import net.minestom.server.network.packet.server.play.EntityRotationPacket;
+import net.minestom.server.network.packet.server.play.PlayerInfoRemovePacket;
+import net.minestom.server.network.packet.server.play.PlayerInfoUpdatePacket;
+import org.jetbrains.annotations.NotNull;
+import org.jetbrains.annotations.Nullable;
+
+import java.util.EnumSet;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * Player-type creature used by Rust NPCs.
+ * Sends the player profile to viewers, and turns its head towards each of them.
+ */
+public class NpcEntity extends EntityCreature {
+    private final String username;
+    private @Nullable PlayerSkin skin;
+    private @Nullable Component displayName;
+    private boolean listed;
+    private double lookRange;
+
+    /** Called from Rust to create a new NPC */
+    public NpcEntity(@NotNull String username, @Nullable PlayerSkin skin) {
+        super(EntityType.PLAYER, UUID.randomUUID());
+        this.username = username;
+        this.skin = skin;
+        // Show every skin layer
+        ((PlayerMeta) getEntityMeta()).setDisplayedSkinParts((byte) 0x7F);
+    }
+
+    public void setSkin(@Nullable PlayerSkin skin) {
+        this.skin = skin;
+        respawnForViewers();
+    }
+
+    public void setDisplayName(@Nullable Component displayName) {
+        this.displayName = displayName;
+        respawnForViewers();
+    }
+
+    public void setListed(boolean listed) {
+        this.listed = listed;
+        respawnForViewers();
+    }
+
+    /** Viewers closer than the range see the NPC looking at them, 0 disables it */
+    public void setLookRange(double lookRange) {
+        this.lookRange = lookRange;
+    }
+
+    @Override
+    public void updateNewViewer(@NotNull Player player) {
+        // The client needs the profile before spawning a player entity
+        player.sendPacket(new PlayerInfoUpdatePacket(
+                EnumSet.of(PlayerInfoUpdatePacket.Action.ADD_PLAYER,
+                        PlayerInfoUpdatePacket.Action.UPDATE_LISTED,
+                        PlayerInfoUpdatePacket.Action.UPDATE_DISPLAY_NAME),
+                List.of(infoEntry())));
+        super.updateNewViewer(player);
+    }
+
+    @Override
+    public void updateOldViewer(@NotNull Player player) {
+        super.updateOldViewer(player);
+        player.sendPacket(new PlayerInfoRemovePacket(getUuid()));
+    }
+
+    @Override
+    public void update(long time) {
+        super.update(time);
+        if (lookRange <= 0 || getNavigator().getGoalPosition() != null) {
+            return;
+        }
+        final Pos eyes = getPosition().add(0, getEyeHeight(), 0);
+        for (Player viewer : getViewers()) {
+            final Pos target = viewer.getPosition().add(0, viewer.getEyeHeight(), 0);
+            if (eyes.distanceSquared(target) > lookRange * lookRange) {
+                continue;
+            }
+            final Pos look = eyes.withLookAt(target);
+            viewer.sendPacket(new EntityHeadLookPacket(getEntityId(), look.yaw()));
+            viewer.sendPacket(new EntityRotationPacket(getEntityId(), look.yaw(), look.pitch(), isOnGround()));
+        }
+    }
+
+    private PlayerInfoUpdatePacket.Entry infoEntry() {
+        final List<PlayerInfoUpdatePacket.Property> properties = skin == null
+                ? List.of()
+                : List.of(new PlayerInfoUpdatePacket.Property("textures", skin.textures(), skin.signature()));
+        return new PlayerInfoUpdatePacket.Entry(getUuid(), username, properties, listed, 0,
+                GameMode.SURVIVAL, displayName, null, 0);
+    }
+
+    private void respawnForViewers() {
+        for (Player viewer : getViewers()) {
+            updateOldViewer(viewer);
+            updateNewViewer(viewer);
+        }
+    }
+}
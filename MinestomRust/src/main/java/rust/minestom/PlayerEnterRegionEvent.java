@@ -0,0 +1,28 @@
+package rust.minestom;
+
+import net.minestom.server.entity.Player;
+import net.minestom.server.event.trait.PlayerInstanceEvent;
+import org.jetbrains.annotations.NotNull;
+
+/**
+ * Called from Rust when a player enters a region tracked by a RegionManager.
+ */
+public class PlayerEnterRegionEvent implements PlayerInstanceEvent {
+    private final Player player;
+    private final String region;
+
+    public PlayerEnterRegionEvent(@NotNull Player player, @NotNull String region) {
+        this.player = player;
+        this.region = region;
+    }
+
+    @Override
+    public @NotNull Player getPlayer() {
+        return player;
+    }
+
+    /** Name of the region */
+    public @NotNull String getRegion() {
+        return region;
+    }
+}
@@ -8,6 +8,8 @@ public class PredicateCallback implements Predicate<Player> {
 
     public PredicateCallback(long callbackId) {
         this.callbackId = callbackId;
+        // Frees the closure once the predicate is no longer used, e.g. when the entity is removed
+        NativeCleaner.CLEANER.register(this, () -> nativeFree(callbackId));
     }
 
     @Override
@@ -16,4 +18,5 @@ public class PredicateCallback implements Predicate<Player> {
     }
 
     private native boolean testPlayer(long callbackId, Player player);
+    private static native void nativeFree(long callbackId);
 } 
\ No newline at end of file
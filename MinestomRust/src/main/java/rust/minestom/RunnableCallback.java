@@ -0,0 +1,23 @@
+package rust.minestom;
+
+/**
+ * Runs a one-shot Rust closure, e.g. when a creature reaches its destination.
+ */
+public class RunnableCallback implements Runnable {
+    private final long callbackId;
+
+    /** Called from Rust with the id of the closure to run */
+    public RunnableCallback(long callbackId) {
+        this.callbackId = callbackId;
+        // Frees the closure if it never runs, e.g. when the path is replaced
+        NativeCleaner.CLEANER.register(this, () -> nativeFree(callbackId));
+    }
+
+    private static native void nativeRun(long callbackId);
+    private static native void nativeFree(long callbackId);
+
+    @Override
+    public void run() {
+        nativeRun(callbackId);
+    }
+}
@@ -0,0 +1,26 @@
+package rust.minestom;
+
+import net.minestom.server.timer.TaskSchedule;
+
+import java.util.function.Supplier;
+
+/**
+ * Task running a Rust step function, which returns the number of ticks
+ * until it should run again, or a negative number to stop.
+ */
+public class SteppedTaskCallback implements Supplier<TaskSchedule> {
+    private final long callbackId;
+
+    /** Called from Rust with the id of the step function */
+    public SteppedTaskCallback(long callbackId) {
+        this.callbackId = callbackId;
+    }
+
+    private static native int nativeStep(long callbackId);
+
+    @Override
+    public TaskSchedule get() {
+        int ticks = nativeStep(callbackId);
+        return ticks < 0 ? TaskSchedule.stop() : TaskSchedule.tick(Math.max(ticks, 1));
+    }
+}
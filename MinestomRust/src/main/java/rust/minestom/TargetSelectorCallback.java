@@ -0,0 +1,31 @@
+package rust.minestom;
+
+import net.minestom.server.entity.Entity;
+import net.minestom.server.entity.EntityCreature;
+import net.minestom.server.entity.ai.TargetSelector;
+import org.jetbrains.annotations.NotNull;
+import org.jetbrains.annotations.Nullable;
+
+/**
+ * Generic callback wrapper for Rust implementations of TargetSelector.
+ */
+public class TargetSelectorCallback extends TargetSelector {
+    private final long callbackId;
+
+    /** Called from Rust to create a new TargetSelector instance */
+    public TargetSelectorCallback(long callbackId, @NotNull EntityCreature entityCreature) {
+        super(entityCreature);
+        this.callbackId = callbackId;
+        // Captures the id only, capturing `this` would keep the selector alive
+        NativeCleaner.CLEANER.register(this, () -> nativeFree(callbackId));
+    }
+
+    private static native void nativeFree(long callbackId);
+
+    private static native @Nullable Entity nativeFindTarget(long callbackId, @NotNull EntityCreature creature);
+
+    @Override
+    public @Nullable Entity findTarget() {
+        return nativeFindTarget(callbackId, getEntityCreature());
+    }
+}
@@ -1,30 +1,17 @@
 use minestom::{
-    Player, PlayerEntityInteractEvent, Result, Source,
-    entity::{
-        display::ItemDisplay,
-        entity::{Entity, EntityType},
-    },
+    ClickableArea, EntityLike, Player, Position, Result, Source,
+    entity::display::{DisplayLike, InteractionClick, ItemDisplay},
+    event::player::Hand,
     instance::Instance,
     item::ItemStack,
     material::Material,
     particle::{ParticlePacket, ParticleType},
     sound::{Sound, SoundEvent},
 };
-use once_cell::sync::Lazy;
 use parking_lot::RwLock;
 use std::{collections::HashMap, sync::Arc};
 use uuid::Uuid;
 
-// ID so that multiple pianos can be spawned in the same instance
-static COUNTER: Lazy<RwLock<u32>> = Lazy::new(|| RwLock::new(0));
-
-fn read_and_increment_counter() -> u32 {
-    let mut counter = COUNTER.write();
-    let current = *counter;
-    *counter += 1;
-    current
-}
-
 pub fn spawn_piano(
     instance: &dyn Instance,
     players: Arc<RwLock<HashMap<Uuid, Player>>>,
@@ -33,7 +20,6 @@ pub fn spawn_piano(
     z: f64,
     yaw: f32,
 ) -> Result<()> {
-    let id = read_and_increment_counter();
     let length = 3.0;
     // The spawn point is 1/4 from the left.
     // The coordinates provided are of the left point
@@ -46,9 +32,8 @@ pub fn spawn_piano(
     let sin = (yaw as f64).to_radians().sin();
     let cos = (yaw as f64).to_radians().cos();
     let pitch = 0.0; // We only support yaw (multiple of 90)
-    let armor_width = 0.5;
+    let key_depth = 0.5;
     let tiles_length = 1.533333 * scale;
-    let max_depth_interaction = 1.9; // height on the armostand to consider it a click
     // TODO: this scaling does not work well for length>3
     // Direction towards the "playing player"
     // x += sin and z -= cos
@@ -70,80 +55,67 @@ pub fn spawn_piano(
         pitch,
     )?;
 
-    let tag_id = format!("tiles_section_{}", id);
-
-    // fill the area with armostands to cover all the tiles,
-    // sinc we cannot have a large hitbox
-    for i in 0..(tiles_length / armor_width).ceil() as i32 {
-        let armor_stand = Entity::new_from_type(EntityType::ArmorStand)?;
-        armor_stand.set_invisible(true)?;
-        armor_stand.set_no_gravity(true)?;
-        armor_stand.spawn(
-            instance,
-            x + (sin * offset1) - (cos * offset2) - (cos * armor_width * i as f64),
-            y - 1.0 + offset_y,
-            z - (cos * offset1) - (sin * offset2) - (sin * armor_width * i as f64),
-            yaw,
-            pitch,
-        )?;
-        let tag_handler = armor_stand.tag_handler()?;
-        tag_handler.set_tag(&tag_id, Some(&i.to_string()))?;
-    }
-
-    let event_node = instance.event_node()?;
-
-    event_node.listen(move |event: &PlayerEntityInteractEvent| {
-        let pos = event.get_interact_position()?;
-        if pos.y > max_depth_interaction {
-            let entity = event.get_target()?;
-            let tag_handler = entity.tag_handler()?;
-            if let Some(value) = tag_handler.get_tag(&tag_id)? {
-                if let Ok(armorstand_index) = value.parse::<i32>() {
-                    // From the segmented hitboxes we need to construct
-                    // the relative coordinate of the full tiles.
-                    let previous_sections = (armorstand_index as f64) * armor_width;
-                    // coordinate from left to right.
-                    let relative_section_coordinate = -cos * pos.x - sin * pos.z;
-                    // go from [-width/2, width/2] to [0, width]
-                    let abs_section_coordinate = relative_section_coordinate + armor_width * 0.5;
-                    let tile_coordinate = previous_sections + abs_section_coordinate;
-                    // now we need to normalize this value since the piano is scaled
-                    let normalized_tile_coordinate = tile_coordinate / tiles_length;
-                    // This goes from 0 to 1+ the extra depends on how well
-                    // the last armorstand fits but we can just discard it.
-                    if normalized_tile_coordinate < 1.1 {
-                        // ideally 1.0 but it's not that precise
-                        // There are 15 tiles of alternating width such as abababa
-                        // with the following sizes
-                        let tiles = 15;
-                        let a = 0.051333333 * scale;
-                        let b = 0.042666666 * scale;
-                        let res = find_tile_index(normalized_tile_coordinate, tiles, a, b);
+    // A thin area over the top of the tiles, where a click plays a note
+    let keys_height = 0.075;
+    let keys_bottom = y + offset_y + 0.9;
+    // The tiles start half a key depth before offset2, from left to right
+    let tiles_start = offset2 - key_depth * 0.5;
+    let tiles_center = tiles_start + tiles_length * 0.5;
 
-                        if let Some((tile_index, tile_middle_point)) = res {
-                            // let avg = (a + b) * 0.5; // it doesn't need to be precise.
-                            // where to spawn the note + sound source (middle point of the tile)
-                            // we need to de-normalized
-                            let denormalized_middle_point = tile_middle_point * tiles_length;
-                            let note_offset =
-                                offset2 - armor_width * 0.5 + denormalized_middle_point;
-                            let offset1 = scale * 0.1; // closer to the player
-                            let source_x = x + (sin * offset1) - (cos * note_offset);
-                            let source_y = y - offset_y + 2.0;
-                            let source_z = z - (cos * offset1) - (sin * note_offset);
+    // Interaction hitboxes are square, the depth is filtered when clicked
+    let area = ClickableArea::new(tiles_length as f32, keys_height as f32)?;
+    area.set_instance(
+        instance,
+        &Position::new(
+            x + (sin * offset1) - (cos * tiles_center),
+            keys_bottom,
+            z - (cos * offset1) - (sin * tiles_center),
+        )
+        .to_pos()?,
+    )?;
 
-                            play_tile(
-                                players.clone(),
-                                tile_index,
-                                tiles,
-                                source_x,
-                                source_y,
-                                source_z,
-                            )?;
-                        }
-                    }
-                }
-            }
+    area.on_click(&instance.event_node()?, move |click| {
+        // only right clicks play notes, once per click
+        if click.button != InteractionClick::Right || !matches!(click.hand, Hand::Main) {
+            return Ok(());
+        }
+        let Some(pos) = &click.position else {
+            return Ok(());
+        };
+        // coordinate towards the player, only the front of the area covers the tiles
+        let depth = sin * pos.x - cos * pos.z;
+        if depth.abs() > key_depth * 0.5 {
+            return Ok(());
+        }
+        // coordinate from left to right, from [-length/2, length/2] to [0, length]
+        let tile_coordinate = -cos * pos.x - sin * pos.z + tiles_length * 0.5;
+        // now we need to normalize this value since the piano is scaled
+        let normalized_tile_coordinate = tile_coordinate / tiles_length;
+        // There are 15 tiles of alternating width such as abababa
+        // with the following sizes
+        let tiles = 15;
+        let a = 0.051333333 * scale;
+        let b = 0.042666666 * scale;
+        let res = find_tile_index(normalized_tile_coordinate, tiles, a, b);
+
+        if let Some((tile_index, tile_middle_point)) = res {
+            // where to spawn the note + sound source (middle point of the tile)
+            // we need to de-normalized
+            let denormalized_middle_point = tile_middle_point * tiles_length;
+            let note_offset = tiles_start + denormalized_middle_point;
+            let offset1 = scale * 0.1; // closer to the player
+            let source_x = x + (sin * offset1) - (cos * note_offset);
+            let source_y = y - offset_y + 2.0;
+            let source_z = z - (cos * offset1) - (sin * note_offset);
+
+            play_tile(
+                players.clone(),
+                tile_index,
+                tiles,
+                source_x,
+                source_y,
+                source_z,
+            )?;
         }
         Ok(())
     })?;
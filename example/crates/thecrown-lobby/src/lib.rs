@@ -5,7 +5,7 @@ pub mod maps;
 use commands::SpawnCommand;
 use log::info;
 use minestom::{
-    self, MinestomServer, Player,
+    self, LivingEntityLike, MinestomServer, Player,
     attribute::Attribute,
     component,
     entity::GameMode,
@@ -15,6 +15,7 @@ use minestom::{
             AsyncPlayerConfigurationEvent, PlayerChatEvent, PlayerDisconnectEvent, PlayerSpawnEvent,
         },
     },
+    instance::Instance,
     item::{InventoryHolder, ItemStack},
     material::Material,
 };
@@ -1,7 +1,12 @@
 use crate::magic_values::{SHRUNK_ACHIEVEMENT_SCALE, TITAN_ACHIEVEMENT_SCALE};
 use minestom::{
-    Attribute, BlockType, Player, PlayerMoveEvent, Pos, SharedInstance, entity::ItemDisplay,
-    event::player::PlayerSpawnEvent, item::ItemStack, material::Material,
+    Attribute, BlockType, LivingEntityLike, Player, PlayerEnterRegionEvent, PlayerMoveEvent, Pos,
+    Position, Region, RegionFlags, RegionManager, SharedInstance,
+    entity::{DisplayLike, Easing, ItemDisplay, Transform, Tween},
+    event::player::PlayerSpawnEvent,
+    instance::Instance,
+    item::ItemStack,
+    material::Material,
 };
 use parking_lot::RwLock;
 use rand::Rng;
@@ -13,6 +18,8 @@ use thecrown_models::{bulbasaur::BulbasaurMob, oldman::OldManModel};
 use uuid::Uuid;
 use world_seed_entity_engine::generic_model::create_wsee_model;
 
+const SHRUNKEN_REGION: &str = "shrunken";
+
 #[derive(Clone)]
 pub struct LobbyMap2 {
     pub instance: SharedInstance,
@@ -58,33 +65,40 @@ impl LobbyMap for LobbyMap2 {
 
                         // Check for achievement
                         if !player.is_achieved(advancements::TITANOMACHY)?
-                            && player.get_attribute(Attribute::Scale)?.base_value()?
+                            && player.get_attribute(Attribute::Scale)?.get_value()?
                                 >= TITAN_ACHIEVEMENT_SCALE
                         {
                             player.set_achieved(advancements::TITANOMACHY)?;
                         }
                     }
-
-                    // Check for achievement
-                    let (x1, y1, z1) = (1762.0, 26.5, 1177.0);
-                    let (x2, y2, z2) = (1764.0, 27.5, 1178.0);
-                    if pos.x >= x1
-                        && pos.x <= x2
-                        && pos.y >= y1
-                        && pos.y <= y2
-                        && pos.z >= z1
-                        && pos.z <= z2
-                        && !player.is_achieved(advancements::SHRUNKEN)?
-                        && player.get_attribute(Attribute::Scale)?.base_value()?
-                            <= SHRUNK_ACHIEVEMENT_SCALE
-                    {
-                        player.set_achieved(advancements::SHRUNKEN)?;
-                    }
                 }
             }
             Ok(())
         })?;
 
+        // Tiny hole only reachable when shrunk
+        let regions = RegionManager::new(&self.instance)?;
+        regions.add(
+            SHRUNKEN_REGION,
+            Region::cuboid(
+                Position::new(1762.0, 26.5, 1177.0),
+                Position::new(1764.0, 27.5, 1178.0),
+            ),
+            RegionFlags::default(),
+        );
+        event_node.listen(move |event: &PlayerEnterRegionEvent| {
+            if event.region()? != SHRUNKEN_REGION {
+                return Ok(());
+            }
+            let player = event.player()?;
+            if !player.is_achieved(advancements::SHRUNKEN)?
+                && player.get_attribute(Attribute::Scale)?.get_value()? <= SHRUNK_ACHIEVEMENT_SCALE
+            {
+                player.set_achieved(advancements::SHRUNKEN)?;
+            }
+            Ok(())
+        })?;
+
         // Old man model
         let model = OldManModel;
         let model = create_wsee_model(model)?;
@@ -282,6 +296,18 @@ impl LobbyMap for LobbyMap2 {
                 yaw + yaw_variation,
                 pitch,
             )?;
+            // Slowly float up and down, each cloud at its own pace
+            let period = rng.random_range(80..=140);
+            display.play(
+                Tween::new()
+                    .then(
+                        Transform::default().with_translation(0.0, 0.4, 0.0),
+                        period,
+                        Easing::Linear,
+                    )
+                    .then(Transform::default(), period, Easing::Linear)
+                    .looping(true),
+            )?;
         }
 
         Ok(())
@@ -9,7 +9,7 @@ use minestom::{
             PlayerSpawnEvent,
         },
     },
-    instance::InstanceContainer,
+    instance::{Instance, InstanceContainer},
     sound::{Sound, SoundEvent, Source},
 };
 use parking_lot::RwLock;
@@ -23,6 +23,9 @@ use thecrown_common::server::Server;
 use uuid::Uuid;
 
 const START_POS: (i32, i32, i32) = (0, 100, 0);
+/// Ticks a per-player instance may stay empty before it is unregistered,
+/// long enough for the player to finish configuration and spawn in it.
+const EMPTY_INSTANCE_TICKS: u64 = 20 * 60;
 const BLOCK_TYPES: &[BlockType] = &[
     BlockType::GrassBlock,
     BlockType::OakLog,
@@ -212,6 +215,10 @@ impl Server for ParkourServer {
 fn create_empty_instance(server: &MinestomServer) -> minestom::Result<InstanceContainer> {
     let instance_manager = server.instance_manager()?;
     let instance = instance_manager.create_instance_container()?;
+    // A leaked instance is better than refusing the player
+    if let Err(e) = instance_manager.unregister_when_empty(&instance, EMPTY_INSTANCE_TICKS) {
+        error!("Failed to schedule parkour instance cleanup: {}", e);
+    }
     Ok(instance)
 }
 
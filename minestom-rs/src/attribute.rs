@@ -1,5 +1,7 @@
 use crate::Result;
-use crate::jni_utils::{JavaObject, get_env};
+use crate::error::MinestomError;
+use crate::jni_utils::{JavaObject, JniValue, collection_to_vec, get_env};
+use jni::objects::{JString, JValue};
 
 #[derive(Debug, Clone, Copy)]
 pub enum Attribute {
@@ -95,6 +97,106 @@ impl Attribute {
     }
 }
 
+/// How the amount of an [`AttributeModifier`] is applied.
+///
+/// The value is computed by adding all `AddValue` amounts to the base value,
+/// then adding that sum times each `AddMultipliedBase` amount,
+/// then multiplying the result by `1 + amount` for each `AddMultipliedTotal`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeOperation {
+    AddValue,
+    AddMultipliedBase,
+    AddMultipliedTotal,
+}
+
+impl AttributeOperation {
+    fn to_field_name(&self) -> &'static str {
+        match self {
+            AttributeOperation::AddValue => "ADD_VALUE",
+            AttributeOperation::AddMultipliedBase => "ADD_MULTIPLIED_BASE",
+            AttributeOperation::AddMultipliedTotal => "ADD_MULTIPLIED_TOTAL",
+        }
+    }
+
+    fn from_field_name(name: &str) -> Option<Self> {
+        match name {
+            "ADD_VALUE" => Some(AttributeOperation::AddValue),
+            "ADD_MULTIPLIED_BASE" => Some(AttributeOperation::AddMultipliedBase),
+            "ADD_MULTIPLIED_TOTAL" => Some(AttributeOperation::AddMultipliedTotal),
+            _ => None,
+        }
+    }
+}
+
+/// A change applied on top of the base value of an attribute, e.g. by an effect or an item.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeModifier {
+    /// Namespaced key, e.g. `thecrown:shrunken`
+    pub id: String,
+    pub amount: f64,
+    pub operation: AttributeOperation,
+}
+
+impl AttributeModifier {
+    fn to_java(&self) -> Result<JavaObject> {
+        let mut env = get_env()?;
+        let id = env.new_string(&self.id)?;
+        let key = env
+            .call_static_method(
+                "net/kyori/adventure/key/Key",
+                "key",
+                "(Ljava/lang/String;)Lnet/kyori/adventure/key/Key;",
+                &[JValue::Object(&id)],
+            )?
+            .l()?;
+        let operation = env
+            .get_static_field(
+                "net/minestom/server/entity/attribute/AttributeOperation",
+                self.operation.to_field_name(),
+                "Lnet/minestom/server/entity/attribute/AttributeOperation;",
+            )?
+            .l()?;
+        let modifier = env.new_object(
+            "net/minestom/server/entity/attribute/AttributeModifier",
+            "(Lnet/kyori/adventure/key/Key;DLnet/minestom/server/entity/attribute/AttributeOperation;)V",
+            &[
+                JValue::Object(&key),
+                JValue::Double(self.amount),
+                JValue::Object(&operation),
+            ],
+        )?;
+        JavaObject::from_env(&mut env, modifier)
+    }
+
+    fn from_java(modifier: &JavaObject) -> Result<Self> {
+        let mut env = get_env()?;
+        let key = modifier.call_object_method("id", "()Lnet/kyori/adventure/key/Key;", &[])?;
+        let id = env
+            .call_method(key.as_obj()?, "asString", "()Ljava/lang/String;", &[])?
+            .l()?;
+        let id: String = env.get_string(&JString::from(id))?.into();
+
+        let operation = modifier.call_object_method(
+            "operation",
+            "()Lnet/minestom/server/entity/attribute/AttributeOperation;",
+            &[],
+        )?;
+        let name = env
+            .call_method(operation.as_obj()?, "name", "()Ljava/lang/String;", &[])?
+            .l()?;
+        let name: String = env.get_string(&JString::from(name))?.into();
+        let operation = AttributeOperation::from_field_name(&name).ok_or_else(|| {
+            MinestomError::Conversion(format!("unknown attribute operation {}", name))
+        })?;
+
+        Ok(Self {
+            id,
+            amount: modifier.call_double_method("amount", "()D", &[])?,
+            operation,
+        })
+    }
+}
+
 /// Represents an attribute instance that can be modified
 pub struct AttributeInstance {
     inner: JavaObject,
@@ -115,4 +217,61 @@ impl AttributeInstance {
         self.inner
             .call_void_method("setBaseValue", "(D)V", &[value.into()])
     }
+
+    /// Gets the value of the attribute, with all its modifiers applied
+    pub fn get_value(&self) -> Result<f64> {
+        self.inner.call_double_method("getValue", "()D", &[])
+    }
+
+    /// Adds a modifier, replacing the one with the same id if any.
+    /// Modifiers with different ids stack, so temporary effects should each use their own.
+    pub fn add_modifier(&self, id: &str, amount: f64, operation: AttributeOperation) -> Result<()> {
+        let modifier = AttributeModifier {
+            id: id.to_string(),
+            amount,
+            operation,
+        }
+        .to_java()?;
+        self.inner.call_object_method(
+            "addModifier",
+            "(Lnet/minestom/server/entity/attribute/AttributeModifier;)Lnet/minestom/server/entity/attribute/AttributeModifier;",
+            &[JniValue::Object(modifier.as_obj()?)],
+        )?;
+        Ok(())
+    }
+
+    /// Removes the modifier with the given id. Returns false if there was none.
+    pub fn remove_modifier(&self, id: &str) -> Result<bool> {
+        let mut env = get_env()?;
+        let id = env.new_string(id)?;
+        let key = env
+            .call_static_method(
+                "net/kyori/adventure/key/Key",
+                "key",
+                "(Ljava/lang/String;)Lnet/kyori/adventure/key/Key;",
+                &[JValue::Object(&id)],
+            )?
+            .l()?;
+        let removed = self.inner.call_object_method(
+            "removeModifier",
+            "(Lnet/kyori/adventure/key/Key;)Lnet/minestom/server/entity/attribute/AttributeModifier;",
+            &[JniValue::Object(key)],
+        )?;
+        Ok(!removed.as_obj()?.is_null())
+    }
+
+    pub fn get_modifiers(&self) -> Result<Vec<AttributeModifier>> {
+        let modifiers =
+            self.inner
+                .call_object_method("modifiers", "()Ljava/util/Collection;", &[])?;
+        collection_to_vec(&modifiers)?
+            .iter()
+            .map(AttributeModifier::from_java)
+            .collect()
+    }
+
+    /// Removes all the modifiers, the value goes back to the base value
+    pub fn clear_modifiers(&self) -> Result<()> {
+        self.inner.call_void_method("clearModifiers", "()V", &[])
+    }
 }
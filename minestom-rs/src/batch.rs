@@ -0,0 +1,61 @@
+use crate::Result;
+use crate::block::Block;
+use crate::instance::Instance;
+use crate::jni_utils::{JavaObject, JniValue, get_env};
+use jni::objects::JObject;
+
+/// A set of block changes applied to an instance in one go.
+/// Wraps Minestom's `AbsoluteBlockBatch`, which groups changes per chunk
+/// instead of sending one block update per `set_block` call.
+#[derive(Clone)]
+pub struct BlockBatch {
+    inner: JavaObject,
+}
+
+impl BlockBatch {
+    /// Creates a new, empty batch.
+    pub fn new() -> Result<Self> {
+        let mut env = get_env()?;
+        let batch = env.new_object(
+            "net/minestom/server/instance/batch/AbsoluteBlockBatch",
+            "()V",
+            &[],
+        )?;
+        Ok(Self {
+            inner: JavaObject::from_env(&mut env, batch)?,
+        })
+    }
+
+    /// Queues a block change at the given absolute coordinates.
+    pub fn set_block(&self, x: i32, y: i32, z: i32, block: &Block) -> Result<()> {
+        self.inner.call_void_method(
+            "setBlock",
+            "(IIILnet/minestom/server/instance/block/Block;)V",
+            &[
+                JniValue::Int(x),
+                JniValue::Int(y),
+                JniValue::Int(z),
+                JniValue::Object(block.inner.as_obj()?),
+            ],
+        )
+    }
+
+    /// Removes every queued change.
+    pub fn clear(&self) -> Result<()> {
+        self.inner.call_void_method("clear", "()V", &[])
+    }
+
+    /// Applies the queued changes to the instance.
+    /// The chunks are updated asynchronously by Minestom.
+    pub fn apply(&self, instance: &dyn Instance) -> Result<()> {
+        self.inner.call_object_method(
+            "apply",
+            "(Lnet/minestom/server/instance/Instance;Ljava/lang/Runnable;)Lnet/minestom/server/instance/batch/AbsoluteBlockBatch;",
+            &[
+                JniValue::Object(instance.inner()?),
+                JniValue::Object(JObject::null()),
+            ],
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,298 @@
+use jni::objects::{JObject, JString, JValue};
+
+use crate::Result;
+use crate::jni_utils::{JavaObject, get_env};
+use crate::particle::ParticleType;
+
+/// Key of a biome registered in the server's biome registry.
+#[derive(Debug, Clone)]
+pub struct BiomeKey {
+    pub(crate) inner: JavaObject,
+}
+
+impl BiomeKey {
+    /// The vanilla plains biome, used by default in new chunks.
+    pub fn plains() -> Result<Self> {
+        let mut env = get_env()?;
+        let key = env
+            .get_static_field(
+                "net/minestom/server/world/biome/Biome",
+                "PLAINS",
+                "Lnet/minestom/server/registry/DynamicRegistry$Key;",
+            )?
+            .l()?;
+        Ok(Self {
+            inner: JavaObject::from_env(&mut env, key)?,
+        })
+    }
+
+    /// Key of any biome, vanilla or custom, by its namespaced name.
+    ///
+    /// The registry is not checked here, a key naming an unregistered biome
+    /// makes the methods using it return an error.
+    pub fn of(name: &str) -> Result<Self> {
+        let mut env = get_env()?;
+        let j_name = env.new_string(name)?;
+        let key = env
+            .call_static_method(
+                "net/minestom/server/registry/DynamicRegistry$Key",
+                "of",
+                "(Ljava/lang/String;)Lnet/minestom/server/registry/DynamicRegistry$Key;",
+                &[JValue::Object(&j_name)],
+            )?
+            .l()?;
+        Ok(Self {
+            inner: JavaObject::from_env(&mut env, key)?,
+        })
+    }
+
+    /// Namespaced name of the biome, e.g. `minecraft:plains`
+    pub fn name(&self) -> Result<String> {
+        let mut env = get_env()?;
+        let name = self
+            .inner
+            .call_object_method("name", "()Ljava/lang/String;", &[])?;
+        let name = JString::from(env.new_local_ref(name.as_obj()?)?);
+        Ok(env.get_string(&name)?.into())
+    }
+}
+
+/// Ambient particles floating around in a biome.
+#[derive(Debug, Clone, Copy)]
+pub struct BiomeParticle {
+    pub particle: ParticleType,
+    /// Chance for a particle to spawn at each client tick, e.g. 0.01
+    pub probability: f32,
+}
+
+/// Properties of a custom biome.
+///
+/// Colors are `0xRRGGBB` values. The default values are the ones of the vanilla plains.
+///
+/// # Example
+/// ```rust,no_run
+/// let dusk = Biome {
+///     sky_color: 0x2B1B4F,
+///     fog_color: 0xA05A8C,
+///     grass_color: Some(0x6A8F3B),
+///     ambient_sound: Some("minecraft:ambient.cave".to_string()),
+///     ..Default::default()
+/// }
+/// .register("thecrown:dusk")?;
+/// instance.set_biome(0, 64, 0, &dusk)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct Biome {
+    pub temperature: f32,
+    pub downfall: f32,
+    pub has_precipitation: bool,
+    pub sky_color: u32,
+    pub fog_color: u32,
+    pub water_color: u32,
+    pub water_fog_color: u32,
+    /// Overrides the grass color computed from temperature and downfall
+    pub grass_color: Option<u32>,
+    /// Overrides the leaves color computed from temperature and downfall
+    pub foliage_color: Option<u32>,
+    pub particle: Option<BiomeParticle>,
+    /// Key of the sound looping while in the biome, e.g. `minecraft:ambient.basalt_deltas.loop`
+    pub ambient_sound: Option<String>,
+}
+
+impl Default for Biome {
+    fn default() -> Self {
+        Self {
+            temperature: 0.8,
+            downfall: 0.4,
+            has_precipitation: true,
+            sky_color: 0x78A7FF,
+            fog_color: 0xC0D8FF,
+            water_color: 0x3F76E4,
+            water_fog_color: 0x050533,
+            grass_color: None,
+            foliage_color: None,
+            particle: None,
+            ambient_sound: None,
+        }
+    }
+}
+
+impl Biome {
+    /// Registers this biome in the server's biome registry.
+    /// Must be called before players join, as registries are sent during configuration.
+    pub fn register(&self, name: &str) -> Result<BiomeKey> {
+        let mut env = get_env()?;
+        let biome = self.to_java()?;
+        let registry = env
+            .call_static_method(
+                "net/minestom/server/MinecraftServer",
+                "getBiomeRegistry",
+                "()Lnet/minestom/server/registry/DynamicRegistry;",
+                &[],
+            )?
+            .l()?;
+        let j_name = env.new_string(name)?;
+        let key = env
+            .call_method(
+                &registry,
+                "register",
+                "(Ljava/lang/String;Ljava/lang/Object;)Lnet/minestom/server/registry/DynamicRegistry$Key;",
+                &[JValue::Object(&j_name), JValue::Object(&biome.as_obj()?)],
+            )?
+            .l()?;
+
+        Ok(BiomeKey {
+            inner: JavaObject::from_env(&mut env, key)?,
+        })
+    }
+
+    fn to_java(&self) -> Result<JavaObject> {
+        let mut env = get_env()?;
+        let effects = env
+            .call_static_method(
+                "net/minestom/server/world/biome/BiomeEffects",
+                "builder",
+                "()Lnet/minestom/server/world/biome/BiomeEffects$Builder;",
+                &[],
+            )?
+            .l()?;
+
+        const EFFECTS_BUILDER: &str = "Lnet/minestom/server/world/biome/BiomeEffects$Builder;";
+        let colors = [
+            ("skyColor", Some(self.sky_color)),
+            ("fogColor", Some(self.fog_color)),
+            ("waterColor", Some(self.water_color)),
+            ("waterFogColor", Some(self.water_fog_color)),
+            ("grassColor", self.grass_color),
+            ("foliageColor", self.foliage_color),
+        ];
+        for (method, color) in colors {
+            let Some(color) = color else {
+                continue;
+            };
+            let color = env.new_object(
+                "net/minestom/server/color/Color",
+                "(I)V",
+                &[JValue::Int(color as i32)],
+            )?;
+            env.call_method(
+                &effects,
+                method,
+                format!("(Lnet/minestom/server/color/Color;){}", EFFECTS_BUILDER),
+                &[JValue::Object(&color)],
+            )?;
+        }
+
+        if let Some(particle) = &self.particle {
+            let java_particle = env
+                .get_static_field(
+                    "net/minestom/server/particle/Particle",
+                    particle.particle.to_java_field(),
+                    "Lnet/minestom/server/particle/Particle;",
+                )?
+                .l()?;
+            let biome_particle = env.new_object(
+                "net/minestom/server/world/biome/BiomeParticle",
+                "(FLnet/minestom/server/particle/Particle;)V",
+                &[
+                    JValue::Float(particle.probability),
+                    JValue::Object(&java_particle),
+                ],
+            )?;
+            env.call_method(
+                &effects,
+                "biomeParticle",
+                format!(
+                    "(Lnet/minestom/server/world/biome/BiomeParticle;){}",
+                    EFFECTS_BUILDER
+                ),
+                &[JValue::Object(&biome_particle)],
+            )?;
+        }
+
+        if let Some(sound) = &self.ambient_sound {
+            let j_sound = env.new_string(sound)?;
+            let key = env
+                .call_static_method(
+                    "net/kyori/adventure/key/Key",
+                    "key",
+                    "(Ljava/lang/String;)Lnet/kyori/adventure/key/Key;",
+                    &[JValue::Object(&j_sound)],
+                )?
+                .l()?;
+            let sound_event = env
+                .call_static_method(
+                    "net/minestom/server/sound/SoundEvent",
+                    "of",
+                    "(Lnet/kyori/adventure/key/Key;Ljava/lang/Float;)Lnet/minestom/server/sound/SoundEvent;",
+                    &[JValue::Object(&key), JValue::Object(&JObject::null())],
+                )?
+                .l()?;
+            env.call_method(
+                &effects,
+                "ambientSound",
+                format!(
+                    "(Lnet/minestom/server/sound/SoundEvent;){}",
+                    EFFECTS_BUILDER
+                ),
+                &[JValue::Object(&sound_event)],
+            )?;
+        }
+
+        let effects = env
+            .call_method(
+                &effects,
+                "build",
+                "()Lnet/minestom/server/world/biome/BiomeEffects;",
+                &[],
+            )?
+            .l()?;
+
+        const BUILDER: &str = "Lnet/minestom/server/world/biome/Biome$Builder;";
+        let builder = env
+            .call_static_method(
+                "net/minestom/server/world/biome/Biome",
+                "builder",
+                format!("(){}", BUILDER),
+                &[],
+            )?
+            .l()?;
+        env.call_method(
+            &builder,
+            "temperature",
+            format!("(F){}", BUILDER),
+            &[JValue::Float(self.temperature)],
+        )?;
+        env.call_method(
+            &builder,
+            "downfall",
+            format!("(F){}", BUILDER),
+            &[JValue::Float(self.downfall)],
+        )?;
+        env.call_method(
+            &builder,
+            "hasPrecipitation",
+            format!("(Z){}", BUILDER),
+            &[JValue::Bool(self.has_precipitation as u8)],
+        )?;
+        env.call_method(
+            &builder,
+            "effects",
+            format!(
+                "(Lnet/minestom/server/world/biome/BiomeEffects;){}",
+                BUILDER
+            ),
+            &[JValue::Object(&effects)],
+        )?;
+
+        let biome = env
+            .call_method(
+                &builder,
+                "build",
+                "()Lnet/minestom/server/world/biome/Biome;",
+                &[],
+            )?
+            .l()?;
+        JavaObject::from_env(&mut env, biome)
+    }
+}
@@ -1,7 +1,9 @@
-use jni::objects::JString;
+use jni::objects::{JString, JValue};
 
 use crate::Result;
+use crate::error::MinestomError;
 use crate::jni_utils::{JavaObject, JniValue, get_env};
+use crate::nbt::Nbt;
 
 #[derive(Debug, Clone)]
 pub struct Block {
@@ -9,6 +11,82 @@ pub struct Block {
 }
 
 impl Block {
+    /// Parses a block state string such as `minecraft:oak_stairs[facing=north,half=bottom]`.
+    pub fn from_state(state: &str) -> Result<Block> {
+        let (key, properties) = parse_block_state(state);
+
+        let mut env = get_env()?;
+        let jkey = env.new_string(key)?;
+        let block = env
+            .call_static_method(
+                "net/minestom/server/instance/block/Block",
+                "fromKey",
+                "(Ljava/lang/String;)Lnet/minestom/server/instance/block/Block;",
+                &[JValue::Object(&jkey)],
+            )?
+            .l()?;
+        if block.is_null() {
+            return Err(MinestomError::InvalidArgument(format!(
+                "Unknown block: {}",
+                key
+            )));
+        }
+
+        let mut block = Block {
+            inner: JavaObject::from_env(&mut env, block)?,
+        };
+        if !properties.is_empty() {
+            let map = env.new_object("java/util/HashMap", "()V", &[])?;
+            for (name, value) in &properties {
+                let jname = env.new_string(name)?;
+                let jvalue = env.new_string(value)?;
+                env.call_method(
+                    &map,
+                    "put",
+                    "(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;",
+                    &[JValue::Object(&jname), JValue::Object(&jvalue)],
+                )?;
+            }
+            block.inner = block.inner.call_object_method(
+                "withProperties",
+                "(Ljava/util/Map;)Lnet/minestom/server/instance/block/Block;",
+                &[JniValue::Object(map)],
+            )?;
+        }
+        Ok(block)
+    }
+
+    /// Returns a copy of this block carrying the given block entity data.
+    pub fn with_nbt(&self, nbt: &Nbt) -> Result<Block> {
+        let compound = nbt.to_java_compound()?;
+        let result_obj = self.inner.call_object_method(
+            "withNbt",
+            "(Lnet/kyori/adventure/nbt/CompoundBinaryTag;)Lnet/minestom/server/instance/block/Block;",
+            &[JniValue::Object(compound.as_obj()?)],
+        )?;
+        Ok(Block { inner: result_obj })
+    }
+
+    /// Gets the namespaced key of this block, e.g. `minecraft:stone`.
+    pub fn name(&self) -> Result<String> {
+        let mut env = get_env()?;
+        let result = self
+            .inner
+            .call_object_method("name", "()Ljava/lang/String;", &[])?;
+        let jstr = JString::from(result.as_obj()?);
+        Ok(env.get_string(&jstr)?.into())
+    }
+
+    /// Returns true for air, cave air and void air.
+    pub fn is_air(&self) -> Result<bool> {
+        self.inner.call_bool_method("isAir", "()Z", &[])
+    }
+
+    /// Returns true if the block blocks movement, e.g. stone but not flowers or water.
+    pub fn is_solid(&self) -> Result<bool> {
+        self.inner.call_bool_method("isSolid", "()Z", &[])
+    }
+
     pub fn with_property(&self, key: &str, value: &str) -> Result<Block> {
         // Acquire the JNI environment
         let mut env = get_env()?;
@@ -29,6 +107,63 @@ impl Block {
     }
 }
 
+/// Splits a block state string into its key and its `name=value` properties.
+pub(crate) fn parse_block_state(state: &str) -> (&str, Vec<(String, String)>) {
+    match state.split_once('[') {
+        Some((key, rest)) => {
+            let properties = rest
+                .trim_end_matches(']')
+                .split(',')
+                .filter_map(|pair| pair.split_once('='))
+                .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+                .collect();
+            (key, properties)
+        }
+        None => (state, Vec::new()),
+    }
+}
+
+/// A face of a block, in the order of Minestom's `BlockFace` enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockFace {
+    Bottom,
+    Top,
+    North,
+    South,
+    West,
+    East,
+}
+
+impl BlockFace {
+    pub(crate) fn from_java(face: &JavaObject) -> Result<Self> {
+        let ordinal = face.call_int_method("ordinal", "()I", &[])?;
+        match ordinal {
+            0 => Ok(BlockFace::Bottom),
+            1 => Ok(BlockFace::Top),
+            2 => Ok(BlockFace::North),
+            3 => Ok(BlockFace::South),
+            4 => Ok(BlockFace::West),
+            5 => Ok(BlockFace::East),
+            _ => Err(MinestomError::Conversion(format!(
+                "Unknown block face ordinal {}",
+                ordinal
+            ))),
+        }
+    }
+
+    /// Unit offset pointing out of this face.
+    pub fn direction(&self) -> (i32, i32, i32) {
+        match self {
+            BlockFace::Bottom => (0, -1, 0),
+            BlockFace::Top => (0, 1, 0),
+            BlockFace::North => (0, 0, -1),
+            BlockFace::South => (0, 0, 1),
+            BlockFace::West => (-1, 0, 0),
+            BlockFace::East => (1, 0, 0),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BlockType {
     // Basic blocks
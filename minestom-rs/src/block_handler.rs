@@ -0,0 +1,473 @@
+use crate::block::{Block, BlockFace};
+use crate::coordinate::{Pos, Position};
+use crate::entity::Player;
+use crate::entity::entity::Entity;
+use crate::error::MinestomError;
+use crate::event::player::Hand;
+use crate::instance::InstanceContainer;
+use crate::jni_utils::{JavaObject, JniValue, get_env};
+use jni::JNIEnv;
+use jni::objects::{JClass, JObject, JValue};
+use jni::sys::{jboolean, jlong, jobject};
+use log::error;
+use once_cell::sync::Lazy;
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc, RwLock,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+/// Trait implemented by Rust block behaviours.
+///
+/// A handler is attached to blocks with [`Block::with_handler`] and is
+/// registered by its key so that blocks loaded from a world get it back.
+pub trait BlockHandler: Send + Sync + 'static {
+    /// Namespaced key of this handler, e.g. `thecrown:launch_pad`
+    fn key(&self) -> String;
+
+    /// Whether [`BlockHandler::tick`] should be called every tick
+    fn is_tickable(&self) -> bool {
+        false
+    }
+
+    /// Called when a block with this handler is placed
+    fn on_place(&self, _placement: &BlockPlacement) {}
+
+    /// Called when a block with this handler is destroyed or replaced
+    fn on_destroy(&self, _destroy: &BlockDestroy) {}
+
+    /// Called when a player right-clicks the block.
+    /// Return `false` to prevent the item in hand from being used.
+    fn on_interact(&self, _interaction: &BlockInteraction) -> bool {
+        true
+    }
+
+    /// Called when an entity is inside the block's collision shape
+    fn on_touch(&self, _touch: &BlockTouch) {}
+
+    /// Called every tick if [`BlockHandler::is_tickable`] returns `true`
+    fn tick(&self, _tick: &BlockTick) {}
+}
+
+/// The Java `BlockHandler` object backed by a Rust [`BlockHandler`].
+#[derive(Clone)]
+pub struct MinestomBlockHandler {
+    pub(crate) inner: JavaObject,
+}
+
+// Registry mapping callback IDs → the Rust implementation,
+// entries are freed once no block or block manager holds the Java handler
+static HANDLER_REGISTRY: Lazy<RwLock<HashMap<u64, Arc<dyn BlockHandler>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+static NEXT_HANDLER_ID: AtomicU64 = AtomicU64::new(1);
+
+// Hardcoded Java class for callbacks
+const JAVA_CLASS: &str = "rust/minestom/BlockHandlerCallback";
+
+/// Creates the Java callback object for a Rust `BlockHandler`.
+pub fn create_block_handler(
+    handler_impl: Arc<dyn BlockHandler>,
+) -> crate::Result<MinestomBlockHandler> {
+    let key = handler_impl.key();
+    let tickable = handler_impl.is_tickable();
+
+    let id = NEXT_HANDLER_ID.fetch_add(1, Ordering::SeqCst);
+    HANDLER_REGISTRY.write().unwrap().insert(id, handler_impl);
+
+    let mut env = get_env()?;
+    let j_key = env.new_string(&key)?;
+    let obj = env.new_object(
+        JAVA_CLASS,
+        "(JLjava/lang/String;Z)V",
+        &[
+            JValue::Long(id as i64),
+            JValue::Object(&j_key),
+            JValue::Bool(tickable as u8),
+        ],
+    )?;
+
+    Ok(MinestomBlockHandler {
+        inner: JavaObject::from_env(&mut env, obj)?,
+    })
+}
+
+/// Wrapper around Minestom's `BlockManager`.
+#[derive(Clone)]
+pub struct BlockManager {
+    inner: JavaObject,
+}
+
+impl BlockManager {
+    pub(crate) fn new(inner: JavaObject) -> Self {
+        Self { inner }
+    }
+
+    /// Registers a handler under its key, so blocks loaded with that
+    /// handler key (e.g. from Anvil block entities) use it.
+    pub fn register_handler(&self, handler: &MinestomBlockHandler) -> crate::Result<()> {
+        let mut env = get_env()?;
+        let key = env
+            .call_method(
+                handler.inner.as_obj()?,
+                "getKey",
+                "()Lnet/kyori/adventure/key/Key;",
+                &[],
+            )?
+            .l()?;
+        let key_string = env
+            .call_method(&key, "asString", "()Ljava/lang/String;", &[])?
+            .l()?;
+
+        self.inner.call_void_method(
+            "registerHandler",
+            "(Ljava/lang/String;Ljava/util/function/Supplier;)V",
+            &[
+                JniValue::Object(key_string),
+                JniValue::Object(handler.inner.as_obj()?),
+            ],
+        )
+    }
+
+    /// Returns true if a handler is registered for the given key.
+    pub fn has_handler(&self, key: &str) -> crate::Result<bool> {
+        let env = get_env()?;
+        let j_key = env.new_string(key)?;
+        let handler = self.inner.call_object_method(
+            "getHandler",
+            "(Ljava/lang/String;)Lnet/minestom/server/instance/block/BlockHandler;",
+            &[JniValue::String(j_key)],
+        )?;
+        Ok(!handler.as_obj()?.is_null())
+    }
+}
+
+impl Block {
+    /// Returns a copy of this block using the given handler.
+    pub fn with_handler(&self, handler: &MinestomBlockHandler) -> crate::Result<Block> {
+        let result_obj = self.inner.call_object_method(
+            "withHandler",
+            "(Lnet/minestom/server/instance/block/BlockHandler;)Lnet/minestom/server/instance/block/Block;",
+            &[JniValue::Object(handler.inner.as_obj()?)],
+        )?;
+        Ok(Block { inner: result_obj })
+    }
+}
+
+fn get_block(obj: &JavaObject) -> crate::Result<Block> {
+    let inner = obj.call_object_method(
+        "getBlock",
+        "()Lnet/minestom/server/instance/block/Block;",
+        &[],
+    )?;
+    Ok(Block { inner })
+}
+
+fn get_instance(obj: &JavaObject) -> crate::Result<InstanceContainer> {
+    let inner = obj.call_object_method(
+        "getInstance",
+        "()Lnet/minestom/server/instance/Instance;",
+        &[],
+    )?;
+    Ok(InstanceContainer::new(inner))
+}
+
+fn get_block_position(obj: &JavaObject) -> crate::Result<Position> {
+    let point = obj.call_object_method(
+        "getBlockPosition",
+        "()Lnet/minestom/server/coordinate/Point;",
+        &[],
+    )?;
+    Pos::new(point).to_position()
+}
+
+fn get_player(obj: &JavaObject, player_class: &str) -> crate::Result<Option<Player>> {
+    let mut env = get_env()?;
+    if !env.is_instance_of(obj.as_obj()?, player_class)? {
+        return Ok(None);
+    }
+    let player =
+        obj.call_object_method("getPlayer", "()Lnet/minestom/server/entity/Player;", &[])?;
+    Ok(Some(Player::new(player)))
+}
+
+/// Context of [`BlockHandler::on_place`].
+pub struct BlockPlacement {
+    inner: JavaObject,
+}
+
+impl BlockPlacement {
+    pub fn block(&self) -> crate::Result<Block> {
+        get_block(&self.inner)
+    }
+
+    pub fn instance(&self) -> crate::Result<InstanceContainer> {
+        get_instance(&self.inner)
+    }
+
+    pub fn block_position(&self) -> crate::Result<Position> {
+        get_block_position(&self.inner)
+    }
+
+    /// The player who placed the block, if it was placed by a player.
+    pub fn player(&self) -> crate::Result<Option<Player>> {
+        get_player(
+            &self.inner,
+            "net/minestom/server/instance/block/BlockHandler$PlayerPlacement",
+        )
+    }
+}
+
+/// Context of [`BlockHandler::on_destroy`].
+pub struct BlockDestroy {
+    inner: JavaObject,
+}
+
+impl BlockDestroy {
+    pub fn block(&self) -> crate::Result<Block> {
+        get_block(&self.inner)
+    }
+
+    pub fn instance(&self) -> crate::Result<InstanceContainer> {
+        get_instance(&self.inner)
+    }
+
+    pub fn block_position(&self) -> crate::Result<Position> {
+        get_block_position(&self.inner)
+    }
+
+    /// The player who broke the block, if it was broken by a player.
+    pub fn player(&self) -> crate::Result<Option<Player>> {
+        get_player(
+            &self.inner,
+            "net/minestom/server/instance/block/BlockHandler$PlayerDestroy",
+        )
+    }
+}
+
+/// Context of [`BlockHandler::on_interact`].
+pub struct BlockInteraction {
+    inner: JavaObject,
+}
+
+impl BlockInteraction {
+    pub fn block(&self) -> crate::Result<Block> {
+        get_block(&self.inner)
+    }
+
+    pub fn instance(&self) -> crate::Result<InstanceContainer> {
+        get_instance(&self.inner)
+    }
+
+    pub fn block_position(&self) -> crate::Result<Position> {
+        get_block_position(&self.inner)
+    }
+
+    pub fn player(&self) -> crate::Result<Player> {
+        let player = self.inner.call_object_method(
+            "getPlayer",
+            "()Lnet/minestom/server/entity/Player;",
+            &[],
+        )?;
+        Ok(Player::new(player))
+    }
+
+    pub fn hand(&self) -> crate::Result<Hand> {
+        let hand = self.inner.call_object_method(
+            "getHand",
+            "()Lnet/minestom/server/entity/PlayerHand;",
+            &[],
+        )?;
+        let ord = hand.call_int_method("ordinal", "()I", &[])?;
+        Hand::from_java(ord)
+            .ok_or_else(|| MinestomError::EventError(format!("Unknown hand ordinal {}", ord)))
+    }
+
+    /// The face of the block that was clicked.
+    pub fn block_face(&self) -> crate::Result<BlockFace> {
+        let face = self.inner.call_object_method(
+            "getBlockFace",
+            "()Lnet/minestom/server/instance/block/BlockFace;",
+            &[],
+        )?;
+        BlockFace::from_java(&face)
+    }
+
+    /// The clicked position inside the block, each coordinate between 0 and 1.
+    pub fn cursor_position(&self) -> crate::Result<Position> {
+        let point = self.inner.call_object_method(
+            "getCursorPosition",
+            "()Lnet/minestom/server/coordinate/Point;",
+            &[],
+        )?;
+        Pos::new(point).to_position()
+    }
+}
+
+/// Context of [`BlockHandler::on_touch`].
+pub struct BlockTouch {
+    inner: JavaObject,
+}
+
+impl BlockTouch {
+    pub fn block(&self) -> crate::Result<Block> {
+        get_block(&self.inner)
+    }
+
+    pub fn instance(&self) -> crate::Result<InstanceContainer> {
+        get_instance(&self.inner)
+    }
+
+    pub fn block_position(&self) -> crate::Result<Position> {
+        get_block_position(&self.inner)
+    }
+
+    /// The entity touching the block.
+    pub fn touching(&self) -> crate::Result<Entity> {
+        let entity = self.inner.call_object_method(
+            "getTouching",
+            "()Lnet/minestom/server/entity/Entity;",
+            &[],
+        )?;
+        Ok(Entity::new(entity))
+    }
+}
+
+/// Context of [`BlockHandler::tick`].
+pub struct BlockTick {
+    inner: JavaObject,
+}
+
+impl BlockTick {
+    pub fn block(&self) -> crate::Result<Block> {
+        get_block(&self.inner)
+    }
+
+    pub fn instance(&self) -> crate::Result<InstanceContainer> {
+        get_instance(&self.inner)
+    }
+
+    pub fn block_position(&self) -> crate::Result<Position> {
+        get_block_position(&self.inner)
+    }
+}
+
+/// Looks up the handler and wraps the Java context object, then runs `f`
+/// without letting a panic unwind into the JVM.
+fn dispatch<R>(
+    raw_env: *mut jni::sys::JNIEnv,
+    callback_id: jlong,
+    j_context: jobject,
+    default: R,
+    f: impl FnOnce(&dyn BlockHandler, JavaObject) -> R,
+) -> R {
+    let handler = match HANDLER_REGISTRY.read().unwrap().get(&(callback_id as u64)) {
+        Some(handler) => handler.clone(),
+        None => return default,
+    };
+    let mut env = match unsafe { JNIEnv::from_raw(raw_env) } {
+        Ok(env) => env,
+        Err(e) => {
+            error!("Failed to get JNIEnv: {}", e);
+            return default;
+        }
+    };
+    let context = match JavaObject::from_env(&mut env, unsafe { JObject::from_raw(j_context) }) {
+        Ok(context) => context,
+        Err(e) => {
+            error!("Failed to wrap block handler context: {}", e);
+            return default;
+        }
+    };
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f(&*handler, context))) {
+        Ok(result) => result,
+        Err(_) => {
+            error!("Panic in block handler {}", callback_id);
+            default
+        }
+    }
+}
+
+/// JNI callback: the BlockHandlerCallback was collected
+#[unsafe(no_mangle)]
+pub unsafe extern "system" fn Java_rust_minestom_BlockHandlerCallback_nativeFree(
+    _raw_env: *mut jni::sys::JNIEnv,
+    _class: JClass,
+    callback_id: jlong,
+) {
+    HANDLER_REGISTRY
+        .write()
+        .unwrap()
+        .remove(&(callback_id as u64));
+}
+
+/// JNI callback: onPlace(Placement)
+#[unsafe(no_mangle)]
+pub unsafe extern "system" fn Java_rust_minestom_BlockHandlerCallback_nativeOnPlace(
+    raw_env: *mut jni::sys::JNIEnv,
+    _class: JClass,
+    callback_id: jlong,
+    j_placement: jobject,
+) {
+    dispatch(raw_env, callback_id, j_placement, (), |handler, inner| {
+        handler.on_place(&BlockPlacement { inner })
+    })
+}
+
+/// JNI callback: onDestroy(Destroy)
+#[unsafe(no_mangle)]
+pub unsafe extern "system" fn Java_rust_minestom_BlockHandlerCallback_nativeOnDestroy(
+    raw_env: *mut jni::sys::JNIEnv,
+    _class: JClass,
+    callback_id: jlong,
+    j_destroy: jobject,
+) {
+    dispatch(raw_env, callback_id, j_destroy, (), |handler, inner| {
+        handler.on_destroy(&BlockDestroy { inner })
+    })
+}
+
+/// JNI callback: onInteract(Interaction) → boolean
+#[unsafe(no_mangle)]
+pub unsafe extern "system" fn Java_rust_minestom_BlockHandlerCallback_nativeOnInteract(
+    raw_env: *mut jni::sys::JNIEnv,
+    _class: JClass,
+    callback_id: jlong,
+    j_interaction: jobject,
+) -> jboolean {
+    let allow = dispatch(
+        raw_env,
+        callback_id,
+        j_interaction,
+        true,
+        |handler, inner| handler.on_interact(&BlockInteraction { inner }),
+    );
+    if allow { 1 } else { 0 }
+}
+
+/// JNI callback: onTouch(Touch)
+#[unsafe(no_mangle)]
+pub unsafe extern "system" fn Java_rust_minestom_BlockHandlerCallback_nativeOnTouch(
+    raw_env: *mut jni::sys::JNIEnv,
+    _class: JClass,
+    callback_id: jlong,
+    j_touch: jobject,
+) {
+    dispatch(raw_env, callback_id, j_touch, (), |handler, inner| {
+        handler.on_touch(&BlockTouch { inner })
+    })
+}
+
+/// JNI callback: tick(Tick)
+#[unsafe(no_mangle)]
+pub unsafe extern "system" fn Java_rust_minestom_BlockHandlerCallback_nativeTick(
+    raw_env: *mut jni::sys::JNIEnv,
+    _class: JClass,
+    callback_id: jlong,
+    j_tick: jobject,
+) {
+    dispatch(raw_env, callback_id, j_tick, (), |handler, inner| {
+        handler.tick(&BlockTick { inner })
+    })
+}
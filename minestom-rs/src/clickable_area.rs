@@ -0,0 +1,115 @@
+use crate::Result;
+use crate::coordinate::Position;
+use crate::entity::display::{Interaction, InteractionClick};
+use crate::entity::{EntityLike, Player};
+use crate::event::player::Hand;
+use crate::event::{EventNode, ListenerHandle};
+use crate::jni_utils::JavaObject;
+use crate::raycast::ray_box_entry;
+
+/// A click on a [`ClickableArea`].
+#[derive(Clone)]
+pub struct AreaClick {
+    pub player: Player,
+    pub button: InteractionClick,
+    /// Hand used to click. Right clicks are reported once per hand,
+    /// left clicks always use the main hand.
+    pub hand: Hand,
+    /// Point that was clicked, relative to the bottom center of the area.
+    /// `None` when a left click cannot be traced back to the area,
+    /// e.g. because the player moved right after clicking.
+    pub position: Option<Position>,
+}
+
+/// A clickable box of any width and height, backed by a single interaction entity.
+///
+/// Replaces rows of invisible armor stands used to cover large surfaces,
+/// reporting where the area was clicked.
+///
+/// # Example
+/// ```rust,no_run
+/// let keyboard = ClickableArea::new(2.0, 0.1)?;
+/// keyboard.set_instance(&instance, &Position::new(0.5, 41.0, 0.5).to_pos()?)?;
+/// keyboard.on_click(&instance.event_node()?, |click| {
+///     if let Some(position) = &click.position {
+///         click.player.send_message(&Component::text(&format!("x = {:.2}", position.x))?)?;
+///     }
+///     Ok(())
+/// })?;
+/// ```
+#[derive(Clone)]
+pub struct ClickableArea {
+    interaction: Interaction,
+}
+
+impl ClickableArea {
+    /// Creates an area `width` blocks wide on both horizontal axes and `height` blocks tall.
+    /// The position of the area is the center of its bottom face.
+    pub fn new(width: f32, height: f32) -> Result<Self> {
+        Ok(Self {
+            interaction: Interaction::new(width, height)?,
+        })
+    }
+
+    pub fn set_size(&self, width: f32, height: f32) -> Result<()> {
+        self.interaction.set_size(width, height)
+    }
+
+    pub fn get_size(&self) -> Result<(f32, f32)> {
+        self.interaction.get_size()
+    }
+
+    /// Gets the interaction entity backing this area
+    pub fn as_interaction(&self) -> &Interaction {
+        &self.interaction
+    }
+
+    /// Calls `callback` whenever a player clicks this area.
+    ///
+    /// Right clicks report the position sent by the client. Left clicks have none,
+    /// so the player's line of sight is traced against the area.
+    /// Unlike [`Interaction::on_click`], right clicks are reported for both hands,
+    /// see [`AreaClick::hand`].
+    /// The returned handle removes the listeners, see [`Interaction::on_click`].
+    pub fn on_click<F>(&self, events: &EventNode, callback: F) -> Result<ListenerHandle>
+    where
+        F: Fn(&AreaClick) -> Result<()> + Send + Sync + 'static,
+    {
+        let area = self.clone();
+        self.interaction
+            .listen_clicks(events, move |player, button, hand, position| {
+                let position = match position {
+                    Some(position) => Some(position),
+                    None => area.trace(&player)?,
+                };
+                callback(&AreaClick {
+                    player,
+                    button,
+                    hand,
+                    position,
+                })
+            })
+    }
+
+    /// Point of the area the player is looking at, relative to its bottom center.
+    fn trace(&self, player: &Player) -> Result<Option<Position>> {
+        let (eye, direction) = player.eye_ray()?;
+        let center = self.get_position()?;
+        let (width, height) = self.get_size()?;
+        let half_width = width as f64 / 2.0;
+        let min = Position::new(center.x - half_width, center.y, center.z - half_width);
+        let max = Position::new(
+            center.x + half_width,
+            center.y + height as f64,
+            center.z + half_width,
+        );
+        Ok(ray_box_entry(&eye, &direction, &min, &max)
+            .map(|hit| Position::new(hit.x - center.x, hit.y - center.y, hit.z - center.z)))
+    }
+}
+
+impl EntityLike for ClickableArea {
+    fn java_object(&self) -> &JavaObject {
+        self.interaction.java_object()
+    }
+}
@@ -1,3 +1,4 @@
+use crate::coordinate::{Pos, Position};
 use crate::jni_utils::{JavaObject, get_env};
 use crate::{MinestomError, Result};
 use jni::objects::JValue;
@@ -52,6 +53,30 @@ impl BoundingBox {
         })
     }
 
+    pub(crate) fn from_java(inner: JavaObject) -> Self {
+        Self { inner }
+    }
+
+    /// Lowest corner of the box, relative to the entity position.
+    pub fn relative_start(&self) -> Result<Position> {
+        let point = self.inner.call_object_method(
+            "relativeStart",
+            "()Lnet/minestom/server/coordinate/Point;",
+            &[],
+        )?;
+        Pos::new(point).to_position()
+    }
+
+    /// Highest corner of the box, relative to the entity position.
+    pub fn relative_end(&self) -> Result<Position> {
+        let point = self.inner.call_object_method(
+            "relativeEnd",
+            "()Lnet/minestom/server/coordinate/Point;",
+            &[],
+        )?;
+        Pos::new(point).to_position()
+    }
+
     /// Returns the inner JavaObject (for passing to JNI methods).
     pub(crate) fn as_java(&self) -> &JavaObject {
         &self.inner
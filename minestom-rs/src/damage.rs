@@ -0,0 +1,327 @@
+use jni::objects::{JObject, JString, JValue};
+
+use crate::Result;
+use crate::coordinate::Position;
+use crate::entity::entity::{Entity, EntityLike};
+use crate::jni_utils::{JavaObject, JniValue, get_env};
+
+/// Key of a damage type registered in the server's damage type registry.
+#[derive(Debug, Clone)]
+pub struct DamageType {
+    pub(crate) inner: JavaObject,
+}
+
+impl DamageType {
+    /// Looks up any registered damage type by its namespaced name, e.g. `minecraft:fall`.
+    pub fn of(name: &str) -> Result<Self> {
+        let mut env = get_env()?;
+        let j_name = env.new_string(name)?;
+        let key = env
+            .call_static_method(
+                "net/minestom/server/registry/DynamicRegistry$Key",
+                "of",
+                "(Ljava/lang/String;)Lnet/minestom/server/registry/DynamicRegistry$Key;",
+                &[JValue::Object(&j_name)],
+            )?
+            .l()?;
+        Ok(Self {
+            inner: JavaObject::from_env(&mut env, key)?,
+        })
+    }
+
+    pub fn generic() -> Result<Self> {
+        Self::of("minecraft:generic")
+    }
+
+    pub fn player_attack() -> Result<Self> {
+        Self::of("minecraft:player_attack")
+    }
+
+    pub fn mob_attack() -> Result<Self> {
+        Self::of("minecraft:mob_attack")
+    }
+
+    pub fn arrow() -> Result<Self> {
+        Self::of("minecraft:arrow")
+    }
+
+    pub fn fall() -> Result<Self> {
+        Self::of("minecraft:fall")
+    }
+
+    pub fn on_fire() -> Result<Self> {
+        Self::of("minecraft:on_fire")
+    }
+
+    pub fn lava() -> Result<Self> {
+        Self::of("minecraft:lava")
+    }
+
+    pub fn drown() -> Result<Self> {
+        Self::of("minecraft:drown")
+    }
+
+    pub fn magic() -> Result<Self> {
+        Self::of("minecraft:magic")
+    }
+
+    pub fn explosion() -> Result<Self> {
+        Self::of("minecraft:explosion")
+    }
+
+    pub fn out_of_world() -> Result<Self> {
+        Self::of("minecraft:out_of_world")
+    }
+
+    /// Lists every damage type of the registry, vanilla and custom ones.
+    pub fn registered() -> Result<Vec<Self>> {
+        let mut env = get_env()?;
+        let registry = damage_type_registry()?;
+        let size = env.call_method(&registry, "size", "()I", &[])?.i()?;
+        let mut types = Vec::with_capacity(size as usize);
+        for id in 0..size {
+            let key = env
+                .call_method(
+                    &registry,
+                    "getKey",
+                    "(I)Lnet/minestom/server/registry/DynamicRegistry$Key;",
+                    &[JValue::Int(id)],
+                )?
+                .l()?;
+            if !key.is_null() {
+                types.push(Self {
+                    inner: JavaObject::from_env(&mut env, key)?,
+                });
+            }
+        }
+        Ok(types)
+    }
+
+    /// Namespaced name of the damage type, e.g. `minecraft:generic`
+    pub fn name(&self) -> Result<String> {
+        let mut env = get_env()?;
+        let name = self
+            .inner
+            .call_object_method("name", "()Ljava/lang/String;", &[])?;
+        let name = JString::from(env.new_local_ref(name.as_obj()?)?);
+        Ok(env.get_string(&name)?.into())
+    }
+}
+
+impl PartialEq for DamageType {
+    fn eq(&self, other: &Self) -> bool {
+        matches!((self.name(), other.name()), (Ok(a), Ok(b)) if a == b)
+    }
+}
+
+fn damage_type_registry() -> Result<JObject<'static>> {
+    let mut env = get_env()?;
+    Ok(env
+        .call_static_method(
+            "net/minestom/server/MinecraftServer",
+            "getDamageTypeRegistry",
+            "()Lnet/minestom/server/registry/DynamicRegistry;",
+            &[],
+        )?
+        .l()?)
+}
+
+/// Properties of a custom damage type.
+///
+/// # Example
+/// ```rust,no_run
+/// let spikes = CustomDamageType::new("thecrown.spikes").register("thecrown:spikes")?;
+/// creature.damage(&spikes, 2.0)?;
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomDamageType {
+    /// Translation key of the death message, as `death.attack.<message_id>`
+    pub message_id: String,
+    /// Hunger exhaustion caused to players
+    pub exhaustion: f32,
+    /// Whether the damage scales with difficulty: `never`, `always` or
+    /// `when_caused_by_living_non_player`
+    pub scaling: String,
+}
+
+impl CustomDamageType {
+    pub fn new(message_id: &str) -> Self {
+        Self {
+            message_id: message_id.to_string(),
+            exhaustion: 0.0,
+            scaling: "never".to_string(),
+        }
+    }
+
+    /// Registers this damage type in the server's damage type registry.
+    /// Must be called before players join, as registries are sent during configuration.
+    pub fn register(&self, name: &str) -> Result<DamageType> {
+        let mut env = get_env()?;
+        let message_id = env.new_string(&self.message_id)?;
+        let scaling = env.new_string(&self.scaling)?;
+        let damage_type = env
+            .call_static_method(
+                "net/minestom/server/entity/damage/DamageType",
+                "create",
+                "(FLjava/lang/String;Ljava/lang/String;)Lnet/minestom/server/entity/damage/DamageType;",
+                &[
+                    JValue::Float(self.exhaustion),
+                    JValue::Object(&message_id),
+                    JValue::Object(&scaling),
+                ],
+            )?
+            .l()?;
+
+        let registry = damage_type_registry()?;
+        let j_name = env.new_string(name)?;
+        let key = env
+            .call_method(
+                &registry,
+                "register",
+                "(Ljava/lang/String;Ljava/lang/Object;)Lnet/minestom/server/registry/DynamicRegistry$Key;",
+                &[JValue::Object(&j_name), JValue::Object(&damage_type)],
+            )?
+            .l()?;
+
+        Ok(DamageType {
+            inner: JavaObject::from_env(&mut env, key)?,
+        })
+    }
+}
+
+/// A single instance of damage dealt to a living entity.
+#[derive(Clone)]
+pub struct Damage {
+    pub(crate) inner: JavaObject,
+}
+
+impl Damage {
+    /// Creates damage without a source, such as fall damage.
+    pub fn new(damage_type: &DamageType, amount: f32) -> Result<Self> {
+        Self::create(damage_type, None, None, None, amount)
+    }
+
+    /// Creates damage dealt by an entity, e.g. a melee hit.
+    pub fn from_entity(
+        damage_type: &DamageType,
+        attacker: &dyn EntityLike,
+        amount: f32,
+    ) -> Result<Self> {
+        Self::create(
+            damage_type,
+            Some(attacker),
+            Some(attacker),
+            Some(&attacker.get_position()?),
+            amount,
+        )
+    }
+
+    /// Creates damage dealt by a `source` entity on behalf of an `attacker`,
+    /// e.g. an arrow shot by a player.
+    pub fn from_projectile(
+        damage_type: &DamageType,
+        source: &dyn EntityLike,
+        attacker: Option<&dyn EntityLike>,
+        amount: f32,
+    ) -> Result<Self> {
+        Self::create(
+            damage_type,
+            Some(source),
+            attacker,
+            Some(&source.get_position()?),
+            amount,
+        )
+    }
+
+    fn create(
+        damage_type: &DamageType,
+        source: Option<&dyn EntityLike>,
+        attacker: Option<&dyn EntityLike>,
+        source_position: Option<&Position>,
+        amount: f32,
+    ) -> Result<Self> {
+        let mut env = get_env()?;
+        let source = match source {
+            Some(source) => source.java_object().as_obj()?,
+            None => JObject::null(),
+        };
+        let attacker = match attacker {
+            Some(attacker) => attacker.java_object().as_obj()?,
+            None => JObject::null(),
+        };
+        let source_position = match source_position {
+            Some(position) => Some(position.to_pos()?),
+            None => None,
+        };
+        let source_position = match &source_position {
+            Some(pos) => pos.inner()?,
+            None => JObject::null(),
+        };
+        let damage = env.new_object(
+            "net/minestom/server/entity/damage/Damage",
+            "(Lnet/minestom/server/registry/DynamicRegistry$Key;Lnet/minestom/server/entity/Entity;Lnet/minestom/server/entity/Entity;Lnet/minestom/server/coordinate/Point;F)V",
+            &[
+                JValue::Object(&damage_type.inner.as_obj()?),
+                JValue::Object(&source),
+                JValue::Object(&attacker),
+                JValue::Object(&source_position),
+                JValue::Float(amount),
+            ],
+        )?;
+        Ok(Self {
+            inner: JavaObject::from_env(&mut env, damage)?,
+        })
+    }
+
+    pub fn damage_type(&self) -> Result<DamageType> {
+        let inner = self.inner.call_object_method(
+            "getType",
+            "()Lnet/minestom/server/registry/DynamicRegistry$Key;",
+            &[],
+        )?;
+        Ok(DamageType { inner })
+    }
+
+    /// Gets the amount of damage, in half hearts.
+    pub fn amount(&self) -> Result<f32> {
+        self.inner.call_float_method("getAmount", "()F", &[])
+    }
+
+    pub fn set_amount(&self, amount: f32) -> Result<()> {
+        self.inner
+            .call_void_method("setAmount", "(F)V", &[JniValue::Float(amount)])
+    }
+
+    /// Gets the entity which directly dealt the damage, e.g. an arrow.
+    pub fn source(&self) -> Result<Option<Entity>> {
+        self.entity("getSource")
+    }
+
+    /// Gets the entity responsible for the damage, e.g. the player who shot the arrow.
+    pub fn attacker(&self) -> Result<Option<Entity>> {
+        self.entity("getAttacker")
+    }
+
+    fn entity(&self, getter: &str) -> Result<Option<Entity>> {
+        let entity =
+            self.inner
+                .call_object_method(getter, "()Lnet/minestom/server/entity/Entity;", &[])?;
+        if entity.as_obj()?.is_null() {
+            Ok(None)
+        } else {
+            Ok(Some(Entity::new(entity)))
+        }
+    }
+}
+
+/// What to do with damage intercepted by
+/// [`EntityCreature::damage`](crate::entity::EntityCreature::damage).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DamageOutcome {
+    /// Applies the damage unchanged
+    PassThrough,
+    /// Applies the damage with another amount
+    Modify(f32),
+    /// Ignores the damage, no `EntityDamageEvent` is called
+    Cancel,
+}
@@ -0,0 +1,225 @@
+use jni::objects::{JObject, JString, JValue};
+
+use crate::Result;
+use crate::error::MinestomError;
+use crate::jni_utils::{JavaObject, get_env};
+
+/// Key of a dimension type registered in the server's dimension type registry.
+#[derive(Debug, Clone)]
+pub struct DimensionKey {
+    pub(crate) inner: JavaObject,
+}
+
+impl DimensionKey {
+    /// The vanilla overworld dimension type.
+    pub fn overworld() -> Result<Self> {
+        Self::vanilla("OVERWORLD")
+    }
+
+    /// The vanilla nether dimension type.
+    pub fn the_nether() -> Result<Self> {
+        Self::vanilla("THE_NETHER")
+    }
+
+    /// The vanilla end dimension type.
+    pub fn the_end() -> Result<Self> {
+        Self::vanilla("THE_END")
+    }
+
+    fn vanilla(field: &str) -> Result<Self> {
+        let mut env = get_env()?;
+        let key = env
+            .get_static_field(
+                "net/minestom/server/world/DimensionType",
+                field,
+                "Lnet/minestom/server/registry/DynamicRegistry$Key;",
+            )?
+            .l()?;
+        Ok(Self {
+            inner: JavaObject::from_env(&mut env, key)?,
+        })
+    }
+
+    /// Namespaced name of the dimension type, e.g. `minecraft:overworld`
+    pub fn name(&self) -> Result<String> {
+        let mut env = get_env()?;
+        let name = self
+            .inner
+            .call_object_method("name", "()Ljava/lang/String;", &[])?;
+        let name = JString::from(env.new_local_ref(name.as_obj()?)?);
+        Ok(env.get_string(&name)?.into())
+    }
+}
+
+/// Properties of a custom dimension type.
+///
+/// The default values are the ones of the vanilla overworld.
+///
+/// # Example
+/// ```rust,no_run
+/// let night = DimensionType {
+///     height: 128,
+///     logical_height: 128,
+///     min_y: 0,
+///     fixed_time: Some(18000),
+///     ..Default::default()
+/// }
+/// .register("thecrown:lobby_night")?;
+/// let instance = instance_manager.create_instance_container_with_dimension(&night)?;
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DimensionType {
+    /// Total height of the world, must be a multiple of 16
+    pub height: i32,
+    /// Lowest block Y, must be a multiple of 16
+    pub min_y: i32,
+    /// Maximum height to which portals and chorus fruits can bring players
+    pub logical_height: i32,
+    /// Minimum light level, between 0 and 1
+    pub ambient_light: f32,
+    /// Time of day the sky is locked at, if any
+    pub fixed_time: Option<i64>,
+    /// Sky and fog effects, e.g. `minecraft:overworld`, `minecraft:the_nether` or `minecraft:the_end`
+    pub effects: String,
+    pub has_skylight: bool,
+    pub has_ceiling: bool,
+    pub natural: bool,
+    pub ultrawarm: bool,
+    pub coordinate_scale: f64,
+    pub bed_works: bool,
+    pub respawn_anchor_works: bool,
+    pub piglin_safe: bool,
+    pub has_raids: bool,
+}
+
+impl Default for DimensionType {
+    fn default() -> Self {
+        Self {
+            height: 384,
+            min_y: -64,
+            logical_height: 384,
+            ambient_light: 0.0,
+            fixed_time: None,
+            effects: "minecraft:overworld".to_string(),
+            has_skylight: true,
+            has_ceiling: false,
+            natural: true,
+            ultrawarm: false,
+            coordinate_scale: 1.0,
+            bed_works: true,
+            respawn_anchor_works: false,
+            piglin_safe: false,
+            has_raids: true,
+        }
+    }
+}
+
+impl DimensionType {
+    /// Registers this dimension type in the server's dimension type registry.
+    /// Must be called before players join, as registries are sent during configuration.
+    pub fn register(&self, name: &str) -> Result<DimensionKey> {
+        if self.height % 16 != 0 || self.min_y % 16 != 0 {
+            return Err(MinestomError::InvalidArgument(
+                "dimension height and min_y must be multiples of 16".to_string(),
+            ));
+        }
+
+        let mut env = get_env()?;
+        let dimension = self.to_java()?;
+        let registry = env
+            .call_static_method(
+                "net/minestom/server/MinecraftServer",
+                "getDimensionTypeRegistry",
+                "()Lnet/minestom/server/registry/DynamicRegistry;",
+                &[],
+            )?
+            .l()?;
+        let j_name = env.new_string(name)?;
+        let key = env
+            .call_method(
+                &registry,
+                "register",
+                "(Ljava/lang/String;Ljava/lang/Object;)Lnet/minestom/server/registry/DynamicRegistry$Key;",
+                &[JValue::Object(&j_name), JValue::Object(&dimension.as_obj()?)],
+            )?
+            .l()?;
+
+        Ok(DimensionKey {
+            inner: JavaObject::from_env(&mut env, key)?,
+        })
+    }
+
+    fn to_java(&self) -> Result<JavaObject> {
+        let mut env = get_env()?;
+        let builder = env
+            .call_static_method(
+                "net/minestom/server/world/DimensionType",
+                "builder",
+                "()Lnet/minestom/server/world/DimensionType$Builder;",
+                &[],
+            )?
+            .l()?;
+
+        const BUILDER: &str = "Lnet/minestom/server/world/DimensionType$Builder;";
+        let mut set = |name: &str, arg: &str, value: JValue| -> Result<()> {
+            env.call_method(&builder, name, format!("({}){}", arg, BUILDER), &[value])?;
+            Ok(())
+        };
+        set("height", "I", JValue::Int(self.height))?;
+        set("minY", "I", JValue::Int(self.min_y))?;
+        set("logicalHeight", "I", JValue::Int(self.logical_height))?;
+        set("ambientLight", "F", JValue::Float(self.ambient_light))?;
+        set("hasSkylight", "Z", JValue::Bool(self.has_skylight as u8))?;
+        set("hasCeiling", "Z", JValue::Bool(self.has_ceiling as u8))?;
+        set("natural", "Z", JValue::Bool(self.natural as u8))?;
+        set("ultrawarm", "Z", JValue::Bool(self.ultrawarm as u8))?;
+        set(
+            "coordinateScale",
+            "D",
+            JValue::Double(self.coordinate_scale),
+        )?;
+        set("bedWorks", "Z", JValue::Bool(self.bed_works as u8))?;
+        set(
+            "respawnAnchorWorks",
+            "Z",
+            JValue::Bool(self.respawn_anchor_works as u8),
+        )?;
+        set("piglinSafe", "Z", JValue::Bool(self.piglin_safe as u8))?;
+        set("hasRaids", "Z", JValue::Bool(self.has_raids as u8))?;
+
+        let effects = env.new_string(&self.effects)?;
+        env.call_method(
+            &builder,
+            "effects",
+            format!("(Ljava/lang/String;){}", BUILDER),
+            &[JValue::Object(&effects)],
+        )?;
+
+        if let Some(fixed_time) = self.fixed_time {
+            let boxed = env
+                .call_static_method(
+                    "java/lang/Long",
+                    "valueOf",
+                    "(J)Ljava/lang/Long;",
+                    &[JValue::Long(fixed_time)],
+                )?
+                .l()?;
+            env.call_method(
+                &builder,
+                "fixedTime",
+                format!("(Ljava/lang/Long;){}", BUILDER),
+                &[JValue::Object(&boxed)],
+            )?;
+        }
+
+        let dimension: JObject = env
+            .call_method(
+                &builder,
+                "build",
+                "()Lnet/minestom/server/world/DimensionType;",
+                &[],
+            )?
+            .l()?;
+        JavaObject::from_env(&mut env, dimension)
+    }
+}
@@ -0,0 +1,675 @@
+use crate::Result;
+use crate::coordinate::Position;
+use crate::entity::MinestomEntityCreature;
+use crate::entity::entity::{Entity, EntityLike};
+use crate::instance::Instance;
+use crate::jni_utils::{JavaObject, JniValue, get_env};
+use jni::JNIEnv;
+use jni::objects::{JClass, JObject, JValue};
+use jni::sys::{jboolean, jlong, jobject};
+use log::error;
+use once_cell::sync::Lazy;
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc, Mutex, RwLock,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+
+/// Behaviour of a creature implemented in Rust, such as wandering or attacking.
+///
+/// Each [`EntityAIGroup`] runs at most one goal at a time: the first goal, in the
+/// order they were added, whose `should_start` returns true is started, then
+/// ticked until `should_end` returns true.
+pub trait Goal: Send + Sync + 'static {
+    /// Called every tick while the goal is not running
+    fn should_start(&self, creature: &MinestomEntityCreature) -> bool;
+
+    fn start(&self, creature: &MinestomEntityCreature);
+
+    /// Called every tick while the goal is running
+    fn tick(&self, creature: &MinestomEntityCreature, time: i64);
+
+    /// Called every tick while the goal is running, after `tick`
+    fn should_end(&self, creature: &MinestomEntityCreature) -> bool;
+
+    fn end(&self, creature: &MinestomEntityCreature);
+}
+
+/// Chooses the entity a creature should target, implemented in Rust.
+pub trait Target: Send + Sync + 'static {
+    fn find_target(&self, creature: &MinestomEntityCreature) -> Option<Entity>;
+}
+
+/// Closure called when a creature reaches its destination
+type NavigationCallback = Box<dyn FnOnce() + Send>;
+
+// Registries mapping callback IDs → the Rust implementation,
+// entries are freed once the Java callback object is collected
+static GOAL_REGISTRY: Lazy<RwLock<HashMap<u64, Arc<dyn Goal>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+static TARGET_REGISTRY: Lazy<RwLock<HashMap<u64, Arc<dyn Target>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+static NAVIGATION_CALLBACKS: Lazy<Mutex<HashMap<u64, NavigationCallback>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+// Callback of the path each creature is walking, by entity id
+static PENDING_PATHS: Lazy<Mutex<HashMap<i32, u64>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+static NEXT_AI_ID: AtomicU64 = AtomicU64::new(1);
+
+fn java_duration(duration: Duration) -> Result<JavaObject> {
+    let mut env = get_env()?;
+    let duration = env
+        .call_static_method(
+            "java/time/Duration",
+            "ofMillis",
+            "(J)Ljava/time/Duration;",
+            &[JValue::Long(duration.as_millis() as i64)],
+        )?
+        .l()?;
+    JavaObject::from_env(&mut env, duration)
+}
+
+/// A goal of a creature, either built into Minestom or implemented in Rust.
+#[derive(Clone)]
+pub struct GoalSelector {
+    inner: JavaObject,
+}
+
+impl GoalSelector {
+    /// Creates a goal running a Rust implementation.
+    pub fn custom(creature: &MinestomEntityCreature, goal: Arc<dyn Goal>) -> Result<Self> {
+        let id = NEXT_AI_ID.fetch_add(1, Ordering::SeqCst);
+        GOAL_REGISTRY.write().unwrap().insert(id, goal);
+
+        let mut env = get_env()?;
+        let obj = env.new_object(
+            "rust/minestom/GoalSelectorCallback",
+            "(JLnet/minestom/server/entity/EntityCreature;)V",
+            &[
+                JValue::Long(id as i64),
+                JValue::Object(&creature.inner.as_obj()?),
+            ],
+        )?;
+        Ok(Self {
+            inner: JavaObject::from_env(&mut env, obj)?,
+        })
+    }
+
+    /// Walks to random positions within `radius` blocks.
+    pub fn random_stroll(creature: &MinestomEntityCreature, radius: i32) -> Result<Self> {
+        Self::builtin(
+            "net/minestom/server/entity/ai/goal/RandomStrollGoal",
+            "(Lnet/minestom/server/entity/EntityCreature;I)V",
+            &[
+                JniValue::Object(creature.inner.as_obj()?),
+                JniValue::Int(radius),
+            ],
+        )
+    }
+
+    /// Turns around randomly, `chance_per_second` out of 100.
+    pub fn random_look_around(
+        creature: &MinestomEntityCreature,
+        chance_per_second: i32,
+    ) -> Result<Self> {
+        Self::builtin(
+            "net/minestom/server/entity/ai/goal/RandomLookAroundGoal",
+            "(Lnet/minestom/server/entity/EntityCreature;I)V",
+            &[
+                JniValue::Object(creature.inner.as_obj()?),
+                JniValue::Int(chance_per_second),
+            ],
+        )
+    }
+
+    /// Walks to the target and attacks it when within `range`, at most once per `delay`.
+    ///
+    /// Attacks only call an `EntityAttackEvent`, damage must be dealt by a listener.
+    pub fn melee_attack(
+        creature: &MinestomEntityCreature,
+        range: f64,
+        delay: Duration,
+    ) -> Result<Self> {
+        Self::builtin(
+            "net/minestom/server/entity/ai/goal/MeleeAttackGoal",
+            "(Lnet/minestom/server/entity/EntityCreature;DLjava/time/Duration;)V",
+            &[
+                JniValue::Object(creature.inner.as_obj()?),
+                JniValue::Double(range),
+                JniValue::Object(java_duration(delay)?.as_obj()?),
+            ],
+        )
+    }
+
+    /// Walks to the target, computing a new path every `path_duration`.
+    pub fn follow_target(
+        creature: &MinestomEntityCreature,
+        path_duration: Duration,
+    ) -> Result<Self> {
+        Self::builtin(
+            "net/minestom/server/entity/ai/goal/FollowTargetGoal",
+            "(Lnet/minestom/server/entity/EntityCreature;Ljava/time/Duration;)V",
+            &[
+                JniValue::Object(creature.inner.as_obj()?),
+                JniValue::Object(java_duration(path_duration)?.as_obj()?),
+            ],
+        )
+    }
+
+    fn builtin(class: &str, sig: &str, args: &[JniValue]) -> Result<Self> {
+        let mut env = get_env()?;
+        let args: Vec<JValue> = args.iter().map(|arg| arg.as_jvalue()).collect();
+        let obj = env.new_object(class, sig, &args)?;
+        Ok(Self {
+            inner: JavaObject::from_env(&mut env, obj)?,
+        })
+    }
+}
+
+/// Finds the target of a creature, used by goals such as [`GoalSelector::melee_attack`].
+#[derive(Clone)]
+pub struct TargetSelector {
+    inner: JavaObject,
+}
+
+impl TargetSelector {
+    /// Creates a target selector running a Rust implementation.
+    pub fn custom(creature: &MinestomEntityCreature, target: Arc<dyn Target>) -> Result<Self> {
+        let id = NEXT_AI_ID.fetch_add(1, Ordering::SeqCst);
+        TARGET_REGISTRY.write().unwrap().insert(id, target);
+
+        let mut env = get_env()?;
+        let obj = env.new_object(
+            "rust/minestom/TargetSelectorCallback",
+            "(JLnet/minestom/server/entity/EntityCreature;)V",
+            &[
+                JValue::Long(id as i64),
+                JValue::Object(&creature.inner.as_obj()?),
+            ],
+        )?;
+        Ok(Self {
+            inner: JavaObject::from_env(&mut env, obj)?,
+        })
+    }
+
+    /// Targets the last entity which damaged the creature, if within `range`.
+    pub fn last_entity_damager(creature: &MinestomEntityCreature, range: f32) -> Result<Self> {
+        let mut env = get_env()?;
+        let obj = env.new_object(
+            "net/minestom/server/entity/ai/target/LastEntityDamagerTarget",
+            "(Lnet/minestom/server/entity/EntityCreature;F)V",
+            &[
+                JValue::Object(&creature.inner.as_obj()?),
+                JValue::Float(range),
+            ],
+        )?;
+        Ok(Self {
+            inner: JavaObject::from_env(&mut env, obj)?,
+        })
+    }
+
+    /// Targets the closest entity within `range` accepted by `filter`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// let players = TargetSelector::closest_entity(&creature, 16.0, |entity| {
+    ///     entity.is_player().unwrap_or(false)
+    /// })?;
+    /// ```
+    pub fn closest_entity<F>(
+        creature: &MinestomEntityCreature,
+        range: f64,
+        filter: F,
+    ) -> Result<Self>
+    where
+        F: Fn(&Entity) -> bool + Send + Sync + 'static,
+    {
+        Self::custom(creature, Arc::new(ClosestEntity { range, filter }))
+    }
+}
+
+struct ClosestEntity<F> {
+    range: f64,
+    filter: F,
+}
+
+impl<F: Fn(&Entity) -> bool + Send + Sync + 'static> ClosestEntity<F> {
+    fn find(&self, creature: &MinestomEntityCreature) -> Result<Option<Entity>> {
+        let Some(instance) = creature.get_instance()? else {
+            return Ok(None);
+        };
+        let position = creature.get_position()?;
+        let own_id = creature.as_entity().get_entity_id()?;
+
+        let mut closest: Option<(f64, Entity)> = None;
+        for entity in instance.get_nearby_entities(&position, self.range)? {
+            if entity.get_entity_id()? == own_id || entity.is_removed()? || !(self.filter)(&entity)
+            {
+                continue;
+            }
+            let other = entity.get_position()?;
+            let (dx, dy, dz) = (
+                other.x - position.x,
+                other.y - position.y,
+                other.z - position.z,
+            );
+            let distance = dx * dx + dy * dy + dz * dz;
+            if closest.as_ref().is_none_or(|(d, _)| distance < *d) {
+                closest = Some((distance, entity));
+            }
+        }
+        Ok(closest.map(|(_, entity)| entity))
+    }
+}
+
+impl<F: Fn(&Entity) -> bool + Send + Sync + 'static> Target for ClosestEntity<F> {
+    fn find_target(&self, creature: &MinestomEntityCreature) -> Option<Entity> {
+        self.find(creature).unwrap_or_else(|e| {
+            error!("Failed to find the closest entity: {}", e);
+            None
+        })
+    }
+}
+
+/// A set of goals and target selectors, see [`MinestomEntityCreature::add_ai_group`].
+///
+/// # Example
+/// ```rust,no_run
+/// let group = EntityAIGroup::new()?;
+/// group.add_goal_selector(&GoalSelector::melee_attack(&creature, 1.5, Duration::from_secs(1))?)?;
+/// group.add_goal_selector(&GoalSelector::follow_target(&creature, Duration::from_millis(500))?)?;
+/// group.add_goal_selector(&GoalSelector::random_stroll(&creature, 10)?)?;
+/// group.add_target_selector(&TargetSelector::closest_entity(&creature, 16.0, |entity| {
+///     entity.is_player().unwrap_or(false)
+/// })?)?;
+/// creature.add_ai_group(&group)?;
+/// ```
+#[derive(Clone)]
+pub struct EntityAIGroup {
+    inner: JavaObject,
+}
+
+impl EntityAIGroup {
+    pub fn new() -> Result<Self> {
+        let mut env = get_env()?;
+        let obj = env.new_object("net/minestom/server/entity/ai/EntityAIGroup", "()V", &[])?;
+        Ok(Self {
+            inner: JavaObject::from_env(&mut env, obj)?,
+        })
+    }
+
+    /// Adds a goal, goals added first have priority.
+    pub fn add_goal_selector(&self, goal: &GoalSelector) -> Result<()> {
+        let goals = self
+            .inner
+            .call_object_method("getGoalSelectors", "()Ljava/util/List;", &[])?;
+        goals.call_bool_method(
+            "add",
+            "(Ljava/lang/Object;)Z",
+            &[JniValue::Object(goal.inner.as_obj()?)],
+        )?;
+        Ok(())
+    }
+
+    /// Adds a target selector, the first one finding a target is used.
+    pub fn add_target_selector(&self, target: &TargetSelector) -> Result<()> {
+        let targets =
+            self.inner
+                .call_object_method("getTargetSelectors", "()Ljava/util/List;", &[])?;
+        targets.call_bool_method(
+            "add",
+            "(Ljava/lang/Object;)Z",
+            &[JniValue::Object(target.inner.as_obj()?)],
+        )?;
+        Ok(())
+    }
+}
+
+/// Pathfinding of a creature.
+#[derive(Clone)]
+pub struct Navigator {
+    inner: JavaObject,
+    entity_id: i32,
+}
+
+impl Navigator {
+    /// Walks to a position. Returns false if no path could be found.
+    pub fn set_path_to(&self, position: &Position) -> Result<bool> {
+        self.forget_pending_path();
+        self.inner.call_bool_method(
+            "setPathTo",
+            "(Lnet/minestom/server/coordinate/Point;)Z",
+            &[JniValue::Object(position.to_pos()?.inner()?)],
+        )
+    }
+
+    /// Walks to a position and calls `on_complete` once within `min_distance` of it.
+    /// Returns false if no path could be found, in which case `on_complete` is never called.
+    /// It is dropped without being called if another path is set or the navigator is stopped.
+    pub fn set_path_to_with_callback<F>(
+        &self,
+        position: &Position,
+        min_distance: f64,
+        on_complete: F,
+    ) -> Result<bool>
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        self.forget_pending_path();
+        let id = NEXT_AI_ID.fetch_add(1, Ordering::SeqCst);
+        NAVIGATION_CALLBACKS
+            .lock()
+            .unwrap()
+            .insert(id, Box::new(on_complete));
+
+        let mut env = get_env()?;
+        let callback = env.new_object(
+            "rust/minestom/RunnableCallback",
+            "(J)V",
+            &[JValue::Long(id as i64)],
+        )?;
+        let found = self.inner.call_bool_method(
+            "setPathTo",
+            "(Lnet/minestom/server/coordinate/Point;DLjava/lang/Runnable;)Z",
+            &[
+                JniValue::Object(position.to_pos()?.inner()?),
+                JniValue::Double(min_distance),
+                JniValue::Object(callback),
+            ],
+        )?;
+        if found {
+            PENDING_PATHS.lock().unwrap().insert(self.entity_id, id);
+        } else {
+            NAVIGATION_CALLBACKS.lock().unwrap().remove(&id);
+        }
+        Ok(found)
+    }
+
+    /// Stops walking.
+    pub fn stop(&self) -> Result<()> {
+        self.forget_pending_path();
+        self.inner.call_bool_method(
+            "setPathTo",
+            "(Lnet/minestom/server/coordinate/Point;)Z",
+            &[JniValue::Object(JObject::null())],
+        )?;
+        Ok(())
+    }
+
+    /// Gets the position being walked to, if any.
+    pub fn get_goal_position(&self) -> Result<Option<Position>> {
+        let goal = self.inner.call_object_method(
+            "getGoalPosition",
+            "()Lnet/minestom/server/coordinate/Point;",
+            &[],
+        )?;
+        if goal.as_obj()?.is_null() {
+            return Ok(None);
+        }
+        Ok(Some(crate::coordinate::Pos::new(goal).to_position()?))
+    }
+
+    /// Drops the callback of the path being replaced, it would never be called
+    fn forget_pending_path(&self) {
+        if let Some(id) = PENDING_PATHS.lock().unwrap().remove(&self.entity_id) {
+            NAVIGATION_CALLBACKS.lock().unwrap().remove(&id);
+        }
+    }
+}
+
+impl MinestomEntityCreature {
+    /// Adds a group of goals, each group runs one goal at a time.
+    pub fn add_ai_group(&self, group: &EntityAIGroup) -> Result<()> {
+        self.inner.call_void_method(
+            "addAIGroup",
+            "(Lnet/minestom/server/entity/ai/EntityAIGroup;)V",
+            &[JniValue::Object(group.inner.as_obj()?)],
+        )
+    }
+
+    pub fn get_navigator(&self) -> Result<Navigator> {
+        let inner = self.inner.call_object_method(
+            "getNavigator",
+            "()Lnet/minestom/server/entity/pathfinding/Navigator;",
+            &[],
+        )?;
+        Ok(Navigator {
+            inner,
+            entity_id: self.as_entity().get_entity_id()?,
+        })
+    }
+
+    /// Gets the current target, set by target selectors or [`MinestomEntityCreature::set_target`].
+    pub fn get_target(&self) -> Result<Option<Entity>> {
+        let target = self.inner.call_object_method(
+            "getTarget",
+            "()Lnet/minestom/server/entity/Entity;",
+            &[],
+        )?;
+        if target.as_obj()?.is_null() {
+            Ok(None)
+        } else {
+            Ok(Some(Entity::new(target)))
+        }
+    }
+
+    pub fn set_target(&self, target: Option<&dyn EntityLike>) -> Result<()> {
+        let target = match target {
+            Some(target) => target.java_object().as_obj()?,
+            None => JObject::null(),
+        };
+        self.inner.call_void_method(
+            "setTarget",
+            "(Lnet/minestom/server/entity/Entity;)V",
+            &[JniValue::Object(target)],
+        )
+    }
+}
+
+fn creature_from_raw(env: &mut JNIEnv, j_creature: jobject) -> Option<MinestomEntityCreature> {
+    match JavaObject::from_env(env, unsafe { JObject::from_raw(j_creature) }) {
+        Ok(inner) => Some(MinestomEntityCreature { inner }),
+        Err(e) => {
+            error!("Failed to wrap creature: {}", e);
+            None
+        }
+    }
+}
+
+/// Looks up a goal and runs `f` with it, catching panics.
+fn with_goal<R>(
+    raw_env: *mut jni::sys::JNIEnv,
+    callback_id: jlong,
+    j_creature: jobject,
+    default: R,
+    f: impl FnOnce(&dyn Goal, &MinestomEntityCreature) -> R,
+) -> R {
+    let goal = match GOAL_REGISTRY.read().unwrap().get(&(callback_id as u64)) {
+        Some(goal) => goal.clone(),
+        None => return default,
+    };
+    let Ok(mut env) = (unsafe { JNIEnv::from_raw(raw_env) }) else {
+        return default;
+    };
+    let Some(creature) = creature_from_raw(&mut env, j_creature) else {
+        return default;
+    };
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f(&*goal, &creature))) {
+        Ok(result) => result,
+        Err(_) => {
+            error!("Panic in goal {}", callback_id);
+            default
+        }
+    }
+}
+
+/// JNI callback: shouldStart()
+#[unsafe(no_mangle)]
+pub unsafe extern "system" fn Java_rust_minestom_GoalSelectorCallback_nativeShouldStart(
+    raw_env: *mut jni::sys::JNIEnv,
+    _class: JClass,
+    callback_id: jlong,
+    j_creature: jobject,
+) -> jboolean {
+    with_goal(raw_env, callback_id, j_creature, false, |goal, creature| {
+        goal.should_start(creature)
+    }) as jboolean
+}
+
+/// JNI callback: start()
+#[unsafe(no_mangle)]
+pub unsafe extern "system" fn Java_rust_minestom_GoalSelectorCallback_nativeStart(
+    raw_env: *mut jni::sys::JNIEnv,
+    _class: JClass,
+    callback_id: jlong,
+    j_creature: jobject,
+) {
+    with_goal(raw_env, callback_id, j_creature, (), |goal, creature| {
+        goal.start(creature)
+    })
+}
+
+/// JNI callback: tick(long time)
+#[unsafe(no_mangle)]
+pub unsafe extern "system" fn Java_rust_minestom_GoalSelectorCallback_nativeTick(
+    raw_env: *mut jni::sys::JNIEnv,
+    _class: JClass,
+    callback_id: jlong,
+    j_creature: jobject,
+    time: jlong,
+) {
+    with_goal(raw_env, callback_id, j_creature, (), |goal, creature| {
+        goal.tick(creature, time)
+    })
+}
+
+/// JNI callback: shouldEnd()
+#[unsafe(no_mangle)]
+pub unsafe extern "system" fn Java_rust_minestom_GoalSelectorCallback_nativeShouldEnd(
+    raw_env: *mut jni::sys::JNIEnv,
+    _class: JClass,
+    callback_id: jlong,
+    j_creature: jobject,
+) -> jboolean {
+    // Stop goals that cannot be evaluated anymore
+    with_goal(raw_env, callback_id, j_creature, true, |goal, creature| {
+        goal.should_end(creature)
+    }) as jboolean
+}
+
+/// JNI callback: end()
+#[unsafe(no_mangle)]
+pub unsafe extern "system" fn Java_rust_minestom_GoalSelectorCallback_nativeEnd(
+    raw_env: *mut jni::sys::JNIEnv,
+    _class: JClass,
+    callback_id: jlong,
+    j_creature: jobject,
+) {
+    with_goal(raw_env, callback_id, j_creature, (), |goal, creature| {
+        goal.end(creature)
+    })
+}
+
+/// JNI callback: findTarget() → Entity
+#[unsafe(no_mangle)]
+pub unsafe extern "system" fn Java_rust_minestom_TargetSelectorCallback_nativeFindTarget(
+    raw_env: *mut jni::sys::JNIEnv,
+    _class: JClass,
+    callback_id: jlong,
+    j_creature: jobject,
+) -> jobject {
+    let null = std::ptr::null_mut();
+    let target = match TARGET_REGISTRY.read().unwrap().get(&(callback_id as u64)) {
+        Some(target) => target.clone(),
+        None => return null,
+    };
+    let Ok(mut env) = (unsafe { JNIEnv::from_raw(raw_env) }) else {
+        return null;
+    };
+    let Some(creature) = creature_from_raw(&mut env, j_creature) else {
+        return null;
+    };
+
+    let found = match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        target.find_target(&creature)
+    })) {
+        Ok(found) => found,
+        Err(_) => {
+            error!("Panic in target selector {}", callback_id);
+            None
+        }
+    };
+    let Some(entity) = found else {
+        return null;
+    };
+    // Hand a new local reference to Java, the global one is released with `entity`
+    match entity
+        .inner
+        .as_obj()
+        .and_then(|obj| Ok(env.new_local_ref(obj)?))
+    {
+        Ok(local) => local.into_raw(),
+        Err(e) => {
+            error!("Failed to return target: {}", e);
+            null
+        }
+    }
+}
+
+/// JNI callback: run()
+#[unsafe(no_mangle)]
+pub unsafe extern "system" fn Java_rust_minestom_RunnableCallback_nativeRun(
+    _raw_env: *mut jni::sys::JNIEnv,
+    _class: JClass,
+    callback_id: jlong,
+) {
+    if let Some(callback) = take_navigation_callback(callback_id as u64)
+        && std::panic::catch_unwind(std::panic::AssertUnwindSafe(callback)).is_err()
+    {
+        error!("Panic in navigation callback {}", callback_id);
+    }
+}
+
+/// Removes a navigation callback, along with the pending path referencing it
+fn take_navigation_callback(callback_id: u64) -> Option<NavigationCallback> {
+    PENDING_PATHS
+        .lock()
+        .unwrap()
+        .retain(|_, pending| *pending != callback_id);
+    NAVIGATION_CALLBACKS.lock().unwrap().remove(&callback_id)
+}
+
+/// JNI callback: the RunnableCallback was collected without running
+#[unsafe(no_mangle)]
+pub unsafe extern "system" fn Java_rust_minestom_RunnableCallback_nativeFree(
+    _raw_env: *mut jni::sys::JNIEnv,
+    _class: JClass,
+    callback_id: jlong,
+) {
+    take_navigation_callback(callback_id as u64);
+}
+
+/// JNI callback: the GoalSelectorCallback was collected
+#[unsafe(no_mangle)]
+pub unsafe extern "system" fn Java_rust_minestom_GoalSelectorCallback_nativeFree(
+    _raw_env: *mut jni::sys::JNIEnv,
+    _class: JClass,
+    callback_id: jlong,
+) {
+    GOAL_REGISTRY.write().unwrap().remove(&(callback_id as u64));
+}
+
+/// JNI callback: the TargetSelectorCallback was collected
+#[unsafe(no_mangle)]
+pub unsafe extern "system" fn Java_rust_minestom_TargetSelectorCallback_nativeFree(
+    _raw_env: *mut jni::sys::JNIEnv,
+    _class: JClass,
+    callback_id: jlong,
+) {
+    TARGET_REGISTRY
+        .write()
+        .unwrap()
+        .remove(&(callback_id as u64));
+}
@@ -0,0 +1,107 @@
+use crate::Result;
+use crate::coordinate::Pos;
+use crate::entity::Player;
+use crate::entity::entity::{Entity, EntityType};
+use crate::jni_utils::{JavaObject, JniValue, collection_to_vec, get_env};
+use jni::objects::JValue;
+
+/// An entity which only exists on the clients of the players it is shown to,
+/// such as a per-player quest marker or a private hologram.
+///
+/// It is never added to an instance, so it does not tick, collide or fire events.
+/// Its metadata is edited through [`ClientSideEntity::as_entity`], followed by
+/// [`ClientSideEntity::refresh_metadata`].
+///
+/// # Example
+/// ```rust,no_run
+/// let marker = ClientSideEntity::new(EntityType::TextDisplay)?;
+/// marker.set_position(&Position::new(10.5, 43.0, 3.5).to_pos()?)?;
+/// marker.as_entity().set_glowing(true)?;
+/// marker.show(&player)?;
+/// ```
+#[derive(Clone)]
+pub struct ClientSideEntity {
+    inner: JavaObject,
+}
+
+impl ClientSideEntity {
+    pub fn new(entity_type: EntityType) -> Result<Self> {
+        let mut env = get_env()?;
+        let java_type = env
+            .get_static_field(
+                "net/minestom/server/entity/EntityType",
+                entity_type.to_java_field(),
+                "Lnet/minestom/server/entity/EntityType;",
+            )?
+            .l()?;
+        let entity = env.new_object(
+            "rust/minestom/ClientSideEntity",
+            "(Lnet/minestom/server/entity/EntityType;)V",
+            &[JValue::Object(&java_type)],
+        )?;
+        Ok(Self {
+            inner: JavaObject::from_env(&mut env, entity)?,
+        })
+    }
+
+    /// Spawns the entity for a player, returns false if it was already shown to them
+    pub fn show(&self, player: &Player) -> Result<bool> {
+        self.inner.call_bool_method(
+            "show",
+            "(Lnet/minestom/server/entity/Player;)Z",
+            &[JniValue::Object(player.inner()?)],
+        )
+    }
+
+    /// Despawns the entity for a player, returns false if it was not shown to them
+    pub fn hide(&self, player: &Player) -> Result<bool> {
+        self.inner.call_bool_method(
+            "hide",
+            "(Lnet/minestom/server/entity/Player;)Z",
+            &[JniValue::Object(player.inner()?)],
+        )
+    }
+
+    pub fn hide_all(&self) -> Result<()> {
+        self.inner.call_void_method("hideAll", "()V", &[])
+    }
+
+    /// Gets the players the entity is shown to
+    pub fn get_shown_to(&self) -> Result<Vec<Player>> {
+        let players = self
+            .inner
+            .call_object_method("getShownTo", "()Ljava/util/Set;", &[])?;
+        Ok(collection_to_vec(&players)?
+            .into_iter()
+            .map(Player::new)
+            .collect())
+    }
+
+    pub fn get_position(&self) -> Result<Pos> {
+        let pos = self.inner.call_object_method(
+            "getClientPosition",
+            "()Lnet/minestom/server/coordinate/Pos;",
+            &[],
+        )?;
+        Ok(Pos::new(pos))
+    }
+
+    /// Moves the entity for every player it is shown to
+    pub fn set_position(&self, pos: &Pos) -> Result<()> {
+        self.inner.call_void_method(
+            "moveTo",
+            "(Lnet/minestom/server/coordinate/Pos;)V",
+            &[JniValue::Object(pos.inner()?)],
+        )
+    }
+
+    /// Gets the underlying entity, to edit its metadata
+    pub fn as_entity(&self) -> Entity {
+        Entity::new(self.inner.clone())
+    }
+
+    /// Sends the metadata to every player the entity is shown to, after editing it
+    pub fn refresh_metadata(&self) -> Result<()> {
+        self.inner.call_void_method("refreshMetadata", "()V", &[])
+    }
+}
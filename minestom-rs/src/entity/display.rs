@@ -1,55 +1,382 @@
+use crate::Player;
 use crate::Result;
+use crate::block::Block;
 use crate::collision::BoundingBox;
+use crate::coordinate::Position;
+use crate::entity::EntityLike;
+use crate::entity::tween::{self, Easing, Transform, Tween, TweenHandle};
+use crate::event::entity::EntityAttackEvent;
+use crate::event::player::{Hand, PlayerEntityInteractEvent};
+use crate::event::{EventNode, ListenerHandle};
 use crate::instance::Instance;
 use crate::item::ItemStack;
 use crate::jni_utils::{JavaObject, JniValue, get_env};
-use jni::objects::JValue;
+use crate::text::Component;
+use jni::objects::{JFloatArray, JValue};
 
-pub struct ItemDisplay {
-    inner: JavaObject,
+fn new_display_entity(entity_type: &str) -> Result<JavaObject> {
+    let mut env = get_env()?;
+    let entity_type = env
+        .get_static_field(
+            "net/minestom/server/entity/EntityType",
+            entity_type,
+            "Lnet/minestom/server/entity/EntityType;",
+        )?
+        .l()?;
+    let entity = env.new_object(
+        "net/minestom/server/entity/Entity",
+        "(Lnet/minestom/server/entity/EntityType;)V",
+        &[JValue::Object(&entity_type)],
+    )?;
+    JavaObject::from_env(&mut env, entity)
 }
 
-impl ItemDisplay {
-    /// Creates a new ItemDisplay with the given item
-    pub fn new(item: &ItemStack) -> Result<Self> {
-        let mut env = get_env()?;
+fn display_meta(entity: &JavaObject) -> Result<JavaObject> {
+    entity.call_object_method(
+        "getEntityMeta",
+        "()Lnet/minestom/server/entity/metadata/EntityMeta;",
+        &[],
+    )
+}
 
-        // Get the EntityType for ItemDisplay
-        let entity_type_class = env.find_class("net/minestom/server/entity/EntityType")?;
-        let entity_type = env.get_static_field(
-            entity_type_class,
-            "ITEM_DISPLAY",
-            "Lnet/minestom/server/entity/EntityType;",
+/// How a display entity rotates to face players.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Billboard {
+    /// Keeps its own rotation
+    Fixed,
+    /// Pivots around the vertical axis
+    Vertical,
+    /// Pivots around the horizontal axis
+    Horizontal,
+    /// Always faces the player
+    Center,
+}
+
+impl Billboard {
+    fn to_java_field(self) -> &'static str {
+        match self {
+            Billboard::Fixed => "FIXED",
+            Billboard::Vertical => "VERTICAL",
+            Billboard::Horizontal => "HORIZONTAL",
+            Billboard::Center => "CENTER",
+        }
+    }
+}
+
+/// Transform applied to the item of an [`ItemDisplay`], as used by item models.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemDisplayTransform {
+    None,
+    ThirdPersonLeftHand,
+    ThirdPersonRightHand,
+    FirstPersonLeftHand,
+    FirstPersonRightHand,
+    Head,
+    Gui,
+    Ground,
+    Fixed,
+}
+
+impl ItemDisplayTransform {
+    fn to_java_field(self) -> &'static str {
+        match self {
+            ItemDisplayTransform::None => "NONE",
+            ItemDisplayTransform::ThirdPersonLeftHand => "THIRD_PERSON_LEFT_HAND",
+            ItemDisplayTransform::ThirdPersonRightHand => "THIRD_PERSON_RIGHT_HAND",
+            ItemDisplayTransform::FirstPersonLeftHand => "FIRST_PERSON_LEFT_HAND",
+            ItemDisplayTransform::FirstPersonRightHand => "FIRST_PERSON_RIGHT_HAND",
+            ItemDisplayTransform::Head => "HEAD",
+            ItemDisplayTransform::Gui => "GUI",
+            ItemDisplayTransform::Ground => "GROUND",
+            ItemDisplayTransform::Fixed => "FIXED",
+        }
+    }
+}
+
+/// Common metadata of [`ItemDisplay`], [`TextDisplay`] and [`BlockDisplay`].
+///
+/// Transformations are applied in the order translation, left rotation, scale, right rotation.
+/// Rotations are quaternions given as `[x, y, z, w]`.
+pub trait DisplayLike: EntityLike {
+    /// Offsets the rendered model from the entity position
+    fn set_translation(&self, x: f32, y: f32, z: f32) -> Result<()> {
+        let mut env = get_env()?;
+        let vec = env.new_object(
+            "net/minestom/server/coordinate/Vec",
+            "(DDD)V",
+            &[
+                JValue::Double(x as f64),
+                JValue::Double(y as f64),
+                JValue::Double(z as f64),
+            ],
         )?;
+        display_meta(self.java_object())?.call_void_method(
+            "setTranslation",
+            "(Lnet/minestom/server/coordinate/Point;)V",
+            &[JniValue::Object(vec)],
+        )
+    }
 
-        // Create a new Entity with ITEM_DISPLAY type
-        let entity_class = env.find_class("net/minestom/server/entity/Entity")?;
-        let entity_type_obj = entity_type.l()?;
-        let entity = env.new_object(
-            entity_class,
-            "(Lnet/minestom/server/entity/EntityType;)V",
-            &[JValue::Object(&entity_type_obj)],
+    fn set_scale(&self, x: f32, y: f32, z: f32) -> Result<()> {
+        let mut env = get_env()?;
+        let vec = env.new_object(
+            "net/minestom/server/coordinate/Vec",
+            "(DDD)V",
+            &[
+                JValue::Double(x as f64),
+                JValue::Double(y as f64),
+                JValue::Double(z as f64),
+            ],
         )?;
+        display_meta(self.java_object())?.call_void_method(
+            "setScale",
+            "(Lnet/minestom/server/coordinate/Vec;)V",
+            &[JniValue::Object(vec)],
+        )
+    }
 
-        // Get the ItemDisplayMeta
-        let meta = env.call_method(
-            &entity,
-            "getEntityMeta",
-            "()Lnet/minestom/server/entity/metadata/EntityMeta;",
+    fn set_left_rotation(&self, rotation: [f32; 4]) -> Result<()> {
+        set_rotation(self.java_object(), "setLeftRotation", rotation)
+    }
+
+    fn set_right_rotation(&self, rotation: [f32; 4]) -> Result<()> {
+        set_rotation(self.java_object(), "setRightRotation", rotation)
+    }
+
+    fn set_billboard(&self, billboard: Billboard) -> Result<()> {
+        let mut env = get_env()?;
+        let constraints = env
+            .get_static_field(
+                "net/minestom/server/entity/metadata/display/AbstractDisplayMeta$BillboardConstraints",
+                billboard.to_java_field(),
+                "Lnet/minestom/server/entity/metadata/display/AbstractDisplayMeta$BillboardConstraints;",
+            )?
+            .l()?;
+        display_meta(self.java_object())?.call_void_method(
+            "setBillboardRenderConstraints",
+            "(Lnet/minestom/server/entity/metadata/display/AbstractDisplayMeta$BillboardConstraints;)V",
+            &[JniValue::Object(constraints)],
+        )
+    }
+
+    /// Overrides the light levels the display is rendered with, from 0 to 15
+    fn set_brightness(&self, block_light: i32, sky_light: i32) -> Result<()> {
+        display_meta(self.java_object())?.call_void_method(
+            "setBrightness",
+            "(II)V",
+            &[JniValue::Int(block_light), JniValue::Int(sky_light)],
+        )
+    }
+
+    /// Multiplier of the distance at which the display is rendered, 1.0 being 64 blocks
+    fn set_view_range(&self, view_range: f32) -> Result<()> {
+        display_meta(self.java_object())?.call_void_method(
+            "setViewRange",
+            "(F)V",
+            &[JniValue::Float(view_range)],
+        )
+    }
+
+    /// Sets the radius and opacity, from 0 to 1, of the shadow below the display
+    fn set_shadow(&self, radius: f32, strength: f32) -> Result<()> {
+        let meta = display_meta(self.java_object())?;
+        meta.call_void_method("setShadowRadius", "(F)V", &[JniValue::Float(radius)])?;
+        meta.call_void_method("setShadowStrength", "(F)V", &[JniValue::Float(strength)])
+    }
+
+    /// Gets the current transformation, ignoring any interpolation in progress
+    fn get_transform(&self) -> Result<Transform> {
+        let meta = display_meta(self.java_object())?;
+        let translation = meta.call_object_method(
+            "getTranslation",
+            "()Lnet/minestom/server/coordinate/Point;",
             &[],
         )?;
+        let scale =
+            meta.call_object_method("getScale", "()Lnet/minestom/server/coordinate/Vec;", &[])?;
+        Ok(Transform {
+            translation: get_vec(&translation)?,
+            scale: get_vec(&scale)?,
+            left_rotation: get_rotation(&meta, "getLeftRotation")?,
+            right_rotation: get_rotation(&meta, "getRightRotation")?,
+        })
+    }
 
-        // Set the item stack on the meta
-        env.call_method(
-            meta.l()?,
+    /// Sets every transformation at once, without interpolation
+    fn set_transform(&self, transform: &Transform) -> Result<()> {
+        apply_transform(self.java_object(), transform, 0)
+    }
+
+    /// Makes the next transformation changes interpolate over `duration` ticks,
+    /// starting `start_delay` ticks after they are received
+    fn set_interpolation(&self, duration: i32, start_delay: i32) -> Result<()> {
+        let meta = display_meta(self.java_object())?;
+        meta.call_void_method(
+            "setTransformationInterpolationDuration",
+            "(I)V",
+            &[JniValue::Int(duration)],
+        )?;
+        meta.call_void_method(
+            "setTransformationInterpolationStartDelta",
+            "(I)V",
+            &[JniValue::Int(start_delay)],
+        )
+    }
+
+    /// Makes teleports interpolate over `duration` ticks, at most 59
+    fn set_teleport_duration(&self, duration: i32) -> Result<()> {
+        display_meta(self.java_object())?.call_void_method(
+            "setPosRotInterpolationDuration",
+            "(I)V",
+            &[JniValue::Int(duration)],
+        )
+    }
+
+    /// Animates the transformation from its current value to `transform` in `ticks` ticks
+    fn animate_to(&self, transform: &Transform, ticks: u32, easing: Easing) -> Result<TweenHandle> {
+        self.play(Tween::new().then(*transform, ticks, easing))
+    }
+
+    /// Plays a tween, starting from the current transformation.
+    /// Tweens played at the same time on one entity conflict, cancel the previous one first.
+    fn play(&self, tween: Tween) -> Result<TweenHandle> {
+        tween::play(self.java_object().clone(), self.get_transform()?, tween)
+    }
+
+    /// Overrides the team color of the glowing outline with an RGB color, `None` resets it
+    fn set_glow_color_override(&self, color: Option<i32>) -> Result<()> {
+        display_meta(self.java_object())?.call_void_method(
+            "setGlowColorOverride",
+            "(I)V",
+            &[JniValue::Int(color.unwrap_or(-1))],
+        )
+    }
+}
+
+/// Sets a transformation in a single metadata update, interpolated over `interpolation` ticks
+pub(crate) fn apply_transform(
+    entity: &JavaObject,
+    transform: &Transform,
+    interpolation: i32,
+) -> Result<()> {
+    let mut env = get_env()?;
+    let meta = display_meta(entity)?;
+    meta.call_void_method("setNotifyAboutChanges", "(Z)V", &[JniValue::Bool(false)])?;
+    let result = (|| {
+        meta.call_void_method(
+            "setTransformationInterpolationDuration",
+            "(I)V",
+            &[JniValue::Int(interpolation)],
+        )?;
+        meta.call_void_method(
+            "setTransformationInterpolationStartDelta",
+            "(I)V",
+            &[JniValue::Int(0)],
+        )?;
+        let [x, y, z] = transform.translation;
+        let translation = env.new_object(
+            "net/minestom/server/coordinate/Vec",
+            "(DDD)V",
+            &[
+                JValue::Double(x as f64),
+                JValue::Double(y as f64),
+                JValue::Double(z as f64),
+            ],
+        )?;
+        meta.call_void_method(
+            "setTranslation",
+            "(Lnet/minestom/server/coordinate/Point;)V",
+            &[JniValue::Object(translation)],
+        )?;
+        let [x, y, z] = transform.scale;
+        let scale = env.new_object(
+            "net/minestom/server/coordinate/Vec",
+            "(DDD)V",
+            &[
+                JValue::Double(x as f64),
+                JValue::Double(y as f64),
+                JValue::Double(z as f64),
+            ],
+        )?;
+        meta.call_void_method(
+            "setScale",
+            "(Lnet/minestom/server/coordinate/Vec;)V",
+            &[JniValue::Object(scale)],
+        )?;
+        set_rotation(entity, "setLeftRotation", transform.left_rotation)?;
+        set_rotation(entity, "setRightRotation", transform.right_rotation)
+    })();
+    // Sends the changes made since notifications were disabled
+    meta.call_void_method("setNotifyAboutChanges", "(Z)V", &[JniValue::Bool(true)])?;
+    result
+}
+
+fn get_vec(point: &JavaObject) -> Result<[f32; 3]> {
+    Ok([
+        point.call_double_method("x", "()D", &[])? as f32,
+        point.call_double_method("y", "()D", &[])? as f32,
+        point.call_double_method("z", "()D", &[])? as f32,
+    ])
+}
+
+fn get_rotation(meta: &JavaObject, getter: &str) -> Result<[f32; 4]> {
+    let env = get_env()?;
+    let array = meta.call_object_method(getter, "()[F", &[])?;
+    let array = JFloatArray::from(env.new_local_ref(array.as_obj()?)?);
+    let mut rotation = [0.0; 4];
+    env.get_float_array_region(&array, 0, &mut rotation)?;
+    Ok(rotation)
+}
+
+fn set_rotation(entity: &JavaObject, setter: &str, rotation: [f32; 4]) -> Result<()> {
+    let env = get_env()?;
+    let array = env.new_float_array(4)?;
+    env.set_float_array_region(&array, 0, &rotation)?;
+    display_meta(entity)?.call_void_method(setter, "([F)V", &[JniValue::Object(array.into())])
+}
+
+pub struct ItemDisplay {
+    inner: JavaObject,
+}
+
+impl ItemDisplay {
+    /// Creates a new ItemDisplay with the given item
+    pub fn new(item: &ItemStack) -> Result<Self> {
+        let display = Self {
+            inner: new_display_entity("ITEM_DISPLAY")?,
+        };
+        display.set_item(item)?;
+        Ok(display)
+    }
+
+    /// Wraps an existing item display entity
+    pub(crate) fn from_entity(inner: JavaObject) -> Self {
+        Self { inner }
+    }
+
+    pub fn set_item(&self, item: &ItemStack) -> Result<()> {
+        display_meta(&self.inner)?.call_void_method(
             "setItemStack",
             "(Lnet/minestom/server/item/ItemStack;)V",
-            &[JValue::Object(&item.as_obj().as_obj()?)],
-        )?;
+            &[JniValue::Object(item.as_obj().as_obj()?)],
+        )
+    }
 
-        Ok(Self {
-            inner: JavaObject::from_env(&mut env, entity)?,
-        })
+    /// Sets how the item is rendered, as if held, worn or placed in an item frame
+    pub fn set_transform(&self, transform: ItemDisplayTransform) -> Result<()> {
+        let mut env = get_env()?;
+        let context = env
+            .get_static_field(
+                "net/minestom/server/entity/metadata/display/ItemDisplayMeta$DisplayContext",
+                transform.to_java_field(),
+                "Lnet/minestom/server/entity/metadata/display/ItemDisplayMeta$DisplayContext;",
+            )?
+            .l()?;
+        display_meta(&self.inner)?.call_void_method(
+            "setDisplayContext",
+            "(Lnet/minestom/server/entity/metadata/display/ItemDisplayMeta$DisplayContext;)V",
+            &[JniValue::Object(context)],
+        )
     }
 
     /// Sets the instance and position of this ItemDisplay in one call
@@ -128,75 +455,286 @@ impl ItemDisplay {
         Ok(())
     }
 
-    /// Sets the scale of this ItemDisplay
-    pub fn set_scale(&self, x: f32, y: f32, z: f32) -> Result<()> {
-        let mut env = get_env()?;
-
-        // Create Vec object
-        let vec_class = env.find_class("net/minestom/server/coordinate/Vec")?;
-        let vec_obj = env.new_object(
-            vec_class,
-            "(DDD)V",
-            &[
-                JValue::Double(x as f64),
-                JValue::Double(y as f64),
-                JValue::Double(z as f64),
-            ],
-        )?;
-
-        // Get the metadata
+    /// Sets whether this ItemDisplay should be visible
+    pub fn set_invisible(&self, invisible: bool) -> Result<()> {
         let meta_obj = self.inner.call_object_method(
             "getEntityMeta",
             "()Lnet/minestom/server/entity/metadata/EntityMeta;",
             &[],
         )?;
 
-        // Set the scale
+        let mut env = get_env()?;
         env.call_method(
             &meta_obj.as_obj()?,
-            "setScale",
-            "(Lnet/minestom/server/coordinate/Vec;)V",
-            &[JValue::Object(&vec_obj)],
+            "setInvisible",
+            "(Z)V",
+            &[JValue::Bool(if invisible { 1 } else { 0 })],
         )?;
 
         Ok(())
     }
+}
 
-    /// Sets the brightness of this ItemDisplay
-    pub fn set_brightness(&self, block_light: i32, sky_light: i32) -> Result<()> {
-        let meta_obj = self.inner.call_object_method(
-            "getEntityMeta",
-            "()Lnet/minestom/server/entity/metadata/EntityMeta;",
-            &[],
-        )?;
+impl EntityLike for ItemDisplay {
+    fn java_object(&self) -> &JavaObject {
+        &self.inner
+    }
+}
+
+impl DisplayLike for ItemDisplay {}
+
+/// Horizontal alignment of the lines of a [`TextDisplay`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextAlignment {
+    Center,
+    Left,
+    Right,
+}
+
+/// A display entity rendering text, without the limitations of custom names.
+#[derive(Clone)]
+pub struct TextDisplay {
+    inner: JavaObject,
+}
+
+impl TextDisplay {
+    pub fn new(text: &Component) -> Result<Self> {
+        let display = Self {
+            inner: new_display_entity("TEXT_DISPLAY")?,
+        };
+        display.set_text(text)?;
+        Ok(display)
+    }
 
+    pub fn set_text(&self, text: &Component) -> Result<()> {
         let mut env = get_env()?;
-        env.call_method(
-            &meta_obj.as_obj()?,
-            "setBrightness",
-            "(II)V",
-            &[JValue::Int(block_light), JValue::Int(sky_light)],
-        )?;
+        display_meta(&self.inner)?.call_void_method(
+            "setText",
+            "(Lnet/kyori/adventure/text/Component;)V",
+            &[text.as_jvalue(&mut env)?],
+        )
+    }
 
-        Ok(())
+    /// Maximum width of a line in pixels before it wraps, 200 by default
+    pub fn set_line_width(&self, width: i32) -> Result<()> {
+        display_meta(&self.inner)?.call_void_method("setLineWidth", "(I)V", &[JniValue::Int(width)])
     }
 
-    /// Sets whether this ItemDisplay should be visible
-    pub fn set_invisible(&self, invisible: bool) -> Result<()> {
-        let meta_obj = self.inner.call_object_method(
-            "getEntityMeta",
-            "()Lnet/minestom/server/entity/metadata/EntityMeta;",
-            &[],
+    /// Sets the background color as ARGB, `None` restores the default translucent background
+    pub fn set_background_color(&self, argb: Option<u32>) -> Result<()> {
+        let meta = display_meta(&self.inner)?;
+        meta.call_void_method(
+            "setUseDefaultBackground",
+            "(Z)V",
+            &[JniValue::Bool(argb.is_none())],
         )?;
+        meta.call_void_method(
+            "setBackgroundColor",
+            "(I)V",
+            &[JniValue::Int(argb.unwrap_or(0x40000000) as i32)],
+        )
+    }
 
+    /// Sets the opacity of the text, from 0 to 255
+    pub fn set_text_opacity(&self, opacity: u8) -> Result<()> {
         let mut env = get_env()?;
+        let meta = display_meta(&self.inner)?;
         env.call_method(
-            &meta_obj.as_obj()?,
-            "setInvisible",
+            meta.as_obj()?,
+            "setTextOpacity",
+            "(B)V",
+            &[JValue::Byte(opacity as i8)],
+        )?;
+        Ok(())
+    }
+
+    pub fn set_text_shadow(&self, shadow: bool) -> Result<()> {
+        display_meta(&self.inner)?.call_void_method("setShadow", "(Z)V", &[JniValue::Bool(shadow)])
+    }
+
+    /// Renders the text through blocks
+    pub fn set_see_through(&self, see_through: bool) -> Result<()> {
+        display_meta(&self.inner)?.call_void_method(
+            "setSeeThrough",
             "(Z)V",
-            &[JValue::Bool(if invisible { 1 } else { 0 })],
+            &[JniValue::Bool(see_through)],
+        )
+    }
+
+    pub fn set_alignment(&self, alignment: TextAlignment) -> Result<()> {
+        let meta = display_meta(&self.inner)?;
+        meta.call_void_method(
+            "setAlignLeft",
+            "(Z)V",
+            &[JniValue::Bool(alignment == TextAlignment::Left)],
         )?;
+        meta.call_void_method(
+            "setAlignRight",
+            "(Z)V",
+            &[JniValue::Bool(alignment == TextAlignment::Right)],
+        )
+    }
+}
 
-        Ok(())
+impl EntityLike for TextDisplay {
+    fn java_object(&self) -> &JavaObject {
+        &self.inner
+    }
+}
+
+impl DisplayLike for TextDisplay {}
+
+/// A display entity rendering a block state.
+#[derive(Clone)]
+pub struct BlockDisplay {
+    inner: JavaObject,
+}
+
+impl BlockDisplay {
+    pub fn new(block: &Block) -> Result<Self> {
+        let display = Self {
+            inner: new_display_entity("BLOCK_DISPLAY")?,
+        };
+        display.set_block(block)?;
+        Ok(display)
+    }
+
+    /// Wraps an existing block display entity
+    pub(crate) fn from_entity(inner: JavaObject) -> Self {
+        Self { inner }
+    }
+
+    pub fn set_block(&self, block: &Block) -> Result<()> {
+        display_meta(&self.inner)?.call_void_method(
+            "setBlockState",
+            "(Lnet/minestom/server/instance/block/Block;)V",
+            &[JniValue::Object(block.inner.as_obj()?)],
+        )
+    }
+}
+
+impl EntityLike for BlockDisplay {
+    fn java_object(&self) -> &JavaObject {
+        &self.inner
+    }
+}
+
+impl DisplayLike for BlockDisplay {}
+
+/// Mouse button used to click an [`Interaction`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InteractionClick {
+    Left,
+    Right,
+}
+
+/// An invisible hitbox which players can click, e.g. to make displays interactive.
+///
+/// # Example
+/// ```rust,no_run
+/// let button = Interaction::new(1.0, 1.0)?;
+/// button.set_instance(&instance, &pos)?;
+/// let listeners = button.on_click(&server.event_handler()?, |player, click| {
+///     player.send_message(&Component::text(&format!("{:?} click", click))?)
+/// })?;
+/// // Later on
+/// button.remove()?;
+/// listeners.remove()?;
+/// ```
+#[derive(Clone)]
+pub struct Interaction {
+    inner: JavaObject,
+}
+
+impl Interaction {
+    pub fn new(width: f32, height: f32) -> Result<Self> {
+        let interaction = Self {
+            inner: new_display_entity("INTERACTION")?,
+        };
+        interaction.set_size(width, height)?;
+        Ok(interaction)
+    }
+
+    pub fn set_size(&self, width: f32, height: f32) -> Result<()> {
+        let meta = display_meta(&self.inner)?;
+        meta.call_void_method("setWidth", "(F)V", &[JniValue::Float(width)])?;
+        meta.call_void_method("setHeight", "(F)V", &[JniValue::Float(height)])
+    }
+
+    /// Gets the width and height of the hitbox
+    pub fn get_size(&self) -> Result<(f32, f32)> {
+        let meta = display_meta(&self.inner)?;
+        Ok((
+            meta.call_float_method("getWidth", "()F", &[])?,
+            meta.call_float_method("getHeight", "()F", &[])?,
+        ))
+    }
+
+    /// Whether clicking plays the arm swing animation
+    pub fn set_responsive(&self, responsive: bool) -> Result<()> {
+        display_meta(&self.inner)?.call_void_method(
+            "setResponse",
+            "(Z)V",
+            &[JniValue::Bool(responsive)],
+        )
+    }
+
+    /// Calls `callback` whenever a player clicks this interaction.
+    ///
+    /// Left clicks are received through `EntityAttackEvent` and right clicks through
+    /// `PlayerEntityInteractEvent`, once per click. The returned handle removes
+    /// the listeners, which are kept after the interaction is removed otherwise.
+    pub fn on_click<F>(&self, events: &EventNode, callback: F) -> Result<ListenerHandle>
+    where
+        F: Fn(&Player, InteractionClick) -> Result<()> + Send + Sync + 'static,
+    {
+        // Right clicks are sent for both hands
+        self.listen_clicks(events, move |player, click, hand, _| match hand {
+            Hand::Main => callback(&player, click),
+            Hand::Off => Ok(()),
+        })
+    }
+
+    /// Listens to the clicks on this interaction, with the hand used and the position
+    /// sent by the client for right clicks. Left clicks are always made with the main hand.
+    pub(crate) fn listen_clicks<F>(&self, events: &EventNode, callback: F) -> Result<ListenerHandle>
+    where
+        F: Fn(Player, InteractionClick, Hand, Option<Position>) -> Result<()>
+            + Send
+            + Sync
+            + 'static,
+    {
+        let id = self.as_entity().get_entity_id()?;
+        let callback = std::sync::Arc::new(callback);
+
+        let mut listeners = events.removable_listeners("interaction-click")?;
+        let left = callback.clone();
+        listeners.listen(move |event: &EntityAttackEvent| {
+            if event.target()?.get_entity_id()? != id {
+                return Ok(());
+            }
+            match event.entity()?.as_player()? {
+                Some(player) => left(player, InteractionClick::Left, Hand::Main, None),
+                None => Ok(()),
+            }
+        })?;
+        listeners.listen(move |event: &PlayerEntityInteractEvent| {
+            if event.get_target()?.get_entity_id()? != id {
+                return Ok(());
+            }
+            callback(
+                event.get_player()?,
+                InteractionClick::Right,
+                event.get_hand()?,
+                Some(event.get_interact_position()?),
+            )
+        })?;
+        Ok(listeners)
+    }
+}
+
+impl EntityLike for Interaction {
+    fn java_object(&self) -> &JavaObject {
+        &self.inner
     }
 }
@@ -1,8 +1,13 @@
 use crate::Component;
 use crate::Result;
 use crate::collision::BoundingBox;
-use crate::instance::Instance;
-use crate::jni_utils::{JavaObject, JniValue, get_env};
+use crate::coordinate::{Pos, Position};
+use crate::entity::{LivingEntity, MinestomEntityCreature, Player};
+use crate::error::MinestomError;
+use crate::event::player_predicate;
+use crate::future::JavaFuture;
+use crate::instance::{AnyInstance, Instance};
+use crate::jni_utils::{JavaObject, JniValue, collection_to_vec, get_env};
 use crate::tag::TagHandler;
 use jni::objects::{JObject, JValue};
 use uuid::Uuid;
@@ -11,7 +16,18 @@ use uuid::Uuid;
 #[derive(Debug, Clone, Copy)]
 pub enum EntityType {
     ArmorStand,
+    Arrow,
+    BlockDisplay,
+    Egg,
+    EnderPearl,
+    Fireball,
+    Interaction,
+    ItemDisplay,
     Player,
+    SmallFireball,
+    Snowball,
+    TextDisplay,
+    Trident,
     Zombie,
     // ...
 }
@@ -21,7 +37,18 @@ impl EntityType {
     pub fn to_java_field(&self) -> &'static str {
         match self {
             EntityType::ArmorStand => "ARMOR_STAND",
+            EntityType::Arrow => "ARROW",
+            EntityType::BlockDisplay => "BLOCK_DISPLAY",
+            EntityType::Egg => "EGG",
+            EntityType::EnderPearl => "ENDER_PEARL",
+            EntityType::Fireball => "FIREBALL",
+            EntityType::Interaction => "INTERACTION",
+            EntityType::ItemDisplay => "ITEM_DISPLAY",
             EntityType::Player => "PLAYER",
+            EntityType::SmallFireball => "SMALL_FIREBALL",
+            EntityType::Snowball => "SNOWBALL",
+            EntityType::TextDisplay => "TEXT_DISPLAY",
+            EntityType::Trident => "TRIDENT",
             EntityType::Zombie => "ZOMBIE",
             // ...
         }
@@ -30,9 +57,20 @@ impl EntityType {
     pub fn from_java_name(name: &str) -> Option<Self> {
         match name {
             "minecraft:armor_stand" => Some(EntityType::ArmorStand),
+            "minecraft:arrow" => Some(EntityType::Arrow),
+            "minecraft:block_display" => Some(EntityType::BlockDisplay),
+            "minecraft:egg" => Some(EntityType::Egg),
+            "minecraft:ender_pearl" => Some(EntityType::EnderPearl),
+            "minecraft:fireball" => Some(EntityType::Fireball),
+            "minecraft:interaction" => Some(EntityType::Interaction),
+            "minecraft:item_display" => Some(EntityType::ItemDisplay),
             "minecraft:player" => Some(EntityType::Player),
+            "minecraft:small_fireball" => Some(EntityType::SmallFireball),
+            "minecraft:snowball" => Some(EntityType::Snowball),
+            "minecraft:text_display" => Some(EntityType::TextDisplay),
+            "minecraft:trident" => Some(EntityType::Trident),
             "minecraft:zombie" => Some(EntityType::Zombie),
-            _ => panic!("Unknown EntityType: {}", name),
+            _ => None,
         }
     }
 }
@@ -40,7 +78,7 @@ impl EntityType {
 /// A generic wrapper around a Minestom entity Java object.
 #[derive(Clone)]
 pub struct Entity {
-    inner: JavaObject,
+    pub(crate) inner: JavaObject,
 }
 
 impl Entity {
@@ -199,6 +237,60 @@ impl Entity {
         Ok(Uuid::from_u128(raw))
     }
 
+    /// Gets the bounding box of this entity, relative to its position.
+    pub fn get_bounding_box(&self) -> Result<BoundingBox> {
+        let bounding_box = self.inner.call_object_method(
+            "getBoundingBox",
+            "()Lnet/minestom/server/collision/BoundingBox;",
+            &[],
+        )?;
+        Ok(BoundingBox::from_java(bounding_box))
+    }
+
+    /// Gets the protocol id of this entity, unique while the server is running.
+    pub fn get_entity_id(&self) -> Result<i32> {
+        self.inner.call_int_method("getEntityId", "()I", &[])
+    }
+
+    fn is_instance_of(&self, class: &str) -> Result<bool> {
+        let mut env = get_env()?;
+        Ok(env.is_instance_of(self.inner.as_obj()?, class)?)
+    }
+
+    /// Returns true if this entity is a player.
+    pub fn is_player(&self) -> Result<bool> {
+        self.is_instance_of("net/minestom/server/entity/Player")
+    }
+
+    /// Gets this entity as a player, if it is one.
+    pub fn as_player(&self) -> Result<Option<Player>> {
+        if self.is_player()? {
+            Ok(Some(Player::new(self.inner.clone())))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Gets this entity as a living entity (players, mobs, armor stands...), if it is one.
+    pub fn as_living(&self) -> Result<Option<LivingEntity>> {
+        if self.is_instance_of("net/minestom/server/entity/LivingEntity")? {
+            Ok(Some(LivingEntity::new(self.inner.clone())))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Gets this entity as a creature implemented in Rust, if it is one.
+    pub fn as_creature(&self) -> Result<Option<MinestomEntityCreature>> {
+        if self.is_instance_of("rust/minestom/EntityCreatureCallback")? {
+            Ok(Some(MinestomEntityCreature {
+                inner: self.inner.clone(),
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Returns the `EntityType` of this entity instance.
     pub fn get_type(&self) -> Result<EntityType> {
         let mut env = get_env()?;
@@ -214,7 +306,9 @@ impl Entity {
         let name_j = env.call_method(&et_obj, "name", "()Ljava/lang/String;", &[])?;
         let jstr = name_j.l()?;
         let rust_str: String = env.get_string((&jstr).into())?.into();
-        Ok(EntityType::from_java_name(&rust_str).unwrap())
+        EntityType::from_java_name(&rust_str).ok_or_else(|| {
+            MinestomError::InvalidArgument(format!("Unsupported entity type: {}", rust_str))
+        })
     }
 
     /// Gets the custom name of this entity, if set.
@@ -260,20 +354,320 @@ impl Entity {
         )?;
         Ok(())
     }
+}
+
+/// Pose of an entity, changing its hitbox and animation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityPose {
+    Standing,
+    FallFlying,
+    Sleeping,
+    Swimming,
+    SpinAttack,
+    Sneaking,
+    LongJumping,
+    Dying,
+    Sitting,
+}
+
+impl EntityPose {
+    fn to_java_field(&self) -> &'static str {
+        match self {
+            EntityPose::Standing => "STANDING",
+            EntityPose::FallFlying => "FALL_FLYING",
+            EntityPose::Sleeping => "SLEEPING",
+            EntityPose::Swimming => "SWIMMING",
+            EntityPose::SpinAttack => "SPIN_ATTACK",
+            EntityPose::Sneaking => "SNEAKING",
+            EntityPose::LongJumping => "LONG_JUMPING",
+            EntityPose::Dying => "DYING",
+            EntityPose::Sitting => "SITTING",
+        }
+    }
+}
+
+/// Common trait for everything backed by a Minestom entity: [`Entity`], [`Player`],
+/// [`LivingEntity`], [`MinestomEntityCreature`], the display entities such as
+/// [`ItemDisplay`](crate::entity::ItemDisplay) and [`Interaction`](crate::entity::Interaction).
+///
+/// Every method except [`EntityLike::java_object`] has a default implementation
+/// working on the underlying Java `Entity`.
+pub trait EntityLike {
+    /// Gets the wrapped Java `Entity` object
+    fn java_object(&self) -> &JavaObject;
+
+    /// Gets this entity as a generic entity
+    fn as_entity(&self) -> Entity {
+        Entity::new(self.java_object().clone())
+    }
+
+    /// Gets the tags stored on the entity
+    fn tag_handler(&self) -> Result<TagHandler> {
+        let inner = self.java_object().call_object_method(
+            "tagHandler",
+            "()Lnet/minestom/server/tag/TagHandler;",
+            &[],
+        )?;
+        Ok(TagHandler { inner })
+    }
+
+    /// Gets the position of the entity
+    fn get_position(&self) -> Result<Position> {
+        self.get_pos()?.to_position()
+    }
 
-    pub fn tag_handler(&self) -> Result<TagHandler> {
+    /// Gets the position of the entity along with its view direction
+    fn get_pos(&self) -> Result<Pos> {
+        let pos = self.java_object().call_object_method(
+            "getPosition",
+            "()Lnet/minestom/server/coordinate/Pos;",
+            &[],
+        )?;
+        Ok(Pos::new(pos))
+    }
+
+    /// Teleports the entity in its current instance.
+    /// The returned future completes once the chunk at the destination is loaded.
+    fn teleport_to(&self, pos: &Pos) -> Result<JavaFuture> {
+        let future = self.java_object().call_object_method(
+            "teleport",
+            "(Lnet/minestom/server/coordinate/Pos;)Ljava/util/concurrent/CompletableFuture;",
+            &[JniValue::Object(pos.inner()?)],
+        )?;
+        JavaFuture::new(future)
+    }
+
+    /// Sets the velocity in blocks per second
+    fn set_velocity(&self, velocity: &Position) -> Result<()> {
         let mut env = get_env()?;
-        // Chiama Java: entity.tagHandler()
-        let th_obj = env
-            .call_method(
-                self.inner.as_obj()?,
-                "tagHandler",
-                "()Lnet/minestom/server/tag/TagHandler;",
-                &[],
+        let vec = env.new_object(
+            "net/minestom/server/coordinate/Vec",
+            "(DDD)V",
+            &[
+                JValue::Double(velocity.x),
+                JValue::Double(velocity.y),
+                JValue::Double(velocity.z),
+            ],
+        )?;
+        self.java_object().call_void_method(
+            "setVelocity",
+            "(Lnet/minestom/server/coordinate/Vec;)V",
+            &[JniValue::Object(vec)],
+        )
+    }
+
+    /// Gets the velocity in blocks per second
+    fn get_velocity(&self) -> Result<Position> {
+        let velocity = self.java_object().call_object_method(
+            "getVelocity",
+            "()Lnet/minestom/server/coordinate/Vec;",
+            &[],
+        )?;
+        Pos::new(velocity).to_position()
+    }
+
+    /// Removes the entity from the server, it cannot be spawned again
+    fn remove(&self) -> Result<()> {
+        self.java_object().call_void_method("remove", "()V", &[])
+    }
+
+    fn is_removed(&self) -> Result<bool> {
+        self.java_object().call_bool_method("isRemoved", "()Z", &[])
+    }
+
+    /// Moves the entity to another instance.
+    /// The returned future completes once the entity is added to the instance.
+    fn set_instance(&self, instance: &dyn Instance, pos: &Pos) -> Result<JavaFuture> {
+        let future = self.java_object().call_object_method(
+            "setInstance",
+            "(Lnet/minestom/server/instance/Instance;Lnet/minestom/server/coordinate/Pos;)Ljava/util/concurrent/CompletableFuture;",
+            &[JniValue::Object(instance.inner()?), JniValue::Object(pos.inner()?)],
+        )?;
+        JavaFuture::new(future)
+    }
+
+    /// Gets the instance of the entity, if it has been spawned
+    fn get_instance(&self) -> Result<Option<AnyInstance>> {
+        let instance = self.java_object().call_object_method(
+            "getInstance",
+            "()Lnet/minestom/server/instance/Instance;",
+            &[],
+        )?;
+        if instance.as_obj()?.is_null() {
+            Ok(None)
+        } else {
+            Ok(Some(AnyInstance::from_java(instance)?))
+        }
+    }
+
+    /// Makes an entity ride this one
+    fn add_passenger(&self, passenger: &dyn EntityLike) -> Result<()> {
+        self.java_object().call_void_method(
+            "addPassenger",
+            "(Lnet/minestom/server/entity/Entity;)V",
+            &[JniValue::Object(passenger.java_object().as_obj()?)],
+        )
+    }
+
+    fn remove_passenger(&self, passenger: &dyn EntityLike) -> Result<()> {
+        self.java_object().call_void_method(
+            "removePassenger",
+            "(Lnet/minestom/server/entity/Entity;)V",
+            &[JniValue::Object(passenger.java_object().as_obj()?)],
+        )
+    }
+
+    fn get_passengers(&self) -> Result<Vec<Entity>> {
+        let passengers =
+            self.java_object()
+                .call_object_method("getPassengers", "()Ljava/util/Set;", &[])?;
+        Ok(collection_to_vec(&passengers)?
+            .into_iter()
+            .map(Entity::new)
+            .collect())
+    }
+
+    /// Sets whether the entity is outlined, even through walls
+    fn set_glowing(&self, glowing: bool) -> Result<()> {
+        self.java_object()
+            .call_void_method("setGlowing", "(Z)V", &[JniValue::Bool(glowing)])
+    }
+
+    /// Shows the entity as burning, without dealing damage
+    fn set_on_fire(&self, on_fire: bool) -> Result<()> {
+        let meta = self.java_object().call_object_method(
+            "getEntityMeta",
+            "()Lnet/minestom/server/entity/metadata/EntityMeta;",
+            &[],
+        )?;
+        meta.call_void_method("setOnFire", "(Z)V", &[JniValue::Bool(on_fire)])
+    }
+
+    fn set_pose(&self, pose: EntityPose) -> Result<()> {
+        let mut env = get_env()?;
+        let java_pose = env
+            .get_static_field(
+                "net/minestom/server/entity/EntityPose",
+                pose.to_java_field(),
+                "Lnet/minestom/server/entity/EntityPose;",
             )?
             .l()?;
-        Ok(TagHandler {
-            inner: JavaObject::from_env(&mut env, th_obj)?,
-        })
+        self.java_object().call_void_method(
+            "setPose",
+            "(Lnet/minestom/server/entity/EntityPose;)V",
+            &[JniValue::Object(java_pose)],
+        )
+    }
+
+    /// Sets whether the entity makes sounds
+    fn set_silent(&self, silent: bool) -> Result<()> {
+        let meta = self.java_object().call_object_method(
+            "getEntityMeta",
+            "()Lnet/minestom/server/entity/metadata/EntityMeta;",
+            &[],
+        )?;
+        meta.call_void_method("setSilent", "(Z)V", &[JniValue::Bool(silent)])
+    }
+
+    /// Rotates the entity to look at a point
+    fn look_at(&self, point: &Position) -> Result<()> {
+        self.java_object().call_void_method(
+            "lookAt",
+            "(Lnet/minestom/server/coordinate/Point;)V",
+            &[JniValue::Object(point.to_pos()?.inner()?)],
+        )
+    }
+
+    /// Rotates the entity to look at another entity
+    fn look_at_entity(&self, entity: &dyn EntityLike) -> Result<()> {
+        self.java_object().call_void_method(
+            "lookAt",
+            "(Lnet/minestom/server/entity/Entity;)V",
+            &[JniValue::Object(entity.java_object().as_obj()?)],
+        )
+    }
+
+    /// Gets the players currently seeing the entity
+    fn get_viewers(&self) -> Result<Vec<Player>> {
+        let viewers =
+            self.java_object()
+                .call_object_method("getViewers", "()Ljava/util/Set;", &[])?;
+        Ok(collection_to_vec(&viewers)?
+            .into_iter()
+            .map(Player::new)
+            .collect())
+    }
+
+    /// Shows the entity to a player, returns false if they already see it
+    fn add_viewer(&self, player: &Player) -> Result<bool> {
+        self.java_object().call_bool_method(
+            "addViewer",
+            "(Lnet/minestom/server/entity/Player;)Z",
+            &[JniValue::Object(player.inner()?)],
+        )
+    }
+
+    /// Hides the entity from a player, returns false if they did not see it
+    fn remove_viewer(&self, player: &Player) -> Result<bool> {
+        self.java_object().call_bool_method(
+            "removeViewer",
+            "(Lnet/minestom/server/entity/Player;)Z",
+            &[JniValue::Object(player.inner()?)],
+        )
+    }
+
+    /// Sets whether players in range automatically see the entity.
+    /// When disabled, viewers must be added with [`EntityLike::add_viewer`].
+    fn set_auto_viewable(&self, auto_viewable: bool) -> Result<()> {
+        self.java_object().call_void_method(
+            "setAutoViewable",
+            "(Z)V",
+            &[JniValue::Bool(auto_viewable)],
+        )
+    }
+
+    fn is_auto_viewable(&self) -> Result<bool> {
+        self.java_object()
+            .call_bool_method("isAutoViewable", "()Z", &[])
+    }
+
+    /// Only shows the entity to players in range for which `rule` returns true,
+    /// e.g. to hide other players during a parkour run.
+    ///
+    /// The rule is evaluated when players come in range, call
+    /// [`EntityLike::update_viewable_rule`] when its result may have changed.
+    fn set_viewable_rule<F>(&self, rule: F) -> Result<()>
+    where
+        F: Fn(&Player) -> bool + Send + Sync + 'static,
+        Self: Sized,
+    {
+        // The closure is freed once the entity drops the predicate
+        let predicate = player_predicate(rule)?;
+        self.java_object().call_void_method(
+            "updateViewableRule",
+            "(Ljava/util/function/Predicate;)V",
+            &[JniValue::Object(predicate.as_obj()?)],
+        )
+    }
+
+    /// Removes the rule set with [`EntityLike::set_viewable_rule`]
+    fn clear_viewable_rule(&self) -> Result<()> {
+        self.java_object().call_void_method(
+            "updateViewableRule",
+            "(Ljava/util/function/Predicate;)V",
+            &[JniValue::Object(JObject::null())],
+        )
+    }
+
+    /// Evaluates the viewable rule again for every player in range
+    fn update_viewable_rule(&self) -> Result<()> {
+        self.java_object()
+            .call_void_method("updateViewableRule", "()V", &[])
+    }
+}
+
+impl EntityLike for Entity {
+    fn java_object(&self) -> &JavaObject {
+        &self.inner
     }
 }
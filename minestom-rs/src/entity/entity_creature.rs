@@ -1,3 +1,4 @@
+use crate::damage::{Damage, DamageOutcome};
 use crate::jni_utils::{JavaObject, get_env};
 use crate::{Player, Pos, instance::Instance};
 use jni::sys::{jboolean, jlong, jobject};
@@ -5,6 +6,7 @@ use jni::{
     JNIEnv,
     objects::{JClass, JObject, JValue},
 };
+use log::error;
 use once_cell::sync::Lazy;
 use std::{
     collections::HashMap,
@@ -14,7 +16,7 @@ use std::{
     },
 };
 
-use crate::entity::entity::EntityType;
+use crate::entity::entity::{EntityLike, EntityType};
 
 /// Trait that your Rust creature must implement
 pub trait EntityCreature: Send + Sync + 'static {
@@ -27,10 +29,11 @@ pub trait EntityCreature: Send + Sync + 'static {
     /// Called every tick; `time` is client‐tick time
     fn tick(&self, time: i64);
 
-    // TODO
-    /// Called when damage is applied. Return `true` to let the default damage logic run,
-    /// or `false` to cancel.
-    // fn damage(&self, damage_type: DamageTypeKey, amount: f32) -> bool;
+    /// Called when damage is about to be applied, before the `EntityDamageEvent`.
+    /// Damage passes through unchanged by default.
+    fn damage(&self, _damage: &Damage) -> DamageOutcome {
+        DamageOutcome::PassThrough
+    }
 
     /// Called just before the creature is removed
     fn remove(&self);
@@ -38,7 +41,7 @@ pub trait EntityCreature: Send + Sync + 'static {
 
 #[derive(Clone)]
 pub struct MinestomEntityCreature {
-    inner: JavaObject,
+    pub(crate) inner: JavaObject,
 }
 
 // Registry mapping callback IDs → the Rust implementation
@@ -101,31 +104,38 @@ pub unsafe extern "system" fn Java_rust_minestom_EntityCreatureCallback_nativeTi
     }
 }
 
-/*  TODO
-/// JNI callback: damage(DynamicRegistry.Key<DamageType>, float) → boolean
+/// JNI callback: damage(Damage) → boolean
 #[unsafe(no_mangle)]
-pub unsafe extern "system" fn
-Java_rust_minestom_EntityCreatureCallback_nativeDamage(
+pub unsafe extern "system" fn Java_rust_minestom_EntityCreatureCallback_nativeDamage(
     raw_env: *mut jni::sys::JNIEnv,
     _class: JClass,
     callback_id: jlong,
-    j_damage_key: jobject,
-    amount: f32,
+    j_damage: jobject,
 ) -> jboolean {
-    let env = JNIEnv::from_raw(raw_env).unwrap();
     let registry = CREATURE_REGISTRY.read().unwrap();
-    if let Some(creature) = registry.get(&(callback_id as u64)) {
-        // Wrap the Java DynamicRegistry.Key<DamageType> into our Rust key type
-        let mut env = env;
-        let rust_key = RegistryKey::new(JavaObject::from_env(&mut env, JObject::from(j_damage_key)).unwrap());
-        // Convert to a DamageTypeKey. (You may need to adapt this if your wrapper differs.)
-        let damage_key = DamageTypeKey::from_registry_key(rust_key);
-        let keep_default = creature.damage(damage_key, amount);
-        return if keep_default { 1 } else { 0 };
+    // If no entry, let the base logic run
+    let Some(creature) = registry.get(&(callback_id as u64)) else {
+        return 1;
+    };
+    let mut env = unsafe { JNIEnv::from_raw(raw_env).unwrap() };
+    let damage = match JavaObject::from_env(&mut env, unsafe { JObject::from_raw(j_damage) }) {
+        Ok(inner) => Damage { inner },
+        Err(e) => {
+            error!("Failed to wrap damage: {}", e);
+            return 1;
+        }
+    };
+    match creature.damage(&damage) {
+        DamageOutcome::PassThrough => 1,
+        DamageOutcome::Modify(amount) => {
+            if let Err(e) = damage.set_amount(amount) {
+                error!("Failed to modify damage: {}", e);
+            }
+            1
+        }
+        DamageOutcome::Cancel => 0,
     }
-    // If no entry, default to “let base logic run”
-    1
-}*/
+}
 
 /// JNI callback: remove()
 #[unsafe(no_mangle)]
@@ -237,3 +247,9 @@ impl MinestomEntityCreature {
     //     Ok(MinestomEntityCreature::new(self.inner.clone()))
     // }
 }
+
+impl EntityLike for MinestomEntityCreature {
+    fn java_object(&self) -> &JavaObject {
+        &self.inner
+    }
+}
@@ -0,0 +1,202 @@
+use crate::Result;
+use crate::attribute::{Attribute, AttributeInstance};
+use crate::damage::{Damage, DamageType};
+use crate::entity::entity::EntityLike;
+use crate::entity::{MinestomEntityCreature, Player};
+use crate::item::{EquipmentSlot, ItemStack};
+use crate::jni_utils::{JavaObject, JniValue, collection_to_vec};
+use crate::potion::{Potion, PotionEffect};
+
+/// An entity with health and equipment, such as players, mobs and armor stands.
+///
+/// Obtained from [`Entity::as_living`](crate::entity::entity::Entity::as_living).
+#[derive(Clone)]
+pub struct LivingEntity {
+    pub(crate) inner: JavaObject,
+}
+
+impl LivingEntity {
+    pub(crate) fn new(inner: JavaObject) -> Self {
+        Self { inner }
+    }
+}
+
+impl EntityLike for LivingEntity {
+    fn java_object(&self) -> &JavaObject {
+        &self.inner
+    }
+}
+
+/// Common trait for entities backed by a Minestom `LivingEntity`: [`LivingEntity`],
+/// [`Player`] and [`MinestomEntityCreature`].
+pub trait LivingEntityLike: EntityLike {
+    fn get_health(&self) -> Result<f32> {
+        self.java_object()
+            .call_float_method("getHealth", "()F", &[])
+    }
+
+    /// Gets the instance of an attribute, to change its base value or add modifiers
+    fn get_attribute(&self, attribute: Attribute) -> Result<AttributeInstance> {
+        let attribute = attribute.to_java_attribute()?;
+        let instance = self.java_object().call_object_method(
+            "getAttribute",
+            "(Lnet/minestom/server/entity/attribute/Attribute;)Lnet/minestom/server/entity/attribute/AttributeInstance;",
+            &[JniValue::Object(attribute.as_obj()?)],
+        )?;
+        Ok(AttributeInstance::new(instance))
+    }
+
+    /// Sets the health, clamped between 0 and the max health attribute
+    fn set_health(&self, health: f32) -> Result<()> {
+        self.java_object()
+            .call_void_method("setHealth", "(F)V", &[JniValue::Float(health)])
+    }
+
+    /// Restores the health to its maximum
+    fn heal(&self) -> Result<()> {
+        self.java_object().call_void_method("heal", "()V", &[])
+    }
+
+    /// Damages the entity, calling an `EntityDamageEvent`.
+    /// Returns false if the damage was cancelled or the entity is invulnerable.
+    fn damage(&self, damage_type: &DamageType, amount: f32) -> Result<bool> {
+        self.java_object().call_bool_method(
+            "damage",
+            "(Lnet/minestom/server/registry/DynamicRegistry$Key;F)Z",
+            &[
+                JniValue::Object(damage_type.inner.as_obj()?),
+                JniValue::Float(amount),
+            ],
+        )
+    }
+
+    /// Applies damage with a source or attacker, see [`Damage`].
+    /// Returns false if the damage was cancelled or the entity is invulnerable.
+    fn apply_damage(&self, damage: &Damage) -> Result<bool> {
+        self.java_object().call_bool_method(
+            "damage",
+            "(Lnet/minestom/server/entity/damage/Damage;)Z",
+            &[JniValue::Object(damage.inner.as_obj()?)],
+        )
+    }
+
+    /// Kills the entity, calling an `EntityDeathEvent`
+    fn kill(&self) -> Result<()> {
+        self.java_object().call_void_method("kill", "()V", &[])
+    }
+
+    fn is_dead(&self) -> Result<bool> {
+        self.java_object().call_bool_method("isDead", "()Z", &[])
+    }
+
+    fn get_equipment(&self, slot: EquipmentSlot) -> Result<ItemStack> {
+        let item = self.java_object().call_object_method(
+            "getEquipment",
+            "(Lnet/minestom/server/entity/EquipmentSlot;)Lnet/minestom/server/item/ItemStack;",
+            &[JniValue::Object(slot.to_java()?.as_obj()?)],
+        )?;
+        Ok(ItemStack::from_java(item))
+    }
+
+    fn set_equipment(&self, slot: EquipmentSlot, item: &ItemStack) -> Result<()> {
+        self.java_object().call_void_method(
+            "setEquipment",
+            "(Lnet/minestom/server/entity/EquipmentSlot;Lnet/minestom/server/item/ItemStack;)V",
+            &[
+                JniValue::Object(slot.to_java()?.as_obj()?),
+                JniValue::Object(item.as_obj().as_obj()?),
+            ],
+        )
+    }
+
+    /// Number of arrows shown stuck in the entity
+    fn get_arrow_count(&self) -> Result<i32> {
+        self.java_object()
+            .call_int_method("getArrowCount", "()I", &[])
+    }
+
+    fn set_arrow_count(&self, count: i32) -> Result<()> {
+        self.java_object()
+            .call_void_method("setArrowCount", "(I)V", &[JniValue::Int(count)])
+    }
+
+    /// Remaining ticks the entity burns for
+    fn get_fire_ticks(&self) -> Result<i32> {
+        self.java_object()
+            .call_int_method("getFireTicks", "()I", &[])
+    }
+
+    /// Sets the entity on fire for the given number of ticks, 0 extinguishes it
+    fn set_fire_ticks(&self, ticks: i32) -> Result<()> {
+        self.java_object()
+            .call_void_method("setFireTicks", "(I)V", &[JniValue::Int(ticks)])
+    }
+
+    fn is_invulnerable(&self) -> Result<bool> {
+        self.java_object()
+            .call_bool_method("isInvulnerable", "()Z", &[])
+    }
+
+    /// Invulnerable entities ignore [`LivingEntityLike::damage`] but can still be killed
+    fn set_invulnerable(&self, invulnerable: bool) -> Result<()> {
+        self.java_object().call_void_method(
+            "setInvulnerable",
+            "(Z)V",
+            &[JniValue::Bool(invulnerable)],
+        )
+    }
+
+    /// Applies an effect, replacing the current one of the same type
+    fn add_effect(&self, potion: &Potion) -> Result<()> {
+        self.java_object().call_void_method(
+            "addEffect",
+            "(Lnet/minestom/server/potion/Potion;)V",
+            &[JniValue::Object(potion.to_java()?.as_obj()?)],
+        )
+    }
+
+    fn remove_effect(&self, effect: PotionEffect) -> Result<()> {
+        self.java_object().call_void_method(
+            "removeEffect",
+            "(Lnet/minestom/server/potion/PotionEffect;)V",
+            &[JniValue::Object(effect.to_java()?.as_obj()?)],
+        )
+    }
+
+    fn clear_effects(&self) -> Result<()> {
+        self.java_object()
+            .call_void_method("clearEffects", "()V", &[])
+    }
+
+    /// Gets the active effects, with the duration they were applied with
+    fn get_active_effects(&self) -> Result<Vec<Potion>> {
+        let effects =
+            self.java_object()
+                .call_object_method("getActiveEffects", "()Ljava/util/List;", &[])?;
+        collection_to_vec(&effects)?
+            .iter()
+            .map(|timed| {
+                let potion = timed.call_object_method(
+                    "potion",
+                    "()Lnet/minestom/server/potion/Potion;",
+                    &[],
+                )?;
+                Potion::from_java(&potion)
+            })
+            .collect()
+    }
+
+    fn has_effect(&self, effect: PotionEffect) -> Result<bool> {
+        self.java_object().call_bool_method(
+            "hasEffect",
+            "(Lnet/minestom/server/potion/PotionEffect;)Z",
+            &[JniValue::Object(effect.to_java()?.as_obj()?)],
+        )
+    }
+}
+
+impl LivingEntityLike for LivingEntity {}
+
+impl LivingEntityLike for Player {}
+
+impl LivingEntityLike for MinestomEntityCreature {}
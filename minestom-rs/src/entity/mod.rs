@@ -1,17 +1,29 @@
 use std::fmt;
 use std::sync::Arc;
 
+pub mod ai;
+pub mod client_side;
 pub mod display;
 pub mod entity;
 pub mod entity_creature;
+pub mod living;
+pub mod npc;
 pub mod player;
+pub mod projectile;
+pub mod tween;
 
+pub use ai::*;
+pub use client_side::ClientSideEntity;
 pub use display::*;
+pub use entity::{EntityLike, EntityPose};
 pub use entity_creature::*;
+pub use living::*;
+pub use npc::*;
 pub use player::*;
+pub use projectile::Projectile;
+pub use tween::{Easing, Transform, Tween, TweenHandle};
 
 use crate::Result;
-use crate::attribute::{Attribute, AttributeInstance};
 use crate::coordinate::Position;
 use crate::jni_utils::{JavaObject, JniValue, get_env};
 use crate::sound::Sound;
@@ -117,6 +129,12 @@ impl fmt::Debug for Player {
     }
 }
 
+impl EntityLike for Player {
+    fn java_object(&self) -> &JavaObject {
+        &self.inner
+    }
+}
+
 impl Player {
     pub(crate) fn new(inner: JavaObject) -> Self {
         Self { inner }
@@ -272,6 +290,16 @@ impl Player {
         )
     }
 
+    /// Stops a sound started with [`Player::play_sound`], e.g. a music track.
+    pub fn stop_sound(&self, sound: &Sound) -> Result<()> {
+        let mut env = get_env()?;
+        self.inner.call_void_method(
+            "stopSound",
+            "(Lnet/kyori/adventure/sound/Sound;)V",
+            &[sound.as_jvalue(&mut env)?],
+        )
+    }
+
     /// Gets the current position of the player.
     pub fn get_position(&self) -> Result<Position> {
         let mut env = get_env()?;
@@ -289,23 +317,6 @@ impl Player {
         Ok(Position::new(x, y, z))
     }
 
-    /// Gets an attribute instance for the specified attribute
-    pub fn get_attribute(&self, attribute: Attribute) -> Result<AttributeInstance> {
-        let mut env = get_env()?;
-        let j_attribute = attribute.to_java_attribute()?;
-
-        let result = self.inner.call_object_method(
-            "getAttribute",
-            "(Lnet/minestom/server/entity/attribute/Attribute;)Lnet/minestom/server/entity/attribute/AttributeInstance;",
-            &[j_attribute.as_jvalue(&mut env)?],
-        )?;
-
-        Ok(AttributeInstance::new(JavaObject::from_env(
-            &mut env,
-            result.as_obj()?,
-        )?))
-    }
-
     pub fn get_player_connection(&self) -> Result<PlayerConnection> {
         let mut env = get_env()?;
 
@@ -0,0 +1,199 @@
+use crate::Result;
+use crate::coordinate::Position;
+use crate::entity::{
+    EntityLike, LivingEntityLike, MinestomEntityCreature, Navigator, Player, PlayerSkin,
+};
+use crate::error::MinestomError;
+use crate::event::player::{Hand, PlayerEntityInteractEvent};
+use crate::event::{EventNode, ListenerHandle};
+use crate::jni_utils::{JavaObject, JniValue, get_env};
+use crate::text::Component;
+use jni::objects::{JObject, JValue};
+use log::warn;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Distance at which a waypoint counts as reached
+const WAYPOINT_DISTANCE: f64 = 0.5;
+
+/// A player-looking entity, such as a shopkeeper or a server selector.
+///
+/// # Example
+/// ```rust,no_run
+/// let npc = Npc::new("Shopkeeper", Some(&skin))?;
+/// npc.set_look_range(Some(8.0))?;
+/// npc.set_instance(&instance, &Position::new(0.5, 42.0, 0.5).to_pos()?)?;
+/// npc.on_interact(&instance.event_node()?, |player| {
+///     player.send_message(&Component::text("Welcome to the shop!")?)
+/// })?;
+/// ```
+#[derive(Clone)]
+pub struct Npc {
+    inner: JavaObject,
+    // Incremented to stop the waypoints being followed
+    path_generation: Arc<AtomicU64>,
+}
+
+impl Npc {
+    /// Creates an NPC named `name`, at most 16 characters, shown above its head.
+    /// Without a skin, the default Steve or Alex skin is used.
+    pub fn new(name: &str, skin: Option<&PlayerSkin>) -> Result<Self> {
+        if name.chars().count() > 16 {
+            return Err(MinestomError::InvalidArgument(format!(
+                "NPC name is longer than 16 characters: {}",
+                name
+            )));
+        }
+
+        let mut env = get_env()?;
+        let j_name = env.new_string(name)?;
+        let skin = match skin {
+            Some(skin) => skin.inner().as_obj()?,
+            None => JObject::null(),
+        };
+        let npc = env.new_object(
+            "rust/minestom/NpcEntity",
+            "(Ljava/lang/String;Lnet/minestom/server/entity/PlayerSkin;)V",
+            &[JValue::Object(&j_name), JValue::Object(&skin)],
+        )?;
+        Ok(Self {
+            inner: JavaObject::from_env(&mut env, npc)?,
+            path_generation: Arc::new(AtomicU64::new(0)),
+        })
+    }
+
+    pub fn set_skin(&self, skin: Option<&PlayerSkin>) -> Result<()> {
+        let skin = match skin {
+            Some(skin) => skin.inner().as_obj()?,
+            None => JObject::null(),
+        };
+        self.inner.call_void_method(
+            "setSkin",
+            "(Lnet/minestom/server/entity/PlayerSkin;)V",
+            &[JniValue::Object(skin)],
+        )
+    }
+
+    /// Sets the name shown in the tab list, `None` shows the NPC name
+    pub fn set_display_name(&self, display_name: Option<&Component>) -> Result<()> {
+        let mut env = get_env()?;
+        let display_name = match display_name {
+            Some(name) => name.as_jvalue(&mut env)?,
+            None => JniValue::Object(JObject::null()),
+        };
+        self.inner.call_void_method(
+            "setDisplayName",
+            "(Lnet/kyori/adventure/text/Component;)V",
+            &[display_name],
+        )
+    }
+
+    /// Whether the NPC has an entry in the tab list, false by default
+    pub fn set_listed(&self, listed: bool) -> Result<()> {
+        self.inner
+            .call_void_method("setListed", "(Z)V", &[JniValue::Bool(listed)])
+    }
+
+    /// Makes the NPC look at each player within `range` blocks, every player seeing it
+    /// look at themselves. `None` disables it.
+    pub fn set_look_range(&self, range: Option<f64>) -> Result<()> {
+        self.inner.call_void_method(
+            "setLookRange",
+            "(D)V",
+            &[JniValue::Double(range.unwrap_or(0.0))],
+        )
+    }
+
+    pub fn get_navigator(&self) -> Result<Navigator> {
+        self.as_creature().get_navigator()
+    }
+
+    /// Gets the NPC as a creature, e.g. to give it AI goals
+    pub fn as_creature(&self) -> MinestomEntityCreature {
+        MinestomEntityCreature {
+            inner: self.inner.clone(),
+        }
+    }
+
+    /// Walks through `waypoints` in order, starting over from the first one if `looping`.
+    /// Replaces the waypoints previously followed.
+    pub fn follow_waypoints(&self, waypoints: Vec<Position>, looping: bool) -> Result<()> {
+        let generation = self.path_generation.fetch_add(1, Ordering::SeqCst) + 1;
+        if waypoints.is_empty() {
+            return Ok(());
+        }
+        self.walk_to_waypoint(Arc::new(waypoints), 0, looping, generation)
+    }
+
+    /// Stops following waypoints
+    pub fn stop_waypoints(&self) -> Result<()> {
+        self.path_generation.fetch_add(1, Ordering::SeqCst);
+        self.get_navigator()?.stop()
+    }
+
+    fn walk_to_waypoint(
+        &self,
+        waypoints: Arc<Vec<Position>>,
+        index: usize,
+        looping: bool,
+        generation: u64,
+    ) -> Result<()> {
+        let npc = self.clone();
+        let next_waypoints = waypoints.clone();
+        let found = self.get_navigator()?.set_path_to_with_callback(
+            &waypoints[index],
+            WAYPOINT_DISTANCE,
+            move || {
+                if npc.path_generation.load(Ordering::SeqCst) != generation {
+                    return;
+                }
+                let next = index + 1;
+                let next = if next < next_waypoints.len() {
+                    next
+                } else if looping {
+                    0
+                } else {
+                    return;
+                };
+                if let Err(e) = npc.walk_to_waypoint(next_waypoints, next, looping, generation) {
+                    warn!("NPC failed to walk to the next waypoint: {}", e);
+                }
+            },
+        )?;
+        if !found {
+            return Err(MinestomError::InvalidArgument(format!(
+                "no path to waypoint {} ({}, {}, {})",
+                index, waypoints[index].x, waypoints[index].y, waypoints[index].z
+            )));
+        }
+        Ok(())
+    }
+
+    /// Calls `callback` when a player right-clicks the NPC.
+    /// The returned handle removes the listener once the NPC is removed.
+    pub fn on_interact<F>(&self, events: &EventNode, callback: F) -> Result<ListenerHandle>
+    where
+        F: Fn(&Player) -> Result<()> + Send + Sync + 'static,
+    {
+        let id = self.as_entity().get_entity_id()?;
+        let mut listener = events.removable_listeners("npc-interact")?;
+        listener.listen(move |event: &PlayerEntityInteractEvent| {
+            // The event is called once for each hand
+            if !matches!(event.get_hand()?, Hand::Main)
+                || event.get_target()?.get_entity_id()? != id
+            {
+                return Ok(());
+            }
+            callback(&event.get_player()?)
+        })?;
+        Ok(listener)
+    }
+}
+
+impl EntityLike for Npc {
+    fn java_object(&self) -> &JavaObject {
+        &self.inner
+    }
+}
+
+impl LivingEntityLike for Npc {}
@@ -2,10 +2,16 @@ use jni::objects::{JByteArray, JObject, JValue};
 use jni::sys::jbyteArray;
 
 use crate::Result;
-use crate::entity::PlayerSkin;
+use crate::block::Block;
+use crate::coordinate::Position;
+use crate::entity::{EntityLike, PlayerSkin};
+use crate::instance::Instance;
 use crate::item::{InventoryHolder, PlayerInventory};
 use crate::jni_utils::{JniValue, get_env};
+use crate::raycast::{BlockRaycastHit, EntityRaycastHit, direction_from_rotation};
 use crate::resource_pack::ResourcePackRequest;
+use crate::world_border::{WorldBorder, WorldBorderPacket};
+use std::time::Duration;
 
 /// Trait for anything that knows how to send itself as a packet to a player.
 
@@ -37,6 +43,80 @@ impl crate::entity::Player {
         Ok(())
     }
 
+    /// Shows a world border to this player only.
+    /// The instance border is sent again when the player changes instance.
+    pub fn set_world_border(&self, border: &WorldBorder) -> Result<()> {
+        self.send_packet(&WorldBorderPacket::new(border.clone()))
+    }
+
+    /// Moves this player's world border from `border` to `target_diameter` over `duration`.
+    pub fn transition_world_border(
+        &self,
+        border: &WorldBorder,
+        target_diameter: f64,
+        duration: Duration,
+    ) -> Result<()> {
+        self.send_packet(&WorldBorderPacket::transition(
+            border.clone(),
+            target_diameter,
+            duration,
+        ))
+    }
+
+    /// Position of the player's eyes and the direction they are looking at.
+    pub(crate) fn eye_ray(&self) -> Result<(Position, Position)> {
+        let mut env = get_env()?;
+        let pos = self.inner.call_object_method(
+            "getPosition",
+            "()Lnet/minestom/server/coordinate/Pos;",
+            &[],
+        )?;
+        let pos = pos.as_obj()?;
+        let x = env.call_method(&pos, "x", "()D", &[])?.d()?;
+        let y = env.call_method(&pos, "y", "()D", &[])?.d()?;
+        let z = env.call_method(&pos, "z", "()D", &[])?.d()?;
+        let yaw = env.call_method(&pos, "yaw", "()F", &[])?.f()?;
+        let pitch = env.call_method(&pos, "pitch", "()F", &[])?.f()?;
+        let eye_height = self.inner.call_double_method("getEyeHeight", "()D", &[])?;
+
+        Ok((
+            Position::new(x, y + eye_height, z),
+            direction_from_rotation(yaw, pitch),
+        ))
+    }
+
+    /// Gets the solid block the player is looking at, up to `max_distance` blocks away.
+    pub fn target_block(&self, max_distance: f64) -> Result<Option<BlockRaycastHit>> {
+        let Some(instance) = self.get_instance()? else {
+            return Ok(None);
+        };
+        let (eye, direction) = self.eye_ray()?;
+        instance.raycast_blocks(&eye, &direction, max_distance, &Block::is_solid)
+    }
+
+    /// Gets the entity the player is looking at, up to `max_distance` blocks away.
+    /// Entities behind blocks are not returned.
+    pub fn target_entity(&self, max_distance: f64) -> Result<Option<EntityRaycastHit>> {
+        let Some(instance) = self.get_instance()? else {
+            return Ok(None);
+        };
+        let (eye, direction) = self.eye_ray()?;
+
+        // Do not look further than the first block in the way
+        let block_hit =
+            instance.raycast_blocks(&eye, &direction, max_distance, &Block::is_solid)?;
+        let max_distance = match block_hit {
+            Some(block_hit) => block_hit.distance,
+            None => max_distance,
+        };
+
+        let env = get_env()?;
+        let player = self.inner.as_obj()?;
+        instance.raycast_entities(&eye, &direction, max_distance, &|entity| {
+            Ok(!env.is_same_object(&player, entity.inner.as_obj()?)?)
+        })
+    }
+
     /// Sends resource packs to the player
     pub fn send_resource_packs(&self, request: &ResourcePackRequest) -> Result<()> {
         let mut env = get_env()?;
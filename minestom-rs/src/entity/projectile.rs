@@ -0,0 +1,219 @@
+use crate::Result;
+use crate::block::Block;
+use crate::coordinate::{Pos, Position};
+use crate::entity::display::{BlockDisplay, DisplayLike, ItemDisplay};
+use crate::entity::entity::{Entity, EntityType};
+use crate::entity::{EntityLike, Player};
+use crate::error::MinestomError;
+use crate::item::ItemStack;
+use crate::jni_utils::{JavaObject, JniValue, get_env};
+use jni::objects::{JObject, JValue};
+
+/// Ticks per second, velocities are sent in blocks per second
+const TICKS_PER_SECOND: f64 = 20.0;
+/// Deviation of the direction for a spread of 1, as in vanilla
+const SPREAD_DEVIATION: f64 = 0.0075;
+
+/// An entity flying on its own, such as an arrow, a snowball or a fireball.
+///
+/// Hits are reported through
+/// [`ProjectileCollideWithBlockEvent`](crate::event::projectile::ProjectileCollideWithBlockEvent) and
+/// [`ProjectileCollideWithEntityEvent`](crate::event::projectile::ProjectileCollideWithEntityEvent).
+///
+/// # Example
+/// ```rust,no_run
+/// let snowball = Projectile::new(EntityType::Snowball, Some(&player))?;
+/// snowball.shoot_at(&target, 1.5, 1.0)?;
+/// ```
+#[derive(Clone)]
+pub struct Projectile {
+    pub(crate) inner: JavaObject,
+}
+
+impl Projectile {
+    /// Creates a projectile of the given type, shot by `shooter` if any.
+    pub fn new(entity_type: EntityType, shooter: Option<&dyn EntityLike>) -> Result<Self> {
+        let mut env = get_env()?;
+        let entity_type = env
+            .get_static_field(
+                "net/minestom/server/entity/EntityType",
+                entity_type.to_java_field(),
+                "Lnet/minestom/server/entity/EntityType;",
+            )?
+            .l()?;
+        let shooter = match shooter {
+            Some(shooter) => shooter.java_object().as_obj()?,
+            None => JObject::null(),
+        };
+        let projectile = env.new_object(
+            "net/minestom/server/entity/EntityProjectile",
+            "(Lnet/minestom/server/entity/Entity;Lnet/minestom/server/entity/EntityType;)V",
+            &[JValue::Object(&shooter), JValue::Object(&entity_type)],
+        )?;
+        Ok(Self {
+            inner: JavaObject::from_env(&mut env, projectile)?,
+        })
+    }
+
+    /// Creates a projectile rendered as an item, e.g. with a custom model.
+    pub fn item_display(item: &ItemStack, shooter: Option<&dyn EntityLike>) -> Result<Self> {
+        let projectile = Self::new(EntityType::ItemDisplay, shooter)?;
+        let display = ItemDisplay::from_entity(projectile.inner.clone());
+        display.set_item(item)?;
+        // Displays ignore the velocity, smooth the position updates instead
+        display.set_teleport_duration(1)?;
+        Ok(projectile)
+    }
+
+    /// Creates a projectile rendered as a block.
+    pub fn block_display(block: &Block, shooter: Option<&dyn EntityLike>) -> Result<Self> {
+        let projectile = Self::new(EntityType::BlockDisplay, shooter)?;
+        let display = BlockDisplay::from_entity(projectile.inner.clone());
+        display.set_block(block)?;
+        display.set_teleport_duration(1)?;
+        Ok(projectile)
+    }
+
+    /// Gets the entity which shot this projectile
+    pub fn get_shooter(&self) -> Result<Option<Entity>> {
+        let shooter = self.inner.call_object_method(
+            "getShooter",
+            "()Lnet/minestom/server/entity/Entity;",
+            &[],
+        )?;
+        if shooter.as_obj()?.is_null() {
+            return Ok(None);
+        }
+        Ok(Some(Entity::new(shooter)))
+    }
+
+    /// Gets the shooter if it is a player
+    pub fn get_player_shooter(&self) -> Result<Option<Player>> {
+        match self.get_shooter()? {
+            Some(shooter) => shooter.as_player(),
+            None => Ok(None),
+        }
+    }
+
+    /// Shoots the projectile from `from` towards `to`.
+    ///
+    /// `power` is the speed in blocks per tick, e.g. 3 for a fully drawn bow and 1.5 for
+    /// a snowball. `spread` randomizes the direction, 1 being the inaccuracy of vanilla.
+    /// The projectile is spawned in the instance of its shooter if it is in none yet.
+    pub fn shoot(&self, from: &Position, to: &Position, power: f64, spread: f64) -> Result<()> {
+        let mut env = get_env()?;
+        let (mut dx, mut dy, mut dz) = (to.x - from.x, to.y - from.y, to.z - from.z);
+        let length = (dx * dx + dy * dy + dz * dz).sqrt();
+        if length == 0.0 {
+            return Err(MinestomError::InvalidArgument(
+                "cannot shoot a projectile at its own position".to_string(),
+            ));
+        }
+
+        let random = env
+            .call_static_method(
+                "java/util/concurrent/ThreadLocalRandom",
+                "current",
+                "()Ljava/util/concurrent/ThreadLocalRandom;",
+                &[],
+            )?
+            .l()?;
+        let deviation = spread * SPREAD_DEVIATION;
+        let mut gaussian = || -> Result<f64> {
+            Ok(env.call_method(&random, "nextGaussian", "()D", &[])?.d()? * deviation)
+        };
+        dx = dx / length + gaussian()?;
+        dy = dy / length + gaussian()?;
+        dz = dz / length + gaussian()?;
+
+        let speed = power * TICKS_PER_SECOND;
+        self.set_velocity(&Position::new(dx * speed, dy * speed, dz * speed))?;
+
+        // Projectiles face where they are going, unlike other entities
+        let yaw = dx.atan2(dz).to_degrees() as f32;
+        let pitch = dy.atan2((dx * dx + dz * dz).sqrt()).to_degrees() as f32;
+        let pos = Pos::of(from.x, from.y, from.z, yaw, pitch);
+
+        if self.get_instance()?.is_some() {
+            self.teleport_to(&pos)?;
+            return Ok(());
+        }
+        let instance = match self.get_shooter()? {
+            Some(shooter) => shooter.get_instance()?,
+            None => None,
+        };
+        match instance {
+            Some(instance) => {
+                self.set_instance(&instance, &pos)?;
+                Ok(())
+            }
+            None => Err(MinestomError::InvalidArgument(
+                "projectile has no instance to be shot in".to_string(),
+            )),
+        }
+    }
+
+    /// Shoots the projectile from the eyes of its shooter towards `to`.
+    pub fn shoot_at(&self, to: &Position, power: f64, spread: f64) -> Result<()> {
+        let Some(shooter) = self.get_shooter()? else {
+            return Err(MinestomError::InvalidArgument(
+                "projectile has no shooter".to_string(),
+            ));
+        };
+        let position = shooter.get_position()?;
+        let eye_height = shooter
+            .inner
+            .call_double_method("getEyeHeight", "()D", &[])?;
+        let from = Position::new(position.x, position.y + eye_height, position.z);
+        self.shoot(&from, to, power, spread)
+    }
+
+    fn aerodynamics(&self) -> Result<JavaObject> {
+        self.inner.call_object_method(
+            "getAerodynamics",
+            "()Lnet/minestom/server/collision/Aerodynamics;",
+            &[],
+        )
+    }
+
+    fn set_aerodynamics(&self, aerodynamics: JavaObject) -> Result<()> {
+        self.inner.call_void_method(
+            "setAerodynamics",
+            "(Lnet/minestom/server/collision/Aerodynamics;)V",
+            &[JniValue::Object(aerodynamics.as_obj()?)],
+        )
+    }
+
+    /// Sets the downward acceleration in blocks per tick squared, 0.05 for arrows
+    pub fn set_gravity(&self, gravity: f64) -> Result<()> {
+        let aerodynamics = self.aerodynamics()?.call_object_method(
+            "withGravity",
+            "(D)Lnet/minestom/server/collision/Aerodynamics;",
+            &[JniValue::Double(gravity)],
+        )?;
+        self.set_aerodynamics(aerodynamics)
+    }
+
+    /// Sets the fraction of its velocity the projectile loses every tick, 0.01 for arrows
+    pub fn set_drag(&self, drag: f64) -> Result<()> {
+        let aerodynamics = self
+            .aerodynamics()?
+            .call_object_method(
+                "withHorizontalAirResistance",
+                "(D)Lnet/minestom/server/collision/Aerodynamics;",
+                &[JniValue::Double(1.0 - drag)],
+            )?
+            .call_object_method(
+                "withVerticalAirResistance",
+                "(D)Lnet/minestom/server/collision/Aerodynamics;",
+                &[JniValue::Double(1.0 - drag)],
+            )?;
+        self.set_aerodynamics(aerodynamics)
+    }
+}
+
+impl EntityLike for Projectile {
+    fn java_object(&self) -> &JavaObject {
+        &self.inner
+    }
+}
@@ -0,0 +1,304 @@
+use crate::Result;
+use crate::entity::display::apply_transform;
+use crate::error::MinestomError;
+use crate::jni_utils::JavaObject;
+use crate::scheduler::submit_stepped_task;
+use log::error;
+use parking_lot::Mutex;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::sync::oneshot;
+
+/// Transformation of a display entity, see [`DisplayLike`](crate::entity::DisplayLike).
+///
+/// Rotations are quaternions given as `[x, y, z, w]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    pub translation: [f32; 3],
+    pub scale: [f32; 3],
+    pub left_rotation: [f32; 4],
+    pub right_rotation: [f32; 4],
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self {
+            translation: [0.0; 3],
+            scale: [1.0; 3],
+            left_rotation: [0.0, 0.0, 0.0, 1.0],
+            right_rotation: [0.0, 0.0, 0.0, 1.0],
+        }
+    }
+}
+
+impl Transform {
+    pub fn with_translation(mut self, x: f32, y: f32, z: f32) -> Self {
+        self.translation = [x, y, z];
+        self
+    }
+
+    pub fn with_scale(mut self, x: f32, y: f32, z: f32) -> Self {
+        self.scale = [x, y, z];
+        self
+    }
+
+    /// Rotates by `angle` radians around the vertical axis
+    pub fn with_yaw(mut self, angle: f32) -> Self {
+        let half = angle / 2.0;
+        self.left_rotation = [0.0, half.sin(), 0.0, half.cos()];
+        self
+    }
+
+    pub fn with_left_rotation(mut self, rotation: [f32; 4]) -> Self {
+        self.left_rotation = rotation;
+        self
+    }
+
+    pub fn with_right_rotation(mut self, rotation: [f32; 4]) -> Self {
+        self.right_rotation = rotation;
+        self
+    }
+
+    /// Interpolates towards `other`, `t` going from 0 to 1
+    pub fn lerp(&self, other: &Transform, t: f32) -> Transform {
+        let lerp3 = |a: [f32; 3], b: [f32; 3]| {
+            [
+                a[0] + (b[0] - a[0]) * t,
+                a[1] + (b[1] - a[1]) * t,
+                a[2] + (b[2] - a[2]) * t,
+            ]
+        };
+        Transform {
+            translation: lerp3(self.translation, other.translation),
+            scale: lerp3(self.scale, other.scale),
+            left_rotation: nlerp(self.left_rotation, other.left_rotation, t),
+            right_rotation: nlerp(self.right_rotation, other.right_rotation, t),
+        }
+    }
+}
+
+/// Normalized linear interpolation of quaternions, taking the shortest path
+fn nlerp(a: [f32; 4], b: [f32; 4], t: f32) -> [f32; 4] {
+    let dot = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3];
+    let sign = if dot < 0.0 { -1.0 } else { 1.0 };
+    let mut q = [0.0; 4];
+    for i in 0..4 {
+        q[i] = a[i] + (b[i] * sign - a[i]) * t;
+    }
+    let length = (q[0] * q[0] + q[1] * q[1] + q[2] * q[2] + q[3] * q[3]).sqrt();
+    if length == 0.0 {
+        return b;
+    }
+    q.map(|c| c / length)
+}
+
+/// Progression of a tween over time.
+///
+/// Linear tweens are interpolated by the client. Other easings are sent as one
+/// step per tick, which costs more bandwidth.
+#[derive(Debug, Clone, Copy)]
+pub enum Easing {
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    /// Maps the progress, from 0 to 1, to the interpolation factor
+    Custom(fn(f32) -> f32),
+}
+
+impl Easing {
+    pub fn apply(&self, t: f32) -> f32 {
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            Easing::Custom(f) => f(t),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Keyframe {
+    transform: Transform,
+    ticks: u32,
+    easing: Easing,
+}
+
+/// A sequence of keyframes played on a display entity with
+/// [`DisplayLike::play`](crate::entity::DisplayLike::play).
+///
+/// # Example
+/// ```rust,no_run
+/// // Floats up and down forever
+/// let bob = Tween::new()
+///     .then(Transform::default().with_translation(0.0, 0.5, 0.0), 40, Easing::EaseInOut)
+///     .then(Transform::default(), 40, Easing::EaseInOut)
+///     .looping(true);
+/// display.play(bob)?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Tween {
+    keyframes: Vec<Keyframe>,
+    looping: bool,
+}
+
+impl Tween {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a keyframe reaching `transform` in `ticks` ticks, from the previous keyframe
+    pub fn then(mut self, transform: Transform, ticks: u32, easing: Easing) -> Self {
+        self.keyframes.push(Keyframe {
+            transform,
+            ticks: ticks.max(1),
+            easing,
+        });
+        self
+    }
+
+    /// Starts over from the first keyframe once the last one is reached
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+}
+
+struct TweenState {
+    tween: Tween,
+    keyframe: usize,
+    elapsed: u32,
+    from: Transform,
+    cancelled: bool,
+    completion: Option<oneshot::Sender<bool>>,
+}
+
+impl TweenState {
+    fn finish(&mut self, completed: bool) {
+        if let Some(completion) = self.completion.take() {
+            let _ = completion.send(completed);
+        }
+    }
+
+    /// Applies the transform for the current tick, returns the ticks until the next step
+    fn step(&mut self, entity: &JavaObject, since_last: u32) -> Result<Option<u32>> {
+        if self.cancelled {
+            return Ok(None);
+        }
+        if entity.call_bool_method("isRemoved", "()Z", &[])? {
+            self.finish(false);
+            return Ok(None);
+        }
+
+        self.elapsed += since_last;
+        while self.elapsed >= self.tween.keyframes[self.keyframe].ticks {
+            let keyframe = self.tween.keyframes[self.keyframe];
+            self.elapsed -= keyframe.ticks;
+            self.from = keyframe.transform;
+            self.keyframe += 1;
+            if self.keyframe == self.tween.keyframes.len() {
+                if !self.tween.looping {
+                    self.finish(true);
+                    return Ok(None);
+                }
+                self.keyframe = 0;
+            }
+        }
+
+        let keyframe = self.tween.keyframes[self.keyframe];
+        if matches!(keyframe.easing, Easing::Linear) {
+            // The client interpolates the whole keyframe by itself
+            if self.elapsed == 0 {
+                apply_transform(entity, &keyframe.transform, keyframe.ticks as i32)?;
+            }
+            Ok(Some(keyframe.ticks - self.elapsed))
+        } else {
+            let t = (self.elapsed + 1) as f32 / keyframe.ticks as f32;
+            let transform = self
+                .from
+                .lerp(&keyframe.transform, keyframe.easing.apply(t));
+            apply_transform(entity, &transform, 1)?;
+            Ok(Some(1))
+        }
+    }
+}
+
+/// A tween playing on a display entity.
+///
+/// Awaiting it resolves once the tween completes, looping tweens only stop when
+/// cancelled. Dropping it does not stop the tween.
+pub struct TweenHandle {
+    state: Arc<Mutex<TweenState>>,
+    receiver: oneshot::Receiver<bool>,
+}
+
+impl TweenHandle {
+    /// Stops the tween, leaving the entity at its current transformation
+    pub fn cancel(&self) {
+        let mut state = self.state.lock();
+        state.cancelled = true;
+        state.finish(false);
+    }
+}
+
+impl Future for TweenHandle {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.receiver)
+            .poll(cx)
+            .map(|result| match result {
+                Ok(true) => Ok(()),
+                _ => Err(MinestomError::EventError(
+                    "tween cancelled before completing".to_string(),
+                )),
+            })
+    }
+}
+
+pub(crate) fn play(entity: JavaObject, from: Transform, tween: Tween) -> Result<TweenHandle> {
+    if tween.keyframes.is_empty() {
+        return Err(MinestomError::InvalidArgument(
+            "tween has no keyframes".to_string(),
+        ));
+    }
+
+    let (sender, receiver) = oneshot::channel();
+    let state = Arc::new(Mutex::new(TweenState {
+        tween,
+        keyframe: 0,
+        elapsed: 0,
+        from,
+        cancelled: false,
+        completion: Some(sender),
+    }));
+
+    let task_state = state.clone();
+    let mut since_last = 0;
+    submit_stepped_task(move || {
+        let mut state = task_state.lock();
+        match state.step(&entity, since_last) {
+            Ok(Some(ticks)) => {
+                since_last = ticks;
+                Some(ticks)
+            }
+            Ok(None) => None,
+            Err(e) => {
+                error!("Failed to step tween: {}", e);
+                state.finish(false);
+                None
+            }
+        }
+    })?;
+
+    Ok(TweenHandle { state, receiver })
+}
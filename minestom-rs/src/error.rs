@@ -51,4 +51,10 @@ pub enum MinestomError {
 
     #[error("UUID error: {0}")]
     UuidError(#[from] UuidError),
+
+    #[error("NBT error: {0}")]
+    Nbt(String),
+
+    #[error("Schematic error: {0}")]
+    Schematic(String),
 }
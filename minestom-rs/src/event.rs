@@ -1,3 +1,4 @@
+use crate::block::Block;
 use crate::coordinate::{Pos, Position};
 use crate::entity::{Player, PlayerSkin};
 use crate::instance::{Instance, InstanceContainer};
@@ -27,6 +28,26 @@ pub(crate) static FILTER_CALLBACKS: Lazy<
 
 static NEXT_CALLBACK_ID: AtomicU64 = AtomicU64::new(1);
 
+/// Wraps a Rust closure into a Java `Predicate<Player>`.
+/// The closure is freed once the predicate is garbage collected.
+pub(crate) fn player_predicate<F>(filter: F) -> Result<JavaObject>
+where
+    F: Fn(&Player) -> bool + Send + Sync + 'static,
+{
+    let callback_id = NEXT_CALLBACK_ID.fetch_add(1, Ordering::SeqCst);
+    FILTER_CALLBACKS
+        .write()
+        .insert(callback_id, Arc::new(filter));
+
+    let mut env = get_env()?;
+    let predicate = env.new_object(
+        "rust/minestom/PredicateCallback",
+        "(J)V",
+        &[JniValue::Long(callback_id as i64).as_jvalue()],
+    )?;
+    JavaObject::from_env(&mut env, predicate)
+}
+
 /// Represents Minestom's EventNode that can be used to register event listeners.
 #[derive(Clone)]
 pub struct EventNode {
@@ -46,6 +67,16 @@ impl EventNode {
         priority: Option<i32>,
         callback: impl Fn(&E) -> Result<()> + Send + Sync + 'static,
     ) -> Result<()> {
+        self.add_listener(priority, callback)?;
+        Ok(())
+    }
+
+    /// Registers a listener, returns the id of its callback
+    fn add_listener<E: Event + 'static>(
+        &self,
+        priority: Option<i32>,
+        callback: impl Fn(&E) -> Result<()> + Send + Sync + 'static,
+    ) -> Result<u64> {
         // Create wrapper that handles priority if set
         let wrapper = move |event: &dyn Event| -> Result<()> {
             if let Some(e) = event.as_any().downcast_ref::<E>() {
@@ -116,7 +147,7 @@ impl EventNode {
             ],
         )?;
 
-        Ok(())
+        Ok(callback_id)
     }
 
     /// Convenience method to register a listener without priority
@@ -172,16 +203,7 @@ impl EventNode {
         F: Fn(&Player) -> bool + Send + Sync + 'static,
     {
         let mut env = get_env()?;
-
-        // Store the filter callback
-        let callback_id = NEXT_CALLBACK_ID.fetch_add(1, Ordering::SeqCst);
-        let filter = Arc::new(filter);
-        FILTER_CALLBACKS.write().insert(callback_id, filter);
-
-        // Create the Java predicate
-        let predicate_class = env.find_class("rust/minestom/PredicateCallback")?;
-        let predicate = env.new_object(predicate_class, "(J)V", &[(callback_id as i64).into()])?;
-        let predicate_global = env.new_global_ref(predicate)?;
+        let predicate = player_predicate(filter)?;
 
         // Get the PLAYER filter
         let event_filter_class = env.find_class("net/minestom/server/event/EventFilter")?;
@@ -206,7 +228,7 @@ impl EventNode {
             &[
                 (&name_jstring).into(),
                 (&*player_filter_global).into(),
-                (&*predicate_global).into(),
+                (&predicate.as_obj()?).into(),
             ],
         )?;
 
@@ -226,6 +248,63 @@ impl EventNode {
         )?;
         Ok(EventNode::from(result))
     }
+
+    /// Creates a child node for listeners which must be removed later
+    pub(crate) fn removable_listeners(&self, name: &str) -> Result<ListenerHandle> {
+        let mut env = get_env()?;
+        let name = env.new_string(name)?;
+        let node = env
+            .call_static_method(
+                "net/minestom/server/event/EventNode",
+                "all",
+                "(Ljava/lang/String;)Lnet/minestom/server/event/EventNode;",
+                &[(&name).into()],
+            )?
+            .l()?;
+        let node = EventNode::from(JavaObject::from_env(&mut env, node)?);
+        self.add_child(&node)?;
+        Ok(ListenerHandle {
+            parent: self.clone(),
+            node,
+            callbacks: Vec::new(),
+        })
+    }
+}
+
+/// Listeners registered together, such as those of
+/// [`Interaction::on_click`](crate::entity::display::Interaction::on_click).
+///
+/// Dropping the handle keeps the listeners, call [`ListenerHandle::remove`]
+/// once they are no longer needed, e.g. when the clicked entity is removed.
+pub struct ListenerHandle {
+    parent: EventNode,
+    node: EventNode,
+    callbacks: Vec<u64>,
+}
+
+impl ListenerHandle {
+    pub(crate) fn listen<E: Event + 'static>(
+        &mut self,
+        callback: impl Fn(&E) -> Result<()> + Send + Sync + 'static,
+    ) -> Result<()> {
+        let callback_id = self.node.add_listener(None, callback)?;
+        self.callbacks.push(callback_id);
+        Ok(())
+    }
+
+    /// Unregisters the listeners and frees their callbacks
+    pub fn remove(self) -> Result<()> {
+        self.parent.inner.call_object_method(
+            "removeChild",
+            "(Lnet/minestom/server/event/EventNode;)Lnet/minestom/server/event/EventNode;",
+            &[JniValue::Object(self.node.inner.as_obj()?)],
+        )?;
+        let mut callbacks = CALLBACKS.write();
+        for callback_id in &self.callbacks {
+            callbacks.remove(callback_id);
+        }
+        Ok(())
+    }
 }
 
 /// Trait implemented by all Minestom events.
@@ -260,7 +339,7 @@ pub mod player {
     }
 
     impl Hand {
-        fn from_java(value: i32) -> Option<Self> {
+        pub(crate) fn from_java(value: i32) -> Option<Self> {
             match value {
                 0 => Some(Hand::Main),
                 1 => Some(Hand::Off),
@@ -718,6 +797,471 @@ pub mod player {
             Self { inner }
         }
     }
+
+    /// Event fired when a player breaks a block.
+    pub struct PlayerBlockBreakEvent {
+        inner: JavaObject,
+    }
+
+    impl PlayerBlockBreakEvent {
+        /// Gets the player breaking the block.
+        pub fn player(&self) -> Result<Player> {
+            let mut env = get_env()?;
+            let result = self.inner.call_object_method(
+                "getPlayer",
+                "()Lnet/minestom/server/entity/Player;",
+                &[],
+            )?;
+            Ok(Player::new(JavaObject::from_env(
+                &mut env,
+                result.as_obj()?,
+            )?))
+        }
+
+        /// Gets the block being broken.
+        pub fn block(&self) -> Result<Block> {
+            let inner = self.inner.call_object_method(
+                "getBlock",
+                "()Lnet/minestom/server/instance/block/Block;",
+                &[],
+            )?;
+            Ok(Block { inner })
+        }
+
+        /// Gets the position of the block being broken.
+        pub fn block_position(&self) -> Result<Position> {
+            let result = self.inner.call_object_method(
+                "getBlockPosition",
+                "()Lnet/minestom/server/coordinate/BlockVec;",
+                &[],
+            )?;
+            Pos::new(result).to_position()
+        }
+
+        /// Cancels the event, the block is not broken.
+        pub fn cancel(&self) -> Result<()> {
+            self.inner
+                .call_void_method("setCancelled", "(Z)V", &[JniValue::Bool(true)])
+        }
+    }
+
+    impl Event for PlayerBlockBreakEvent {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+        fn java_class_name() -> &'static str {
+            "net/minestom/server/event/player/PlayerBlockBreakEvent"
+        }
+        fn new(inner: JavaObject) -> Self {
+            Self { inner }
+        }
+    }
+
+    /// Event fired when a player places a block.
+    pub struct PlayerBlockPlaceEvent {
+        inner: JavaObject,
+    }
+
+    impl PlayerBlockPlaceEvent {
+        /// Gets the player placing the block.
+        pub fn player(&self) -> Result<Player> {
+            let mut env = get_env()?;
+            let result = self.inner.call_object_method(
+                "getPlayer",
+                "()Lnet/minestom/server/entity/Player;",
+                &[],
+            )?;
+            Ok(Player::new(JavaObject::from_env(
+                &mut env,
+                result.as_obj()?,
+            )?))
+        }
+
+        /// Gets the block being placed.
+        pub fn block(&self) -> Result<Block> {
+            let inner = self.inner.call_object_method(
+                "getBlock",
+                "()Lnet/minestom/server/instance/block/Block;",
+                &[],
+            )?;
+            Ok(Block { inner })
+        }
+
+        /// Gets the position where the block is placed.
+        pub fn block_position(&self) -> Result<Position> {
+            let result = self.inner.call_object_method(
+                "getBlockPosition",
+                "()Lnet/minestom/server/coordinate/BlockVec;",
+                &[],
+            )?;
+            Pos::new(result).to_position()
+        }
+
+        /// Cancels the event, the block is not placed.
+        pub fn cancel(&self) -> Result<()> {
+            self.inner
+                .call_void_method("setCancelled", "(Z)V", &[JniValue::Bool(true)])
+        }
+    }
+
+    impl Event for PlayerBlockPlaceEvent {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+        fn java_class_name() -> &'static str {
+            "net/minestom/server/event/player/PlayerBlockPlaceEvent"
+        }
+        fn new(inner: JavaObject) -> Self {
+            Self { inner }
+        }
+    }
+}
+
+pub mod entity {
+    use super::*;
+    use crate::damage::{Damage, DamageType};
+    use crate::entity::LivingEntity;
+    use crate::entity::entity::Entity;
+    use crate::potion::Potion;
+
+    /// Event fired when a living entity is about to take damage.
+    pub struct EntityDamageEvent {
+        inner: JavaObject,
+    }
+
+    impl EntityDamageEvent {
+        /// Gets the entity being damaged.
+        pub fn entity(&self) -> Result<LivingEntity> {
+            let entity = self.inner.call_object_method(
+                "getEntity",
+                "()Lnet/minestom/server/entity/LivingEntity;",
+                &[],
+            )?;
+            Ok(LivingEntity::new(entity))
+        }
+
+        /// Gets the damage, with its source and attacker.
+        pub fn damage(&self) -> Result<Damage> {
+            let inner = self.inner.call_object_method(
+                "getDamage",
+                "()Lnet/minestom/server/entity/damage/Damage;",
+                &[],
+            )?;
+            Ok(Damage { inner })
+        }
+
+        /// Gets the type of the damage, e.g. `minecraft:fall`.
+        pub fn damage_type(&self) -> Result<DamageType> {
+            self.damage()?.damage_type()
+        }
+
+        /// Gets the amount of damage, in half hearts.
+        pub fn amount(&self) -> Result<f32> {
+            self.damage()?.amount()
+        }
+
+        /// Changes the amount of damage dealt.
+        pub fn set_amount(&self, amount: f32) -> Result<()> {
+            self.damage()?.set_amount(amount)
+        }
+
+        /// Cancels the event, no damage is dealt.
+        pub fn cancel(&self) -> Result<()> {
+            self.inner
+                .call_void_method("setCancelled", "(Z)V", &[JniValue::Bool(true)])
+        }
+    }
+
+    impl Event for EntityDamageEvent {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+        fn java_class_name() -> &'static str {
+            "net/minestom/server/event/entity/EntityDamageEvent"
+        }
+        fn new(inner: JavaObject) -> Self {
+            Self { inner }
+        }
+    }
+
+    /// Event fired when an entity attacks another, e.g. a player left-clicking an entity.
+    pub struct EntityAttackEvent {
+        inner: JavaObject,
+    }
+
+    impl EntityAttackEvent {
+        /// Gets the attacking entity.
+        pub fn entity(&self) -> Result<Entity> {
+            let entity = self.inner.call_object_method(
+                "getEntity",
+                "()Lnet/minestom/server/entity/Entity;",
+                &[],
+            )?;
+            Ok(Entity::new(entity))
+        }
+
+        /// Gets the attacked entity.
+        pub fn target(&self) -> Result<Entity> {
+            let target = self.inner.call_object_method(
+                "getTarget",
+                "()Lnet/minestom/server/entity/Entity;",
+                &[],
+            )?;
+            Ok(Entity::new(target))
+        }
+    }
+
+    impl Event for EntityAttackEvent {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+        fn java_class_name() -> &'static str {
+            "net/minestom/server/event/entity/EntityAttackEvent"
+        }
+        fn new(inner: JavaObject) -> Self {
+            Self { inner }
+        }
+    }
+
+    fn potion_event_entity(inner: &JavaObject) -> Result<Entity> {
+        let entity =
+            inner.call_object_method("getEntity", "()Lnet/minestom/server/entity/Entity;", &[])?;
+        Ok(Entity::new(entity))
+    }
+
+    fn potion_event_potion(inner: &JavaObject) -> Result<Potion> {
+        let potion =
+            inner.call_object_method("getPotion", "()Lnet/minestom/server/potion/Potion;", &[])?;
+        Potion::from_java(&potion)
+    }
+
+    /// Event fired after an effect is applied to an entity.
+    pub struct EntityPotionAddEvent {
+        inner: JavaObject,
+    }
+
+    impl EntityPotionAddEvent {
+        pub fn entity(&self) -> Result<Entity> {
+            potion_event_entity(&self.inner)
+        }
+
+        pub fn potion(&self) -> Result<Potion> {
+            potion_event_potion(&self.inner)
+        }
+    }
+
+    impl Event for EntityPotionAddEvent {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+        fn java_class_name() -> &'static str {
+            "net/minestom/server/event/entity/EntityPotionAddEvent"
+        }
+        fn new(inner: JavaObject) -> Self {
+            Self { inner }
+        }
+    }
+
+    /// Event fired after an effect is removed from an entity or expires.
+    pub struct EntityPotionRemoveEvent {
+        inner: JavaObject,
+    }
+
+    impl EntityPotionRemoveEvent {
+        pub fn entity(&self) -> Result<Entity> {
+            potion_event_entity(&self.inner)
+        }
+
+        pub fn potion(&self) -> Result<Potion> {
+            potion_event_potion(&self.inner)
+        }
+    }
+
+    impl Event for EntityPotionRemoveEvent {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+        fn java_class_name() -> &'static str {
+            "net/minestom/server/event/entity/EntityPotionRemoveEvent"
+        }
+        fn new(inner: JavaObject) -> Self {
+            Self { inner }
+        }
+    }
+
+    /// Event fired when a living entity dies.
+    pub struct EntityDeathEvent {
+        inner: JavaObject,
+    }
+
+    impl EntityDeathEvent {
+        /// Gets the entity that died.
+        pub fn entity(&self) -> Result<LivingEntity> {
+            let entity = self.inner.call_object_method(
+                "getEntity",
+                "()Lnet/minestom/server/entity/LivingEntity;",
+                &[],
+            )?;
+            Ok(LivingEntity::new(entity))
+        }
+    }
+
+    impl Event for EntityDeathEvent {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+        fn java_class_name() -> &'static str {
+            "net/minestom/server/event/entity/EntityDeathEvent"
+        }
+        fn new(inner: JavaObject) -> Self {
+            Self { inner }
+        }
+    }
+}
+
+pub mod projectile {
+    use super::*;
+    use crate::entity::Projectile;
+    use crate::entity::entity::Entity;
+
+    fn collision_projectile(inner: &JavaObject) -> Result<Projectile> {
+        let inner =
+            inner.call_object_method("getEntity", "()Lnet/minestom/server/entity/Entity;", &[])?;
+        Ok(Projectile { inner })
+    }
+
+    fn collision_position(inner: &JavaObject) -> Result<Position> {
+        let pos = inner.call_object_method(
+            "getCollisionPosition",
+            "()Lnet/minestom/server/coordinate/Pos;",
+            &[],
+        )?;
+        Pos::new(pos).to_position()
+    }
+
+    /// Event fired when a projectile hits a block.
+    /// Unless cancelled, the projectile gets stuck in the block.
+    pub struct ProjectileCollideWithBlockEvent {
+        inner: JavaObject,
+    }
+
+    impl ProjectileCollideWithBlockEvent {
+        pub fn projectile(&self) -> Result<Projectile> {
+            collision_projectile(&self.inner)
+        }
+
+        /// Gets the position where the projectile hit the block.
+        pub fn position(&self) -> Result<Position> {
+            collision_position(&self.inner)
+        }
+
+        pub fn block(&self) -> Result<Block> {
+            let inner = self.inner.call_object_method(
+                "getBlock",
+                "()Lnet/minestom/server/instance/block/Block;",
+                &[],
+            )?;
+            Ok(Block { inner })
+        }
+
+        /// Cancels the collision, the projectile keeps flying.
+        pub fn cancel(&self) -> Result<()> {
+            self.inner
+                .call_void_method("setCancelled", "(Z)V", &[JniValue::Bool(true)])
+        }
+    }
+
+    impl Event for ProjectileCollideWithBlockEvent {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+        fn java_class_name() -> &'static str {
+            "net/minestom/server/event/entity/projectile/ProjectileCollideWithBlockEvent"
+        }
+        fn new(inner: JavaObject) -> Self {
+            Self { inner }
+        }
+    }
+
+    /// Event fired when a projectile hits an entity.
+    /// Unless cancelled, the projectile is removed.
+    pub struct ProjectileCollideWithEntityEvent {
+        inner: JavaObject,
+    }
+
+    impl ProjectileCollideWithEntityEvent {
+        pub fn projectile(&self) -> Result<Projectile> {
+            collision_projectile(&self.inner)
+        }
+
+        /// Gets the position where the projectile hit the entity.
+        pub fn position(&self) -> Result<Position> {
+            collision_position(&self.inner)
+        }
+
+        /// Gets the entity which was hit.
+        pub fn target(&self) -> Result<Entity> {
+            let target = self.inner.call_object_method(
+                "getTarget",
+                "()Lnet/minestom/server/entity/Entity;",
+                &[],
+            )?;
+            Ok(Entity::new(target))
+        }
+
+        /// Cancels the collision, the projectile keeps flying.
+        pub fn cancel(&self) -> Result<()> {
+            self.inner
+                .call_void_method("setCancelled", "(Z)V", &[JniValue::Bool(true)])
+        }
+    }
+
+    impl Event for ProjectileCollideWithEntityEvent {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+        fn java_class_name() -> &'static str {
+            "net/minestom/server/event/entity/projectile/ProjectileCollideWithEntityEvent"
+        }
+        fn new(inner: JavaObject) -> Self {
+            Self { inner }
+        }
+    }
+}
+
+pub mod instance {
+    use super::*;
+    use crate::entity::entity::Entity;
+
+    /// Event fired when an entity leaves an instance, because it is removed
+    /// or moved to another instance. Players disconnecting also leave their instance.
+    pub struct RemoveEntityFromInstanceEvent {
+        inner: JavaObject,
+    }
+
+    impl RemoveEntityFromInstanceEvent {
+        /// Gets the entity leaving the instance.
+        pub fn entity(&self) -> Result<Entity> {
+            let entity = self.inner.call_object_method(
+                "getEntity",
+                "()Lnet/minestom/server/entity/Entity;",
+                &[],
+            )?;
+            Ok(Entity::new(entity))
+        }
+    }
+
+    impl Event for RemoveEntityFromInstanceEvent {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+        fn java_class_name() -> &'static str {
+            "net/minestom/server/event/instance/RemoveEntityFromInstanceEvent"
+        }
+        fn new(inner: JavaObject) -> Self {
+            Self { inner }
+        }
+    }
 }
 
 pub mod server {
@@ -893,6 +1437,16 @@ pub use self::ping::ResponseData;
 pub use self::player::PlayerDisconnectEvent;
 pub use self::server::ServerListPingEvent;
 
+/// JNI callback: the PredicateCallback was collected
+#[unsafe(no_mangle)]
+pub unsafe extern "system" fn Java_rust_minestom_PredicateCallback_nativeFree(
+    _raw_env: *mut jni::sys::JNIEnv,
+    _class: jni::objects::JClass,
+    callback_id: jni::sys::jlong,
+) {
+    FILTER_CALLBACKS.write().remove(&(callback_id as u64));
+}
+
 #[unsafe(no_mangle)]
 pub unsafe extern "system" fn Java_rust_minestom_PredicateCallback_testPlayer(
     env: *mut jni::sys::JNIEnv,
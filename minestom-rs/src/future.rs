@@ -0,0 +1,120 @@
+use crate::Result;
+use crate::error::MinestomError;
+use crate::jni_utils::{JavaObject, JniValue, get_env};
+use jni::JNIEnv;
+use jni::objects::{JClass, JObject, JString, JValue};
+use jni::sys::{jlong, jobject};
+use log::error;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::task::{Context, Poll};
+use tokio::sync::oneshot;
+
+type Completion = std::result::Result<(), String>;
+
+// Registry mapping callback IDs → the Rust side of pending futures
+static PENDING_FUTURES: Lazy<Mutex<HashMap<u64, oneshot::Sender<Completion>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+static NEXT_FUTURE_ID: AtomicU64 = AtomicU64::new(1);
+
+/// A Java `CompletableFuture`, such as the one returned by a teleport.
+///
+/// It can be awaited from async code, or waited for with [`JavaFuture::wait`].
+/// Dropping it does not cancel the underlying operation.
+pub struct JavaFuture {
+    inner: JavaObject,
+    receiver: oneshot::Receiver<Completion>,
+}
+
+impl JavaFuture {
+    /// Wraps a `CompletableFuture` and gets notified of its completion.
+    pub(crate) fn new(inner: JavaObject) -> Result<Self> {
+        let id = NEXT_FUTURE_ID.fetch_add(1, Ordering::SeqCst);
+        let (sender, receiver) = oneshot::channel();
+        PENDING_FUTURES.lock().insert(id, sender);
+
+        let mut env = get_env()?;
+        let callback = env.new_object(
+            "rust/minestom/FutureCallback",
+            "(J)V",
+            &[JValue::Long(id as i64)],
+        )?;
+        // Runs the callback right away if the future is already complete
+        inner.call_object_method(
+            "whenComplete",
+            "(Ljava/util/function/BiConsumer;)Ljava/util/concurrent/CompletableFuture;",
+            &[JniValue::Object(callback)],
+        )?;
+
+        Ok(Self { inner, receiver })
+    }
+
+    /// Returns true if the operation is complete.
+    pub fn is_done(&self) -> Result<bool> {
+        self.inner.call_bool_method("isDone", "()Z", &[])
+    }
+
+    /// Blocks the current thread until the operation completes.
+    ///
+    /// Blocking the server thread, e.g. inside an event listener, may deadlock
+    /// if the operation needs it to complete.
+    pub fn wait(self) -> Result<()> {
+        self.inner
+            .call_object_method("join", "()Ljava/lang/Object;", &[])?;
+        Ok(())
+    }
+}
+
+impl Future for JavaFuture {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.receiver)
+            .poll(cx)
+            .map(|result| match result {
+                Ok(Ok(())) => Ok(()),
+                Ok(Err(e)) => Err(MinestomError::JvmError(e)),
+                Err(_) => Err(MinestomError::JvmError(
+                    "future dropped without completing".to_string(),
+                )),
+            })
+    }
+}
+
+/// JNI callback: accept(Object result, Throwable error)
+#[unsafe(no_mangle)]
+pub unsafe extern "system" fn Java_rust_minestom_FutureCallback_nativeComplete(
+    raw_env: *mut jni::sys::JNIEnv,
+    _class: JClass,
+    callback_id: jlong,
+    _result: jobject,
+    j_error: jobject,
+) {
+    let Some(sender) = PENDING_FUTURES.lock().remove(&(callback_id as u64)) else {
+        return;
+    };
+    let j_error = unsafe { JObject::from_raw(j_error) };
+    if j_error.is_null() {
+        let _ = sender.send(Ok(()));
+        return;
+    }
+
+    let mut env = match unsafe { JNIEnv::from_raw(raw_env) } {
+        Ok(env) => env,
+        Err(e) => {
+            error!("Failed to get JNIEnv: {}", e);
+            let _ = sender.send(Err(e.to_string()));
+            return;
+        }
+    };
+    let message = env
+        .call_method(&j_error, "toString", "()Ljava/lang/String;", &[])
+        .and_then(|message| message.l())
+        .and_then(|message| env.get_string(&JString::from(message)).map(String::from))
+        .unwrap_or_else(|_| "Unknown error".to_string());
+    let _ = sender.send(Err(message));
+}
@@ -0,0 +1,215 @@
+use crate::biome::BiomeKey;
+use crate::block::Block;
+use crate::coordinate::{Pos, Position};
+use crate::jni_utils::{JavaObject, JniValue, get_env};
+use jni::JNIEnv;
+use jni::objects::{JClass, JObject, JValue};
+use jni::sys::{jlong, jobject};
+use log::error;
+use once_cell::sync::Lazy;
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc, RwLock,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+/// World generator implemented in Rust.
+///
+/// Minestom calls the generator for each section of a chunk being loaded, possibly
+/// from several threads at once.
+///
+/// Closures taking a [`GenerationUnit`] implement this trait:
+/// ```rust,no_run
+/// instance.set_generator(Arc::new(|unit: &GenerationUnit| {
+///     unit.fill_height(0, 40, &BlockType::Stone.to_block()?)?;
+///     unit.fill_biome(&dusk)
+/// }))?;
+/// ```
+pub trait Generator: Send + Sync + 'static {
+    fn generate(&self, unit: &GenerationUnit) -> crate::Result<()>;
+}
+
+impl<F> Generator for F
+where
+    F: Fn(&GenerationUnit) -> crate::Result<()> + Send + Sync + 'static,
+{
+    fn generate(&self, unit: &GenerationUnit) -> crate::Result<()> {
+        self(unit)
+    }
+}
+
+/// The area being generated, with absolute block coordinates.
+pub struct GenerationUnit {
+    inner: JavaObject,
+    modifier: JavaObject,
+}
+
+impl GenerationUnit {
+    fn new(inner: JavaObject) -> crate::Result<Self> {
+        let modifier = inner.call_object_method(
+            "modifier",
+            "()Lnet/minestom/server/instance/generator/UnitModifier;",
+            &[],
+        )?;
+        Ok(Self { inner, modifier })
+    }
+
+    /// Lowest corner of the unit, inclusive
+    pub fn absolute_start(&self) -> crate::Result<Position> {
+        let point = self.inner.call_object_method(
+            "absoluteStart",
+            "()Lnet/minestom/server/coordinate/Point;",
+            &[],
+        )?;
+        Pos::new(point).to_position()
+    }
+
+    /// Highest corner of the unit, exclusive
+    pub fn absolute_end(&self) -> crate::Result<Position> {
+        let point = self.inner.call_object_method(
+            "absoluteEnd",
+            "()Lnet/minestom/server/coordinate/Point;",
+            &[],
+        )?;
+        Pos::new(point).to_position()
+    }
+
+    /// Sets a block, the coordinates must be inside the unit
+    pub fn set_block(&self, x: i32, y: i32, z: i32, block: &Block) -> crate::Result<()> {
+        self.modifier.call_void_method(
+            "setBlock",
+            "(IIILnet/minestom/server/instance/block/Block;)V",
+            &[
+                JniValue::Int(x),
+                JniValue::Int(y),
+                JniValue::Int(z),
+                JniValue::Object(block.inner.as_obj()?),
+            ],
+        )
+    }
+
+    /// Fills the whole unit with a block
+    pub fn fill(&self, block: &Block) -> crate::Result<()> {
+        self.modifier.call_void_method(
+            "fill",
+            "(Lnet/minestom/server/instance/block/Block;)V",
+            &[JniValue::Object(block.inner.as_obj()?)],
+        )
+    }
+
+    /// Fills the unit between `min_height` (inclusive) and `max_height` (exclusive)
+    pub fn fill_height(
+        &self,
+        min_height: i32,
+        max_height: i32,
+        block: &Block,
+    ) -> crate::Result<()> {
+        self.modifier.call_void_method(
+            "fillHeight",
+            "(IILnet/minestom/server/instance/block/Block;)V",
+            &[
+                JniValue::Int(min_height),
+                JniValue::Int(max_height),
+                JniValue::Object(block.inner.as_obj()?),
+            ],
+        )
+    }
+
+    /// Sets the biome at a position, biomes are stored per 4x4x4 cell
+    pub fn set_biome(&self, x: i32, y: i32, z: i32, biome: &BiomeKey) -> crate::Result<()> {
+        self.modifier.call_void_method(
+            "setBiome",
+            "(IIILnet/minestom/server/registry/DynamicRegistry$Key;)V",
+            &[
+                JniValue::Int(x),
+                JniValue::Int(y),
+                JniValue::Int(z),
+                JniValue::Object(biome.inner.as_obj()?),
+            ],
+        )
+    }
+
+    /// Sets the biome of the whole unit
+    pub fn fill_biome(&self, biome: &BiomeKey) -> crate::Result<()> {
+        self.modifier.call_void_method(
+            "fillBiome",
+            "(Lnet/minestom/server/registry/DynamicRegistry$Key;)V",
+            &[JniValue::Object(biome.inner.as_obj()?)],
+        )
+    }
+}
+
+// Registry mapping callback IDs → the Rust implementation,
+// entries are freed once the Java generator is garbage collected
+static GENERATOR_REGISTRY: Lazy<RwLock<HashMap<u64, Arc<dyn Generator>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+static NEXT_GENERATOR_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Creates the Java `Generator` object backed by a Rust generator.
+pub(crate) fn create_generator(generator: Arc<dyn Generator>) -> crate::Result<JavaObject> {
+    let id = NEXT_GENERATOR_ID.fetch_add(1, Ordering::SeqCst);
+    GENERATOR_REGISTRY.write().unwrap().insert(id, generator);
+
+    let mut env = get_env()?;
+    let obj = env.new_object(
+        "rust/minestom/GeneratorCallback",
+        "(J)V",
+        &[JValue::Long(id as i64)],
+    )?;
+    JavaObject::from_env(&mut env, obj)
+}
+
+/// JNI callback: the GeneratorCallback was collected
+#[unsafe(no_mangle)]
+pub unsafe extern "system" fn Java_rust_minestom_GeneratorCallback_nativeFree(
+    _raw_env: *mut jni::sys::JNIEnv,
+    _class: JClass,
+    callback_id: jlong,
+) {
+    GENERATOR_REGISTRY
+        .write()
+        .unwrap()
+        .remove(&(callback_id as u64));
+}
+
+/// JNI callback: generate(GenerationUnit)
+#[unsafe(no_mangle)]
+pub unsafe extern "system" fn Java_rust_minestom_GeneratorCallback_nativeGenerate(
+    raw_env: *mut jni::sys::JNIEnv,
+    _class: JClass,
+    callback_id: jlong,
+    j_unit: jobject,
+) {
+    let generator = match GENERATOR_REGISTRY
+        .read()
+        .unwrap()
+        .get(&(callback_id as u64))
+    {
+        Some(generator) => generator.clone(),
+        None => return,
+    };
+    let mut env = match unsafe { JNIEnv::from_raw(raw_env) } {
+        Ok(env) => env,
+        Err(e) => {
+            error!("Failed to get JNIEnv: {}", e);
+            return;
+        }
+    };
+    let unit = match JavaObject::from_env(&mut env, unsafe { JObject::from_raw(j_unit) })
+        .and_then(GenerationUnit::new)
+    {
+        Ok(unit) => unit,
+        Err(e) => {
+            error!("Failed to wrap generation unit: {}", e);
+            return;
+        }
+    };
+
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| generator.generate(&unit))) {
+        Ok(Ok(())) => {}
+        Ok(Err(e)) => error!("Error in generator {}: {}", callback_id, e),
+        Err(_) => error!("Panic in generator {}", callback_id),
+    }
+}
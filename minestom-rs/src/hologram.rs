@@ -0,0 +1,431 @@
+use crate::Result;
+use crate::block::Block;
+use crate::coordinate::Position;
+use crate::entity::display::{
+    Billboard, BlockDisplay, DisplayLike, Interaction, InteractionClick, ItemDisplay, TextDisplay,
+};
+use crate::entity::{EntityLike, Player};
+use crate::error::MinestomError;
+use crate::event::ListenerHandle;
+use crate::event::player::PlayerDisconnectEvent;
+use crate::instance::{AnyInstance, Instance};
+use crate::item::ItemStack;
+use crate::scheduler::submit_stepped_task;
+use crate::text::Component;
+use log::error;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::sync::{Arc, Weak};
+use uuid::Uuid;
+
+/// Vertical space taken by a text line
+const TEXT_LINE_HEIGHT: f64 = 0.3;
+/// Vertical space taken by an item or block line
+const OBJECT_LINE_HEIGHT: f64 = 0.6;
+const OBJECT_SCALE: f32 = 0.5;
+
+type LineResolver = Arc<dyn Fn(&Player) -> Result<Component> + Send + Sync>;
+
+/// A line of a [`Hologram`].
+#[derive(Clone)]
+pub enum HologramLine {
+    Text(Component),
+    /// Text resolved for each viewer, e.g. to show their own name or stats
+    PerPlayer(LineResolver),
+    Item(ItemStack),
+    Block(Block),
+}
+
+impl HologramLine {
+    pub fn text(text: &str) -> Result<Self> {
+        Ok(HologramLine::Text(Component::text(text)?))
+    }
+
+    /// Creates a line whose text is resolved for each viewer.
+    /// Resolved again by [`Hologram::refresh`].
+    pub fn per_player<F>(resolve: F) -> Self
+    where
+        F: Fn(&Player) -> Result<Component> + Send + Sync + 'static,
+    {
+        HologramLine::PerPlayer(Arc::new(resolve))
+    }
+
+    fn height(&self) -> f64 {
+        match self {
+            HologramLine::Text(_) | HologramLine::PerPlayer(_) => TEXT_LINE_HEIGHT,
+            HologramLine::Item(_) | HologramLine::Block(_) => OBJECT_LINE_HEIGHT,
+        }
+    }
+}
+
+type SharedDisplay = Box<dyn DisplayLike + Send + Sync>;
+
+enum LineEntities {
+    Shared(SharedDisplay),
+    PerPlayer(HashMap<Uuid, TextDisplay>),
+}
+
+struct LineState {
+    line: HologramLine,
+    entities: LineEntities,
+}
+
+struct HologramInner {
+    instance: AnyInstance,
+    position: Mutex<Position>,
+    lines: Mutex<Vec<LineState>>,
+    viewers: Mutex<HashMap<Uuid, Player>>,
+    interaction: Mutex<Option<Interaction>>,
+    click_listeners: Mutex<Option<ListenerHandle>>,
+    disconnect_listener: Mutex<Option<ListenerHandle>>,
+}
+
+/// Floating lines of text, items and blocks built on display entities.
+///
+/// The position is the top of the first line, following lines are stacked below it.
+/// Holograms are only shown to the players added with [`Hologram::add_viewer`].
+///
+/// # Example
+/// ```rust,no_run
+/// let hologram = Hologram::new(&instance, Position::new(0.5, 42.0, 0.5))?;
+/// hologram.add_line(HologramLine::text("Welcome to The Crown")?)?;
+/// hologram.add_line(HologramLine::per_player(|player| {
+///     Component::text(&format!("Hello {}", player.get_username()?))
+/// }))?;
+/// hologram.add_line(HologramLine::Item(ItemStack::of(Material::Diamond)?))?;
+///
+/// let holograms = hologram.clone();
+/// instance.event_node()?.listen(move |event: &PlayerSpawnEvent| {
+///     holograms.add_viewer(&event.player()?)
+/// })?;
+/// ```
+#[derive(Clone)]
+pub struct Hologram {
+    inner: Arc<HologramInner>,
+}
+
+impl Hologram {
+    pub fn new(instance: &dyn Instance, position: Position) -> Result<Self> {
+        let hologram = Self {
+            inner: Arc::new(HologramInner {
+                instance: AnyInstance::from_java(instance.java_object().clone())?,
+                position: Mutex::new(position),
+                lines: Mutex::new(Vec::new()),
+                viewers: Mutex::new(HashMap::new()),
+                interaction: Mutex::new(None),
+                click_listeners: Mutex::new(None),
+                disconnect_listener: Mutex::new(None),
+            }),
+        };
+
+        // Forget players leaving, their per-player lines are removed with them
+        let weak = Arc::downgrade(&hologram.inner);
+        let mut listener = instance.event_node()?.removable_listeners("hologram")?;
+        listener.listen(move |event: &PlayerDisconnectEvent| match weak.upgrade() {
+            Some(inner) => Hologram { inner }.remove_viewer(&event.player()?),
+            None => Ok(()),
+        })?;
+        *hologram.inner.disconnect_listener.lock() = Some(listener);
+
+        Ok(hologram)
+    }
+
+    /// Appends a line below the current ones
+    pub fn add_line(&self, line: HologramLine) -> Result<()> {
+        let mut lines = self.inner.lines.lock();
+        let y = self.inner.position.lock().y - total_height(&lines) - line.height();
+        let entities = self.spawn_line(&line, y)?;
+        lines.push(LineState { line, entities });
+        drop(lines);
+        self.update_interaction()
+    }
+
+    /// Replaces the line at `index`
+    pub fn set_line(&self, index: usize, line: HologramLine) -> Result<()> {
+        let mut lines = self.inner.lines.lock();
+        let Some(state) = lines.get(index) else {
+            return Err(out_of_bounds(index));
+        };
+        remove_entities(&state.entities)?;
+        let y = self.inner.position.lock().y - total_height(&lines[..index]) - line.height();
+        let entities = self.spawn_line(&line, y)?;
+        lines[index] = LineState { line, entities };
+        self.layout(&lines)?;
+        drop(lines);
+        self.update_interaction()
+    }
+
+    /// Removes the line at `index`, moving the following ones up
+    pub fn remove_line(&self, index: usize) -> Result<()> {
+        let mut lines = self.inner.lines.lock();
+        if index >= lines.len() {
+            return Err(out_of_bounds(index));
+        }
+        let state = lines.remove(index);
+        remove_entities(&state.entities)?;
+        self.layout(&lines)?;
+        drop(lines);
+        self.update_interaction()
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.inner.lines.lock().len()
+    }
+
+    pub fn get_position(&self) -> Position {
+        self.inner.position.lock().clone()
+    }
+
+    /// Moves the hologram, the position being the top of the first line
+    pub fn set_position(&self, position: Position) -> Result<()> {
+        *self.inner.position.lock() = position;
+        self.layout(&self.inner.lines.lock())?;
+        self.update_interaction()
+    }
+
+    /// Shows the hologram to a player
+    pub fn add_viewer(&self, player: &Player) -> Result<()> {
+        let uuid = player.get_uuid()?;
+        if self
+            .inner
+            .viewers
+            .lock()
+            .insert(uuid, player.clone())
+            .is_some()
+        {
+            return Ok(());
+        }
+
+        let top = self.inner.position.lock().y;
+        let mut lines = self.inner.lines.lock();
+        let mut y = top;
+        for state in lines.iter_mut() {
+            y -= state.line.height();
+            match (&state.line, &mut state.entities) {
+                (HologramLine::PerPlayer(resolve), LineEntities::PerPlayer(displays)) => {
+                    let display = self.spawn_player_text(resolve, player, y)?;
+                    displays.insert(uuid, display);
+                }
+                (_, LineEntities::Shared(display)) => {
+                    display.add_viewer(player)?;
+                }
+                _ => {}
+            }
+        }
+        drop(lines);
+
+        if let Some(interaction) = self.inner.interaction.lock().as_ref() {
+            interaction.add_viewer(player)?;
+        }
+        Ok(())
+    }
+
+    /// Hides the hologram from a player
+    pub fn remove_viewer(&self, player: &Player) -> Result<()> {
+        let uuid = player.get_uuid()?;
+        if self.inner.viewers.lock().remove(&uuid).is_none() {
+            return Ok(());
+        }
+
+        for state in self.inner.lines.lock().iter_mut() {
+            match &mut state.entities {
+                LineEntities::Shared(display) => {
+                    display.remove_viewer(player)?;
+                }
+                LineEntities::PerPlayer(displays) => {
+                    if let Some(display) = displays.remove(&uuid) {
+                        display.remove()?;
+                    }
+                }
+            }
+        }
+        if let Some(interaction) = self.inner.interaction.lock().as_ref() {
+            interaction.remove_viewer(player)?;
+        }
+        Ok(())
+    }
+
+    /// Resolves the per-player lines again for every viewer
+    pub fn refresh(&self) -> Result<()> {
+        let viewers = self.inner.viewers.lock().clone();
+        for state in self.inner.lines.lock().iter() {
+            let (HologramLine::PerPlayer(resolve), LineEntities::PerPlayer(displays)) =
+                (&state.line, &state.entities)
+            else {
+                continue;
+            };
+            for (uuid, display) in displays {
+                if let Some(player) = viewers.get(uuid) {
+                    display.set_text(&resolve(player)?)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Calls [`Hologram::refresh`] every `ticks` ticks, until every handle to the hologram is dropped
+    pub fn refresh_every(&self, ticks: u32) -> Result<()> {
+        let weak: Weak<HologramInner> = Arc::downgrade(&self.inner);
+        submit_stepped_task(move || {
+            let inner = weak.upgrade()?;
+            if let Err(e) = (Hologram { inner }).refresh() {
+                error!("Failed to refresh hologram: {}", e);
+            }
+            Some(ticks)
+        })
+    }
+
+    /// Calls `callback` when a viewer clicks the hologram, through an interaction
+    /// entity covering every line.
+    pub fn on_click<F>(&self, callback: F) -> Result<()>
+    where
+        F: Fn(&Player, InteractionClick) -> Result<()> + Send + Sync + 'static,
+    {
+        let interaction = Interaction::new(1.0, 0.0)?;
+        let y = self.inner.position.lock().y;
+        self.spawn(&interaction, y)?;
+        for player in self.inner.viewers.lock().values() {
+            interaction.add_viewer(player)?;
+        }
+        let listeners = interaction.on_click(&self.inner.instance.event_node()?, callback)?;
+
+        if let Some(previous) = self.inner.interaction.lock().replace(interaction) {
+            previous.remove()?;
+        }
+        if let Some(previous) = self.inner.click_listeners.lock().replace(listeners) {
+            previous.remove()?;
+        }
+        self.update_interaction()
+    }
+
+    /// Removes every entity of the hologram, it can no longer be used afterwards
+    pub fn remove(&self) -> Result<()> {
+        for state in self.inner.lines.lock().drain(..) {
+            remove_entities(&state.entities)?;
+        }
+        if let Some(interaction) = self.inner.interaction.lock().take() {
+            interaction.remove()?;
+        }
+        if let Some(listeners) = self.inner.click_listeners.lock().take() {
+            listeners.remove()?;
+        }
+        if let Some(listener) = self.inner.disconnect_listener.lock().take() {
+            listener.remove()?;
+        }
+        self.inner.viewers.lock().clear();
+        Ok(())
+    }
+
+    fn spawn_line(&self, line: &HologramLine, y: f64) -> Result<LineEntities> {
+        let display: SharedDisplay = match line {
+            HologramLine::Text(text) => {
+                let display = TextDisplay::new(text)?;
+                display.set_billboard(Billboard::Center)?;
+                Box::new(display)
+            }
+            HologramLine::PerPlayer(resolve) => {
+                let mut displays = HashMap::new();
+                for (uuid, player) in self.inner.viewers.lock().iter() {
+                    displays.insert(*uuid, self.spawn_player_text(resolve, player, y)?);
+                }
+                return Ok(LineEntities::PerPlayer(displays));
+            }
+            HologramLine::Item(item) => {
+                let display = ItemDisplay::new(item)?;
+                display.set_billboard(Billboard::Vertical)?;
+                display.set_scale(OBJECT_SCALE, OBJECT_SCALE, OBJECT_SCALE)?;
+                display.set_translation(0.0, (OBJECT_LINE_HEIGHT / 2.0) as f32, 0.0)?;
+                Box::new(display)
+            }
+            HologramLine::Block(block) => {
+                let display = BlockDisplay::new(block)?;
+                display.set_scale(OBJECT_SCALE, OBJECT_SCALE, OBJECT_SCALE)?;
+                // Block displays start at the block corner, center it on the line
+                let offset = -OBJECT_SCALE / 2.0;
+                display.set_translation(offset, 0.05, offset)?;
+                Box::new(display)
+            }
+        };
+        self.spawn(display.as_ref(), y)?;
+        for player in self.inner.viewers.lock().values() {
+            display.add_viewer(player)?;
+        }
+        Ok(LineEntities::Shared(display))
+    }
+
+    fn spawn_player_text(
+        &self,
+        resolve: &LineResolver,
+        player: &Player,
+        y: f64,
+    ) -> Result<TextDisplay> {
+        let display = TextDisplay::new(&resolve(player)?)?;
+        display.set_billboard(Billboard::Center)?;
+        self.spawn(&display, y)?;
+        display.add_viewer(player)?;
+        Ok(display)
+    }
+
+    fn spawn(&self, entity: &dyn EntityLike, y: f64) -> Result<()> {
+        entity.set_auto_viewable(false)?;
+        let position = self.inner.position.lock();
+        let pos = Position::new(position.x, y, position.z).to_pos()?;
+        drop(position);
+        entity.set_instance(&self.inner.instance, &pos)?.wait()
+    }
+
+    /// Moves every line to its place below the previous one
+    fn layout(&self, lines: &[LineState]) -> Result<()> {
+        let position = self.inner.position.lock().clone();
+        let mut y = position.y;
+        for state in lines {
+            y -= state.line.height();
+            let pos = Position::new(position.x, y, position.z).to_pos()?;
+            match &state.entities {
+                LineEntities::Shared(display) => {
+                    display.teleport_to(&pos)?;
+                }
+                LineEntities::PerPlayer(displays) => {
+                    for display in displays.values() {
+                        display.teleport_to(&pos)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Resizes the interaction entity to cover every line
+    fn update_interaction(&self) -> Result<()> {
+        let interaction = self.inner.interaction.lock();
+        let Some(interaction) = interaction.as_ref() else {
+            return Ok(());
+        };
+        let height = total_height(&self.inner.lines.lock());
+        interaction.set_size(1.0, height as f32)?;
+        let position = self.inner.position.lock().clone();
+        interaction
+            .teleport_to(&Position::new(position.x, position.y - height, position.z).to_pos()?)?;
+        Ok(())
+    }
+}
+
+fn total_height(lines: &[LineState]) -> f64 {
+    lines.iter().map(|state| state.line.height()).sum()
+}
+
+fn remove_entities(entities: &LineEntities) -> Result<()> {
+    match entities {
+        LineEntities::Shared(display) => display.remove(),
+        LineEntities::PerPlayer(displays) => {
+            for display in displays.values() {
+                display.remove()?;
+            }
+            Ok(())
+        }
+    }
+}
+
+fn out_of_bounds(index: usize) -> MinestomError {
+    MinestomError::InvalidArgument(format!("hologram has no line {}", index))
+}
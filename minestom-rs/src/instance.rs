@@ -1,48 +1,582 @@
 use crate::MinestomError;
 use crate::Result;
+use crate::biome::BiomeKey;
 use crate::block::Block;
-use crate::block::BlockType;
-use crate::coordinate::Position;
+use crate::coordinate::{Pos, Position};
+use crate::dimension::DimensionKey;
 use crate::entity::Player;
+use crate::entity::entity::{Entity, EntityType};
 use crate::event::EventNode;
-use crate::jni_utils::{JavaObject, JniValue, get_env};
+use crate::generator::{Generator, create_generator};
+use crate::jni_utils::{JavaObject, JniValue, collection_to_vec, get_env};
+use crate::raycast::{self, BlockRaycastHit, EntityRaycastHit};
+use crate::scheduler::{SchedulerManager, submit_stepped_task};
+use crate::sound::Sound;
+use crate::tag::TagHandler;
+use crate::text::Component;
+use crate::weather::Weather;
+use crate::world_border::WorldBorder;
 use jni::objects::JValue;
-use jni::objects::{JObject, JObjectArray};
+use jni::objects::{JObject, JString};
 use log::{debug, error, info};
 use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
 
 /// Common trait for all instance types (InstanceContainer and SharedInstance).
 /// This allows methods to accept either type without needing conversion.
+///
+/// Every method except [`Instance::java_object`] has a default implementation
+/// working on the underlying Java `Instance`, so both types share the same API.
 pub trait Instance {
+    /// Gets the wrapped Java `Instance` object
+    fn java_object(&self) -> &JavaObject;
+
     /// Gets the inner Java object for JNI calls
-    fn inner(&self) -> Result<JObject<'_>>;
+    fn inner(&self) -> Result<JObject<'_>> {
+        self.java_object().as_obj()
+    }
+
+    /// Gets the unique id of this instance
+    fn get_uuid(&self) -> Result<Uuid> {
+        let mut env = get_env()?;
+        let uuid = self
+            .java_object()
+            .call_object_method("getUuid", "()Ljava/util/UUID;", &[])?;
+        let uuid_str = env
+            .call_method(uuid.as_obj()?, "toString", "()Ljava/lang/String;", &[])?
+            .l()?;
+        let uuid_str: String = env.get_string(&JString::from(uuid_str))?.into();
+        Ok(Uuid::parse_str(&uuid_str)?)
+    }
+
+    /// Gets the tags stored on the instance, e.g. the state of the game played in it
+    fn tag_handler(&self) -> Result<TagHandler> {
+        let inner = self.java_object().call_object_method(
+            "tagHandler",
+            "()Lnet/minestom/server/tag/TagHandler;",
+            &[],
+        )?;
+        Ok(TagHandler { inner })
+    }
+
+    /// Gets the name of the dimension type of this instance, e.g. `minecraft:overworld`
+    fn get_dimension_name(&self) -> Result<String> {
+        let mut env = get_env()?;
+        let name = self.java_object().call_object_method(
+            "getDimensionName",
+            "()Ljava/lang/String;",
+            &[],
+        )?;
+        let name = JString::from(env.new_local_ref(name.as_obj()?)?);
+        Ok(env.get_string(&name)?.into())
+    }
 
     /// Gets all players in this instance
-    fn get_players(&self) -> Result<Vec<Player>>;
+    fn get_players(&self) -> Result<Vec<Player>> {
+        let players =
+            self.java_object()
+                .call_object_method("getPlayers", "()Ljava/util/Set;", &[])?;
+        Ok(collection_to_vec(&players)?
+            .into_iter()
+            .map(Player::new)
+            .collect())
+    }
+
+    /// Gets all entities in this instance, players included
+    fn get_entities(&self) -> Result<Vec<Entity>> {
+        let entities =
+            self.java_object()
+                .call_object_method("getEntities", "()Ljava/util/Set;", &[])?;
+        Ok(collection_to_vec(&entities)?
+            .into_iter()
+            .map(Entity::new)
+            .collect())
+    }
+
+    /// Gets the entities within `range` blocks of a point
+    fn get_nearby_entities(&self, point: &Position, range: f64) -> Result<Vec<Entity>> {
+        let entities = self.java_object().call_object_method(
+            "getNearbyEntities",
+            "(Lnet/minestom/server/coordinate/Point;D)Ljava/util/Collection;",
+            &[
+                JniValue::Object(point.to_pos()?.inner()?),
+                JniValue::Double(range),
+            ],
+        )?;
+        Ok(collection_to_vec(&entities)?
+            .into_iter()
+            .map(Entity::new)
+            .collect())
+    }
+
+    /// Gets the entities in a chunk, or nothing if the chunk is not loaded
+    fn get_chunk_entities(&self, chunk_x: i32, chunk_z: i32) -> Result<Vec<Entity>> {
+        let chunk = self.java_object().call_object_method(
+            "getChunk",
+            "(II)Lnet/minestom/server/instance/Chunk;",
+            &[JniValue::Int(chunk_x), JniValue::Int(chunk_z)],
+        )?;
+        if chunk.as_obj()?.is_null() {
+            return Ok(Vec::new());
+        }
+        let entities = self.java_object().call_object_method(
+            "getChunkEntities",
+            "(Lnet/minestom/server/instance/Chunk;)Ljava/util/Set;",
+            &[JniValue::Object(chunk.as_obj()?)],
+        )?;
+        Ok(collection_to_vec(&entities)?
+            .into_iter()
+            .map(Entity::new)
+            .collect())
+    }
+
+    /// Gets the entities of the given type
+    fn get_entities_by_type(&self, entity_type: EntityType) -> Result<Vec<Entity>> {
+        let mut env = get_env()?;
+        let java_type = env
+            .get_static_field(
+                "net/minestom/server/entity/EntityType",
+                entity_type.to_java_field(),
+                "Lnet/minestom/server/entity/EntityType;",
+            )?
+            .l()?;
+
+        let mut entities = Vec::new();
+        for entity in self.get_entities()? {
+            let current = entity.inner.call_object_method(
+                "getEntityType",
+                "()Lnet/minestom/server/entity/EntityType;",
+                &[],
+            )?;
+            if env.is_same_object(&java_type, current.as_obj()?)? {
+                entities.push(entity);
+            }
+        }
+        Ok(entities)
+    }
+
+    /// Finds an entity of this instance by its UUID
+    fn get_entity_by_uuid(&self, uuid: Uuid) -> Result<Option<Entity>> {
+        let entity = self.java_object().call_object_method(
+            "getEntityByUuid",
+            "(Ljava/util/UUID;)Lnet/minestom/server/entity/Entity;",
+            &[JniValue::Object(java_uuid(uuid)?)],
+        )?;
+        if entity.as_obj()?.is_null() {
+            Ok(None)
+        } else {
+            Ok(Some(Entity::new(entity)))
+        }
+    }
+
+    /// Finds an entity of this instance by its protocol id, see [`Entity::get_entity_id`]
+    fn get_entity_by_id(&self, id: i32) -> Result<Option<Entity>> {
+        let entity = self.java_object().call_object_method(
+            "getEntityById",
+            "(I)Lnet/minestom/server/entity/Entity;",
+            &[JniValue::Int(id)],
+        )?;
+        if entity.as_obj()?.is_null() {
+            Ok(None)
+        } else {
+            Ok(Some(Entity::new(entity)))
+        }
+    }
 
     /// Checks if a chunk is loaded
-    fn get_chunk(&self, chunk_x: i32, chunk_z: i32) -> Result<bool>;
+    fn get_chunk(&self, chunk_x: i32, chunk_z: i32) -> Result<bool> {
+        self.java_object().call_bool_method(
+            "isChunkLoaded",
+            "(II)Z",
+            &[JniValue::Int(chunk_x), JniValue::Int(chunk_z)],
+        )
+    }
 
-    /// Loads a chunk
-    fn load_chunk(&self, chunk_x: i32, chunk_z: i32) -> Result<()>;
+    /// Loads a chunk, blocking until it is ready
+    fn load_chunk(&self, chunk_x: i32, chunk_z: i32) -> Result<()> {
+        // Call loadChunk which returns a CompletableFuture
+        let result = self.java_object().call_object_method(
+            "loadChunk",
+            "(II)Ljava/util/concurrent/CompletableFuture;",
+            &[JniValue::Int(chunk_x), JniValue::Int(chunk_z)],
+        )?;
+
+        // Call join() on the CompletableFuture to wait for it to complete
+        let mut env = get_env()?;
+        env.call_method(result.as_obj()?, "join", "()Ljava/lang/Object;", &[])?;
+
+        Ok(())
+    }
 
     /// Unloads a chunk
-    fn unload_chunk(&self, chunk_x: i32, chunk_z: i32) -> Result<()>;
+    fn unload_chunk(&self, chunk_x: i32, chunk_z: i32) -> Result<()> {
+        self.java_object().call_void_method(
+            "unloadChunk",
+            "(II)V",
+            &[JniValue::Int(chunk_x), JniValue::Int(chunk_z)],
+        )
+    }
 
     /// Gets the spawn position
-    fn get_spawn_position(&self) -> Result<Position>;
+    fn get_spawn_position(&self) -> Result<Position> {
+        let result = self.java_object().call_object_method(
+            "getSpawnLocation",
+            "()Lnet/minestom/server/coordinate/Pos;",
+            &[],
+        )?;
+        Pos::new(result).to_position()
+    }
 
     /// Sets the spawn position
-    fn set_spawn_position(&self, position: &Position) -> Result<()>;
+    fn set_spawn_position(&self, position: &Position) -> Result<()> {
+        let pos = position.to_pos()?;
+        self.java_object().call_void_method(
+            "setSpawnLocation",
+            "(Lnet/minestom/server/coordinate/Pos;)V",
+            &[JniValue::Object(pos.inner()?)],
+        )
+    }
 
     /// Sets a block at the specified coordinates
-    fn set_block(&self, x: i32, y: i32, z: i32, block: Block) -> Result<()>;
+    fn set_block(&self, x: i32, y: i32, z: i32, block: Block) -> Result<()> {
+        self.java_object().call_void_method(
+            "setBlock",
+            "(IIILnet/minestom/server/instance/block/Block;)V",
+            &[
+                JniValue::Int(x),
+                JniValue::Int(y),
+                JniValue::Int(z),
+                JniValue::Object(block.inner.as_obj()?),
+            ],
+        )
+    }
+
+    /// Gets the block at the specified coordinates.
+    /// The chunk must be loaded.
+    fn get_block(&self, x: i32, y: i32, z: i32) -> Result<Block> {
+        let inner = self.java_object().call_object_method(
+            "getBlock",
+            "(III)Lnet/minestom/server/instance/block/Block;",
+            &[JniValue::Int(x), JniValue::Int(y), JniValue::Int(z)],
+        )?;
+        Ok(Block { inner })
+    }
+
+    /// Gets the biome at the specified coordinates.
+    /// The chunk must be loaded.
+    fn get_biome(&self, x: i32, y: i32, z: i32) -> Result<BiomeKey> {
+        let inner = self.java_object().call_object_method(
+            "getBiome",
+            "(III)Lnet/minestom/server/registry/DynamicRegistry$Key;",
+            &[JniValue::Int(x), JniValue::Int(y), JniValue::Int(z)],
+        )?;
+        Ok(BiomeKey { inner })
+    }
+
+    /// Sets the biome at the specified coordinates.
+    /// Biomes are stored per 4x4x4 cell, players must reload the chunk to see the change.
+    fn set_biome(&self, x: i32, y: i32, z: i32, biome: &BiomeKey) -> Result<()> {
+        self.java_object().call_void_method(
+            "setBiome",
+            "(IIILnet/minestom/server/registry/DynamicRegistry$Key;)V",
+            &[
+                JniValue::Int(x),
+                JniValue::Int(y),
+                JniValue::Int(z),
+                JniValue::Object(biome.inner.as_obj()?),
+            ],
+        )
+    }
+
+    /// Sets the generator used for chunks loaded from now on
+    fn set_generator(&self, generator: Arc<dyn Generator>) -> Result<()> {
+        let generator = create_generator(generator)?;
+        self.java_object().call_void_method(
+            "setGenerator",
+            "(Lnet/minestom/server/instance/generator/Generator;)V",
+            &[JniValue::Object(generator.as_obj()?)],
+        )
+    }
+
+    /// Casts a ray and returns the first non-air block accepted by `filter` it crosses
+    /// within `max_distance`, e.g. `&|block| block.is_solid()` to go through water and plants.
+    /// Blocks are treated as full cubes, and the ray stops at unloaded chunks.
+    fn raycast_blocks(
+        &self,
+        origin: &Position,
+        direction: &Position,
+        max_distance: f64,
+        filter: &dyn Fn(&Block) -> Result<bool>,
+    ) -> Result<Option<BlockRaycastHit>> {
+        raycast::raycast_blocks(self, origin, direction, max_distance, filter)
+    }
 
-    /// Sets the time rate of this instance
-    fn set_time_rate(&self, rate: i32) -> Result<()>;
+    /// Casts a ray and returns the closest entity whose bounding box it crosses
+    /// within `max_distance`. Entities rejected by `filter` are ignored.
+    fn raycast_entities(
+        &self,
+        origin: &Position,
+        direction: &Position,
+        max_distance: f64,
+        filter: &dyn Fn(&Entity) -> Result<bool>,
+    ) -> Result<Option<EntityRaycastHit>> {
+        raycast::raycast_entities(self, origin, direction, max_distance, filter)
+    }
 
-    /// Gets the event node for this instance
-    fn event_node(&self) -> Result<EventNode>;
+    /// Gets the current time of day of this instance, in ticks
+    fn get_time(&self) -> Result<i64> {
+        let mut env = get_env()?;
+        Ok(env.call_method(self.inner()?, "getTime", "()J", &[])?.j()?)
+    }
+
+    /// Sets the time of day of this instance, in ticks
+    fn set_time(&self, time: i64) -> Result<()> {
+        self.java_object()
+            .call_void_method("setTime", "(J)V", &[JniValue::Long(time)])
+    }
+
+    /// Gets the time rate of this instance
+    fn get_time_rate(&self) -> Result<i32> {
+        self.java_object()
+            .call_int_method("getTimeRate", "()I", &[])
+    }
+
+    /// Sets the time rate of this instance.
+    /// The time rate represents how fast time passes in the instance (default value is 1).
+    fn set_time_rate(&self, rate: i32) -> Result<()> {
+        self.java_object()
+            .call_void_method("setTimeRate", "(I)V", &[JniValue::Int(rate)])
+    }
+
+    /// Sets how often, in ticks, the time of day is sent to players.
+    /// Use 0 to only send it when it is changed with [`Instance::set_time`].
+    fn set_time_synchronization_ticks(&self, ticks: i32) -> Result<()> {
+        self.java_object().call_void_method(
+            "setTimeSynchronizationTicks",
+            "(I)V",
+            &[JniValue::Int(ticks)],
+        )
+    }
+
+    /// Gets the current weather of this instance
+    fn weather(&self) -> Result<Weather> {
+        let weather = self.java_object().call_object_method(
+            "getWeather",
+            "()Lnet/minestom/server/instance/Weather;",
+            &[],
+        )?;
+        Weather::from_java(&weather)
+    }
+
+    /// Changes the weather of this instance immediately
+    fn set_weather(&self, weather: &Weather) -> Result<()> {
+        let weather = weather.to_java()?;
+        self.java_object().call_void_method(
+            "setWeather",
+            "(Lnet/minestom/server/instance/Weather;)V",
+            &[JniValue::Object(weather.as_obj()?)],
+        )
+    }
+
+    /// Gradually changes the rain and thunder levels to `weather` over `ticks` ticks
+    fn transition_weather(&self, weather: &Weather, ticks: i32) -> Result<()> {
+        let weather = weather.to_java()?;
+        self.java_object().call_void_method(
+            "setWeather",
+            "(Lnet/minestom/server/instance/Weather;I)V",
+            &[JniValue::Object(weather.as_obj()?), JniValue::Int(ticks)],
+        )
+    }
+
+    /// Gets the current world border of this instance
+    fn world_border(&self) -> Result<WorldBorder> {
+        let border = self.java_object().call_object_method(
+            "getWorldBorder",
+            "()Lnet/minestom/server/instance/WorldBorder;",
+            &[],
+        )?;
+        WorldBorder::from_java(&border)
+    }
+
+    /// Replaces the world border of this instance immediately
+    fn set_world_border(&self, border: &WorldBorder) -> Result<()> {
+        self.transition_world_border(border, Duration::ZERO)
+    }
+
+    /// Moves the world border of this instance to `border` over `duration`.
+    /// Players see the border shrink or grow smoothly.
+    fn transition_world_border(&self, border: &WorldBorder, duration: Duration) -> Result<()> {
+        let border = border.to_java()?;
+        self.java_object().call_void_method(
+            "setWorldBorder",
+            "(Lnet/minestom/server/instance/WorldBorder;D)V",
+            &[
+                JniValue::Object(border.as_obj()?),
+                JniValue::Double(duration.as_secs_f64()),
+            ],
+        )
+    }
+
+    /// Gets the event node for this instance.
+    /// This can be used to register event listeners specific to this instance.
+    fn event_node(&self) -> Result<EventNode> {
+        let result = self.java_object().call_object_method(
+            "eventNode",
+            "()Lnet/minestom/server/event/EventNode;",
+            &[],
+        )?;
+        Ok(EventNode::from(result))
+    }
+
+    /// Gets the scheduler of this instance.
+    /// Tasks scheduled here are ticked with the instance and stop when it is unregistered.
+    fn scheduler(&self) -> Result<SchedulerManager> {
+        let result = self.java_object().call_object_method(
+            "scheduler",
+            "()Lnet/minestom/server/timer/Scheduler;",
+            &[],
+        )?;
+        Ok(SchedulerManager::new(result))
+    }
+
+    /// Sends a chat message to every player in this instance
+    fn send_message(&self, message: &Component) -> Result<()> {
+        let mut env = get_env()?;
+        self.java_object().call_void_method(
+            "sendMessage",
+            "(Lnet/kyori/adventure/text/Component;)V",
+            &[message.as_jvalue(&mut env)?],
+        )
+    }
+
+    /// Sends an action bar message to every player in this instance
+    fn send_action_bar(&self, message: &Component) -> Result<()> {
+        let mut env = get_env()?;
+        self.java_object().call_void_method(
+            "sendActionBar",
+            "(Lnet/kyori/adventure/text/Component;)V",
+            &[message.as_jvalue(&mut env)?],
+        )
+    }
+
+    /// Plays a sound to every player in this instance
+    fn play_sound(&self, sound: &Sound) -> Result<()> {
+        let mut env = get_env()?;
+        self.java_object().call_void_method(
+            "playSound",
+            "(Lnet/kyori/adventure/sound/Sound;)V",
+            &[sound.as_jvalue(&mut env)?],
+        )
+    }
+
+    /// Plays a sound at the given coordinates to every player in this instance
+    fn play_sound_at(&self, sound: &Sound, x: f64, y: f64, z: f64) -> Result<()> {
+        let mut env = get_env()?;
+        self.java_object().call_void_method(
+            "playSound",
+            "(Lnet/kyori/adventure/sound/Sound;DDD)V",
+            &[
+                sound.as_jvalue(&mut env)?,
+                JniValue::Double(x),
+                JniValue::Double(y),
+                JniValue::Double(z),
+            ],
+        )
+    }
+
+    /// Returns true if this instance is registered in the instance manager
+    fn is_registered(&self) -> Result<bool> {
+        self.java_object()
+            .call_bool_method("isRegistered", "()Z", &[])
+    }
+
+    /// Unregisters this instance from the instance manager.
+    /// The instance must not contain any player.
+    fn unregister(&self) -> Result<()> {
+        let mut env = get_env()?;
+        let instance_manager = env
+            .call_static_method(
+                "net/minestom/server/MinecraftServer",
+                "getInstanceManager",
+                "()Lnet/minestom/server/instance/InstanceManager;",
+                &[],
+            )?
+            .l()?;
+        env.call_method(
+            &instance_manager,
+            "unregisterInstance",
+            "(Lnet/minestom/server/instance/Instance;)V",
+            &[JValue::Object(&self.inner()?)],
+        )?;
+        Ok(())
+    }
+
+    /// Sets this instance as the default spawning instance for all players.
+    /// This should be called before starting the server.
+    fn set_as_default_spawn_instance(&self) -> Result<()> {
+        let mut env = get_env()?;
+
+        debug!("Getting ConnectionManager from MinecraftServer");
+        let connection_manager = env
+            .call_static_method(
+                "net/minestom/server/MinecraftServer",
+                "getConnectionManager",
+                "()Lnet/minestom/server/network/ConnectionManager;",
+                &[],
+            )?
+            .l()?;
+        if connection_manager.is_null() {
+            error!("ConnectionManager is null!");
+            return Err(MinestomError::EventError(
+                "ConnectionManager is null".to_string(),
+            ));
+        }
+
+        let instance_obj = self.inner()?;
+
+        // The method name changed across Minestom versions
+        for method in ["setSpawningInstance", "setDefaultSpawningInstance"] {
+            debug!("Attempting to call {} on ConnectionManager", method);
+            match env.call_method(
+                &connection_manager,
+                method,
+                "(Lnet/minestom/server/instance/Instance;)V",
+                &[JValue::Object(&instance_obj)],
+            ) {
+                Ok(_) => {
+                    debug!("Successfully set default instance");
+                    return Ok(());
+                }
+                Err(e) => {
+                    debug!("Method {} not found: {}. Trying next method...", method, e);
+                    if env.exception_check()? {
+                        env.exception_clear()?;
+                    }
+                }
+            }
+        }
+
+        error!("Could not find a method to set the default instance");
+        Err(MinestomError::EventError(
+            "Could not find a method to set the default instance".to_string(),
+        ))
+    }
+}
+
+/// Converts a UUID into a Java `UUID`
+fn java_uuid<'local>(uuid: Uuid) -> Result<JObject<'local>> {
+    let mut env = get_env()?;
+    let uuid_jstring = env.new_string(uuid.to_string())?;
+    Ok(env
+        .call_static_method(
+            "java/util/UUID",
+            "fromString",
+            "(Ljava/lang/String;)Ljava/util/UUID;",
+            &[JValue::Object(&uuid_jstring)],
+        )?
+        .l()?)
 }
 
 #[derive(Clone)]
@@ -100,21 +634,106 @@ impl InstanceManager {
         Ok(InstanceContainer::new(result))
     }
 
-    pub fn get_instance(&self, unique_id: i32) -> Result<Option<InstanceContainer>> {
+    /// Creates an instance container using a registered dimension type.
+    pub fn create_instance_container_with_dimension(
+        &self,
+        dimension: &DimensionKey,
+    ) -> Result<InstanceContainer> {
+        let result = self.inner.call_object_method(
+            "createInstanceContainer",
+            "(Lnet/minestom/server/registry/DynamicRegistry$Key;)Lnet/minestom/server/instance/InstanceContainer;",
+            &[JniValue::Object(dimension.inner.as_obj()?)],
+        )?;
+
+        Ok(InstanceContainer::new(result))
+    }
+
+    /// Looks up a registered instance by its unique id.
+    pub fn get_instance(&self, uuid: Uuid) -> Result<Option<AnyInstance>> {
         let result = self.inner.call_object_method(
             "getInstance",
-            "(I)Lnet/minestom/server/instance/Instance;",
-            &[JniValue::Int(unique_id)],
+            "(Ljava/util/UUID;)Lnet/minestom/server/instance/Instance;",
+            &[JniValue::Object(java_uuid(uuid)?)],
         )?;
 
-        let obj = result.as_obj()?;
-        if obj.is_null() {
+        if result.as_obj()?.is_null() {
             Ok(None)
         } else {
-            Ok(Some(InstanceContainer::new(result)))
+            Ok(Some(AnyInstance::from_java(result)?))
         }
     }
 
+    /// Gets all registered instances
+    pub fn get_instances(&self) -> Result<Vec<AnyInstance>> {
+        let instances = self
+            .inner
+            .call_object_method("getInstances", "()Ljava/util/Set;", &[])?;
+        collection_to_vec(&instances)?
+            .into_iter()
+            .map(AnyInstance::from_java)
+            .collect()
+    }
+
+    /// Registers an instance created outside of the manager, e.g. with [`InstanceContainer::copy`].
+    pub fn register_instance(&self, instance: &dyn Instance) -> Result<()> {
+        self.inner.call_void_method(
+            "registerInstance",
+            "(Lnet/minestom/server/instance/Instance;)V",
+            &[JniValue::Object(instance.inner()?)],
+        )
+    }
+
+    /// Unregisters an instance, freeing its chunks and entities.
+    /// The instance must not contain any player.
+    pub fn unregister_instance(&self, instance: &dyn Instance) -> Result<()> {
+        self.inner.call_void_method(
+            "unregisterInstance",
+            "(Lnet/minestom/server/instance/Instance;)V",
+            &[JniValue::Object(instance.inner()?)],
+        )
+    }
+
+    /// Unregisters `instance` once it has had no players for `empty_ticks` consecutive ticks.
+    ///
+    /// The count starts right away, so `empty_ticks` must leave enough time for the
+    /// first player to join. This is meant for per-player or per-match instances,
+    /// which would otherwise stay loaded after everybody left.
+    pub fn unregister_when_empty(&self, instance: &dyn Instance, empty_ticks: u64) -> Result<()> {
+        // The check runs on the global scheduler, so the instance is never
+        // unregistered while it is being ticked
+        let instance_manager = self.clone();
+        let instance = AnyInstance::from_java(instance.java_object().clone())?;
+        let mut empty_for = 0;
+
+        submit_stepped_task(move || {
+            let mut check = || -> Result<bool> {
+                if !instance.get_players()?.is_empty() {
+                    empty_for = 0;
+                    return Ok(false);
+                }
+                empty_for += 1;
+                if empty_for < empty_ticks {
+                    return Ok(false);
+                }
+
+                debug!("Unregistering instance empty for {} ticks", empty_ticks);
+                if instance.is_registered()? {
+                    instance_manager.unregister_instance(&instance)?;
+                }
+                Ok(true)
+            };
+            match check() {
+                Ok(false) => Some(1),
+                // Stopping drops the instance so it can be garbage collected
+                Ok(true) => None,
+                Err(e) => {
+                    error!("Failed to check empty instance: {}", e);
+                    None
+                }
+            }
+        })
+    }
+
     /// Creates a SharedInstance from an InstanceContainer.
     /// The SharedInstance will reference the same underlying world data but have separate entities.
     pub fn create_shared_instance(
@@ -142,10 +761,6 @@ impl InstanceContainer {
         Self { inner }
     }
 
-    pub fn inner(&self) -> Result<JObject<'_>> {
-        self.inner.as_obj()
-    }
-
     /// Loads an Anvil world into this instance using the Common class implementation.
     ///
     /// # Arguments
@@ -173,268 +788,23 @@ impl InstanceContainer {
         Ok(())
     }
 
-    pub fn get_players(&self) -> Result<Vec<Player>> {
-        let result =
-            self.inner
-                .call_object_method("getPlayers", "()Ljava/util/Collection;", &[])?;
-
-        let mut env = get_env()?;
-        let result_obj = result.as_obj()?;
-        let array = env.call_method(result_obj, "toArray", "()[Ljava/lang/Object;", &[])?;
-
-        let array = array.l()?;
-        let array = JObjectArray::from(array);
-        let length = env.get_array_length(&array)?;
-        let mut players = Vec::with_capacity(length as usize);
-
-        for i in 0..length {
-            let player = env.get_object_array_element(&array, i)?;
-            players.push(Player::new(JavaObject::from_env(&mut env, player)?));
-        }
-
-        Ok(players)
-    }
-
-    pub fn get_chunk(&self, chunk_x: i32, chunk_z: i32) -> Result<bool> {
-        let result = self.inner.call_bool_method(
-            "loadChunk",
-            "(II)Z",
-            &[JniValue::Int(chunk_x), JniValue::Int(chunk_z)],
-        )?;
-        Ok(result)
-    }
-
-    pub fn load_chunk(&self, chunk_x: i32, chunk_z: i32) -> Result<()> {
-        // Call loadChunk which returns a CompletableFuture
-        let result = self.inner.call_object_method(
-            "loadChunk",
-            "(II)Ljava/util/concurrent/CompletableFuture;",
-            &[JniValue::Int(chunk_x), JniValue::Int(chunk_z)],
-        )?;
-
-        // Call join() on the CompletableFuture to wait for it to complete
-        let mut env = get_env()?;
-        let future_obj = result.as_obj()?;
-        env.call_method(future_obj, "join", "()Ljava/lang/Object;", &[])?;
-
-        Ok(())
-    }
-
-    pub fn unload_chunk(&self, chunk_x: i32, chunk_z: i32) -> Result<()> {
-        self.inner.call_void_method(
-            "unloadChunk",
-            "(II)V",
-            &[JniValue::Int(chunk_x), JniValue::Int(chunk_z)],
-        )
-    }
-
-    pub fn get_spawn_position(&self) -> Result<Position> {
-        let result = self.inner.call_object_method(
-            "getSpawnLocation",
-            "()Lnet/minestom/server/coordinate/Pos;",
-            &[],
-        )?;
-
-        let mut env = get_env()?;
-        let pos = result.as_obj()?;
-
-        let x = env.call_method(&pos, "x", "()D", &[])?.d()?;
-        let y = env.call_method(&pos, "y", "()D", &[])?.d()?;
-        let z = env.call_method(&pos, "z", "()D", &[])?.d()?;
-
-        Ok(Position::new(x, y, z))
-    }
-
-    pub fn set_spawn_position(&self, position: &Position) -> Result<()> {
-        let mut env = get_env()?;
-        let pos_class = env.find_class("net/minestom/server/coordinate/Pos")?;
-        let pos = env.new_object(
-            pos_class,
-            "(DDD)V",
-            &[
-                JniValue::Double(position.x).as_jvalue(),
-                JniValue::Double(position.y).as_jvalue(),
-                JniValue::Double(position.z).as_jvalue(),
-            ],
-        )?;
-
-        self.inner.call_void_method(
-            "setSpawnLocation",
-            "(Lnet/minestom/server/coordinate/Pos;)V",
-            &[JniValue::Object(pos)],
-        )
-    }
-
-    /// Sets this instance as the default spawning instance for all players.
-    /// This should be called before starting the server.
-    pub fn set_as_default_spawn_instance(&self) -> Result<()> {
-        let mut env = get_env()?;
-
-        // First, let's try to list the available methods on ConnectionManager to debug the issue
-        debug!("Getting ConnectionManager class");
-        let _connection_manager_class =
-            env.find_class("net/minestom/server/network/ConnectionManager")?;
-
-        debug!("Finding MinecraftServer class");
-        let minecraft_server = env.find_class("net/minestom/server/MinecraftServer")?;
-
-        // Get the ConnectionManager
-        debug!("Getting ConnectionManager from MinecraftServer");
-        let connection_manager = env.call_static_method(
-            minecraft_server,
-            "getConnectionManager",
-            "()Lnet/minestom/server/network/ConnectionManager;",
-            &[],
-        )?;
-
-        debug!("Got connection manager");
-        let connection_manager_obj = connection_manager.l()?;
-        if connection_manager_obj.is_null() {
-            error!("ConnectionManager is null!");
-            return Err(MinestomError::EventError(
-                "ConnectionManager is null".to_string(),
-            ));
-        }
-
-        // Get the instance object
-        debug!("Getting instance object");
-        let instance_obj = self.inner()?;
-
-        // Attempt different method names that might exist in the ConnectionManager
-        // Try "setSpawningInstance" instead of "setDefaultInstance"
-        debug!("Attempting to call setSpawningInstance on ConnectionManager");
-        match env.call_method(
-            &connection_manager_obj,
-            "setSpawningInstance",
-            "(Lnet/minestom/server/instance/Instance;)V",
-            &[jni::objects::JValue::Object(&instance_obj)],
-        ) {
-            Ok(_) => {
-                debug!("Successfully called setSpawningInstance");
-                debug!("Successfully set default instance");
-                return Ok(());
-            }
-            Err(e) => {
-                debug!(
-                    "Method setSpawningInstance not found: {}. Trying next method...",
-                    e
-                );
-            }
-        }
-
-        // Try "setDefaultSpawningInstance"
-        debug!("Attempting to call setDefaultSpawningInstance on ConnectionManager");
-        match env.call_method(
-            &connection_manager_obj,
-            "setDefaultSpawningInstance",
-            "(Lnet/minestom/server/instance/Instance;)V",
-            &[jni::objects::JValue::Object(&instance_obj)],
-        ) {
-            Ok(_) => {
-                debug!("Successfully called setDefaultSpawningInstance");
-                debug!("Successfully set default instance");
-                return Ok(());
-            }
-            Err(e) => {
-                debug!(
-                    "Method setDefaultSpawningInstance not found: {}. Trying next method...",
-                    e
-                );
-            }
-        }
-
-        // Fall back to a direct approach - call a static method on the server class
-        // or try other methods that might work
-        error!("Could not find a method to set the default instance");
-        Err(MinestomError::EventError(
-            "Could not find a method to set the default instance".to_string(),
-        ))
-    }
-
-    /// Sets a block at the specified coordinates.
+    /// Creates a copy of this instance with all its loaded chunks.
     ///
-    /// # Arguments
-    /// * `x` - The x coordinate
-    /// * `y` - The y coordinate
-    /// * `z` - The z coordinate
-    /// * `block` - The block to set
-    pub fn set_block(&self, x: i32, y: i32, z: i32, block: Block) -> Result<()> {
-        let block_obj = block.inner.clone();
-
-        self.inner.call_void_method(
-            "setBlock",
-            "(IIILnet/minestom/server/instance/block/Block;)V",
-            &[
-                JniValue::Int(x),
-                JniValue::Int(y),
-                JniValue::Int(z),
-                JniValue::Object(block_obj.as_obj()?),
-            ],
-        )
-    }
-
-    /// Sets the time rate of this instance.
-    /// The time rate represents how fast time passes in the instance.
-    ///
-    /// # Arguments
-    /// * `rate` - The time rate (default value is 1)
-    pub fn set_time_rate(&self, rate: i32) -> Result<()> {
-        self.inner
-            .call_void_method("setTimeRate", "(I)V", &[JniValue::Int(rate)])
-    }
-
-    /// Gets the event node for this instance.
-    /// This can be used to register event listeners specific to this instance.
-    pub fn event_node(&self) -> Result<EventNode> {
-        let mut env = get_env()?;
+    /// The copy is not registered, use [`InstanceManager::register_instance`]
+    /// before sending players to it.
+    pub fn copy(&self) -> Result<InstanceContainer> {
         let result = self.inner.call_object_method(
-            "eventNode",
-            "()Lnet/minestom/server/event/EventNode;",
+            "copy",
+            "()Lnet/minestom/server/instance/InstanceContainer;",
             &[],
         )?;
-        Ok(EventNode::from(result))
+        Ok(InstanceContainer::new(result))
     }
 }
 
 impl Instance for InstanceContainer {
-    fn inner(&self) -> Result<JObject<'_>> {
-        self.inner.as_obj()
-    }
-
-    fn get_players(&self) -> Result<Vec<Player>> {
-        self.get_players()
-    }
-
-    fn get_chunk(&self, chunk_x: i32, chunk_z: i32) -> Result<bool> {
-        self.get_chunk(chunk_x, chunk_z)
-    }
-
-    fn load_chunk(&self, chunk_x: i32, chunk_z: i32) -> Result<()> {
-        self.load_chunk(chunk_x, chunk_z)
-    }
-
-    fn unload_chunk(&self, chunk_x: i32, chunk_z: i32) -> Result<()> {
-        self.unload_chunk(chunk_x, chunk_z)
-    }
-
-    fn get_spawn_position(&self) -> Result<Position> {
-        self.get_spawn_position()
-    }
-
-    fn set_spawn_position(&self, position: &Position) -> Result<()> {
-        self.set_spawn_position(position)
-    }
-
-    fn set_block(&self, x: i32, y: i32, z: i32, block: Block) -> Result<()> {
-        self.set_block(x, y, z, block)
-    }
-
-    fn set_time_rate(&self, rate: i32) -> Result<()> {
-        self.set_time_rate(rate)
-    }
-
-    fn event_node(&self) -> Result<EventNode> {
-        self.event_node()
+    fn java_object(&self) -> &JavaObject {
+        &self.inner
     }
 }
 
@@ -443,185 +813,63 @@ impl SharedInstance {
         Self { inner }
     }
 
-    pub fn inner(&self) -> Result<JObject<'_>> {
-        self.inner.as_obj()
-    }
-
-    pub fn get_players(&self) -> Result<Vec<Player>> {
-        let result =
-            self.inner
-                .call_object_method("getPlayers", "()Ljava/util/Collection;", &[])?;
-
-        let mut env = get_env()?;
-        let result_obj = result.as_obj()?;
-        let array = env.call_method(result_obj, "toArray", "()[Ljava/lang/Object;", &[])?;
-
-        let array = array.l()?;
-        let array = JObjectArray::from(array);
-        let length = env.get_array_length(&array)?;
-        let mut players = Vec::with_capacity(length as usize);
-
-        for i in 0..length {
-            let player = env.get_object_array_element(&array, i)?;
-            players.push(Player::new(JavaObject::from_env(&mut env, player)?));
-        }
-
-        Ok(players)
-    }
-
-    pub fn get_chunk(&self, chunk_x: i32, chunk_z: i32) -> Result<bool> {
-        let result = self.inner.call_bool_method(
-            "loadChunk",
-            "(II)Z",
-            &[JniValue::Int(chunk_x), JniValue::Int(chunk_z)],
-        )?;
-        Ok(result)
-    }
-
-    pub fn load_chunk(&self, chunk_x: i32, chunk_z: i32) -> Result<()> {
-        // Call loadChunk which returns a CompletableFuture
-        let result = self.inner.call_object_method(
-            "loadChunk",
-            "(II)Ljava/util/concurrent/CompletableFuture;",
-            &[JniValue::Int(chunk_x), JniValue::Int(chunk_z)],
-        )?;
-
-        // Call join() on the CompletableFuture to wait for it to complete
-        let mut env = get_env()?;
-        let future_obj = result.as_obj()?;
-        env.call_method(future_obj, "join", "()Ljava/lang/Object;", &[])?;
-
-        Ok(())
-    }
-
-    pub fn unload_chunk(&self, chunk_x: i32, chunk_z: i32) -> Result<()> {
-        self.inner.call_void_method(
-            "unloadChunk",
-            "(II)V",
-            &[JniValue::Int(chunk_x), JniValue::Int(chunk_z)],
-        )
-    }
-
-    pub fn get_spawn_position(&self) -> Result<Position> {
+    /// Gets the instance container this shared instance takes its chunks from.
+    pub fn get_instance_container(&self) -> Result<InstanceContainer> {
         let result = self.inner.call_object_method(
-            "getSpawnLocation",
-            "()Lnet/minestom/server/coordinate/Pos;",
-            &[],
-        )?;
-
-        let mut env = get_env()?;
-        let pos = result.as_obj()?;
-
-        let x = env.call_method(&pos, "x", "()D", &[])?.d()?;
-        let y = env.call_method(&pos, "y", "()D", &[])?.d()?;
-        let z = env.call_method(&pos, "z", "()D", &[])?.d()?;
-
-        Ok(Position::new(x, y, z))
-    }
-
-    pub fn set_spawn_position(&self, position: &Position) -> Result<()> {
-        let mut env = get_env()?;
-        let pos_class = env.find_class("net/minestom/server/coordinate/Pos")?;
-        let pos = env.new_object(
-            pos_class,
-            "(DDD)V",
-            &[
-                JniValue::Double(position.x).as_jvalue(),
-                JniValue::Double(position.y).as_jvalue(),
-                JniValue::Double(position.z).as_jvalue(),
-            ],
-        )?;
-
-        self.inner.call_void_method(
-            "setSpawnLocation",
-            "(Lnet/minestom/server/coordinate/Pos;)V",
-            &[JniValue::Object(pos)],
-        )
-    }
-
-    /// Sets a block at the specified coordinates.
-    ///
-    /// # Arguments
-    /// * `x` - The x coordinate
-    /// * `y` - The y coordinate
-    /// * `z` - The z coordinate
-    /// * `block` - The block to set
-    pub fn set_block(&self, x: i32, y: i32, z: i32, block: Block) -> Result<()> {
-        let block_obj = block.inner.clone();
-
-        self.inner.call_void_method(
-            "setBlock",
-            "(IIILnet/minestom/server/instance/block/Block;)V",
-            &[
-                JniValue::Int(x),
-                JniValue::Int(y),
-                JniValue::Int(z),
-                JniValue::Object(block_obj.as_obj()?),
-            ],
-        )
-    }
-
-    /// Sets the time rate of this instance.
-    /// The time rate represents how fast time passes in the instance.
-    ///
-    /// # Arguments
-    /// * `rate` - The time rate (default value is 1)
-    pub fn set_time_rate(&self, rate: i32) -> Result<()> {
-        self.inner
-            .call_void_method("setTimeRate", "(I)V", &[JniValue::Int(rate)])
-    }
-
-    /// Gets the event node for this instance.
-    /// This can be used to register event listeners specific to this instance.
-    pub fn event_node(&self) -> Result<EventNode> {
-        let mut env = get_env()?;
-        let result = self.inner.call_object_method(
-            "eventNode",
-            "()Lnet/minestom/server/event/EventNode;",
+            "getInstanceContainer",
+            "()Lnet/minestom/server/instance/InstanceContainer;",
             &[],
         )?;
-        Ok(EventNode::from(result))
+        Ok(InstanceContainer::new(result))
     }
 }
 
 impl Instance for SharedInstance {
-    fn inner(&self) -> Result<JObject<'_>> {
-        self.inner.as_obj()
-    }
-
-    fn get_players(&self) -> Result<Vec<Player>> {
-        self.get_players()
-    }
-
-    fn get_chunk(&self, chunk_x: i32, chunk_z: i32) -> Result<bool> {
-        self.get_chunk(chunk_x, chunk_z)
-    }
-
-    fn load_chunk(&self, chunk_x: i32, chunk_z: i32) -> Result<()> {
-        self.load_chunk(chunk_x, chunk_z)
-    }
-
-    fn unload_chunk(&self, chunk_x: i32, chunk_z: i32) -> Result<()> {
-        self.unload_chunk(chunk_x, chunk_z)
+    fn java_object(&self) -> &JavaObject {
+        &self.inner
     }
+}
 
-    fn get_spawn_position(&self) -> Result<Position> {
-        self.get_spawn_position()
-    }
+/// An instance of any kind, as returned by lookups on the [`InstanceManager`].
+#[derive(Clone)]
+pub enum AnyInstance {
+    Container(InstanceContainer),
+    Shared(SharedInstance),
+}
 
-    fn set_spawn_position(&self, position: &Position) -> Result<()> {
-        self.set_spawn_position(position)
+impl AnyInstance {
+    pub(crate) fn from_java(inner: JavaObject) -> Result<Self> {
+        let mut env = get_env()?;
+        if env.is_instance_of(
+            inner.as_obj()?,
+            "net/minestom/server/instance/SharedInstance",
+        )? {
+            Ok(AnyInstance::Shared(SharedInstance::new(inner)))
+        } else {
+            Ok(AnyInstance::Container(InstanceContainer::new(inner)))
+        }
     }
 
-    fn set_block(&self, x: i32, y: i32, z: i32, block: Block) -> Result<()> {
-        self.set_block(x, y, z, block)
+    pub fn as_container(&self) -> Option<&InstanceContainer> {
+        match self {
+            AnyInstance::Container(container) => Some(container),
+            AnyInstance::Shared(_) => None,
+        }
     }
 
-    fn set_time_rate(&self, rate: i32) -> Result<()> {
-        self.set_time_rate(rate)
+    pub fn as_shared(&self) -> Option<&SharedInstance> {
+        match self {
+            AnyInstance::Container(_) => None,
+            AnyInstance::Shared(shared) => Some(shared),
+        }
     }
+}
 
-    fn event_node(&self) -> Result<EventNode> {
-        self.event_node()
+impl Instance for AnyInstance {
+    fn java_object(&self) -> &JavaObject {
+        match self {
+            AnyInstance::Container(container) => container.java_object(),
+            AnyInstance::Shared(shared) => shared.java_object(),
+        }
     }
 }
@@ -0,0 +1,141 @@
+use crate::Result;
+use crate::instance::{Instance, InstanceContainer, InstanceManager};
+use log::debug;
+use parking_lot::Mutex;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+type ResetFn = dyn Fn(&InstanceContainer) -> Result<()> + Send + Sync;
+
+/// A pool of ready-to-use copies of a template instance.
+///
+/// Copying an instance is much cheaper than loading or generating a world, but it
+/// still takes time. The pool keeps `size` registered copies around so that
+/// per-player or per-match instances can be handed out immediately.
+///
+/// # Example
+/// ```rust,no_run
+/// let template = instance_manager.create_instance_container()?;
+/// template.load_anvil_world("worlds/arena")?;
+/// // Only loaded chunks are copied
+/// template.load_chunk(0, 0)?;
+///
+/// let pool = InstancePool::new(&instance_manager, template, 4)?;
+/// let arena = pool.acquire()?;
+/// // ... play the match ...
+/// pool.release(arena)?;
+/// ```
+#[derive(Clone)]
+pub struct InstancePool {
+    inner: Arc<PoolInner>,
+}
+
+struct PoolInner {
+    instance_manager: InstanceManager,
+    template: InstanceContainer,
+    size: usize,
+    available: Mutex<VecDeque<InstanceContainer>>,
+    reset: Mutex<Option<Arc<ResetFn>>>,
+}
+
+impl InstancePool {
+    /// Creates a pool and pre-warms `size` copies of `template`.
+    /// The template itself is never handed out.
+    pub fn new(
+        instance_manager: &InstanceManager,
+        template: InstanceContainer,
+        size: usize,
+    ) -> Result<Self> {
+        let pool = Self {
+            inner: Arc::new(PoolInner {
+                instance_manager: instance_manager.clone(),
+                template,
+                size,
+                available: Mutex::new(VecDeque::with_capacity(size)),
+                reset: Mutex::new(None),
+            }),
+        };
+        pool.fill()?;
+        Ok(pool)
+    }
+
+    /// Sets a function cleaning up released instances so they can be reused.
+    ///
+    /// Without it, released instances are unregistered and replaced by a fresh copy
+    /// of the template. Resetting is faster when a game only touches a few blocks.
+    pub fn with_reset<F>(self, reset: F) -> Self
+    where
+        F: Fn(&InstanceContainer) -> Result<()> + Send + Sync + 'static,
+    {
+        *self.inner.reset.lock() = Some(Arc::new(reset));
+        self
+    }
+
+    /// Takes an instance out of the pool, copying the template if none is ready.
+    pub fn acquire(&self) -> Result<InstanceContainer> {
+        let ready = self.inner.available.lock().pop_front();
+        match ready {
+            Some(instance) => Ok(instance),
+            None => {
+                debug!("Instance pool is empty, copying the template on demand");
+                self.new_copy()
+            }
+        }
+    }
+
+    /// Gives an instance back to the pool once every player left it.
+    ///
+    /// The instance is reset and reused if a reset function is set, otherwise it is
+    /// unregistered and replaced with a fresh copy. Instances exceeding the pool
+    /// size are unregistered, as well as instances which failed to reset.
+    pub fn release(&self, instance: InstanceContainer) -> Result<()> {
+        let reset = self.inner.reset.lock().clone();
+        if let Some(reset) = reset
+            && self.available() < self.inner.size
+        {
+            if let Err(e) = reset(&instance) {
+                // A partially reset instance cannot be reused, replace it
+                self.inner.instance_manager.unregister_instance(&instance)?;
+                self.fill()?;
+                return Err(e);
+            }
+            self.inner.available.lock().push_back(instance);
+            return Ok(());
+        }
+
+        self.inner.instance_manager.unregister_instance(&instance)?;
+        self.fill()
+    }
+
+    /// Number of instances ready to be acquired
+    pub fn available(&self) -> usize {
+        self.inner.available.lock().len()
+    }
+
+    /// Copies the template until `size` instances are ready.
+    pub fn fill(&self) -> Result<()> {
+        while self.available() < self.inner.size {
+            let instance = self.new_copy()?;
+            self.inner.available.lock().push_back(instance);
+        }
+        Ok(())
+    }
+
+    /// Unregisters every instance waiting in the pool.
+    /// Acquired instances are not affected.
+    pub fn clear(&self) -> Result<()> {
+        let instances: Vec<_> = self.inner.available.lock().drain(..).collect();
+        for instance in instances {
+            if instance.is_registered()? {
+                self.inner.instance_manager.unregister_instance(&instance)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn new_copy(&self) -> Result<InstanceContainer> {
+        let instance = self.inner.template.copy()?;
+        self.inner.instance_manager.register_instance(&instance)?;
+        Ok(instance)
+    }
+}
@@ -1,6 +1,7 @@
 use crate::Result;
-use crate::jni_utils::{JavaObject, get_env};
+use crate::jni_utils::{JavaObject, JniValue, get_env};
 use crate::material::Material;
+use crate::tag::Tag;
 use jni::objects::{JObject, JValue};
 
 #[derive(Debug, Clone)]
@@ -46,33 +47,48 @@ impl ItemStack {
         })
     }
 
-    pub fn with_tag<T>(&self, tag: &str, value: T) -> Result<Self>
-    where
-        T: for<'a> Into<JValue<'a, 'a>>,
-    {
-        let mut env = get_env()?;
-
-        // Create the Tag object
-        let tag_obj = env
-            .call_static_method(
-                "net/minestom/server/tag/Tag",
-                "String",
-                "(Ljava/lang/String;)Lnet/minestom/server/tag/Tag;",
-                &[JValue::from(&env.new_string(tag)?)],
-            )?
-            .l()?;
+    /// Returns a copy of this item with `tag` set to `value`, items are immutable
+    pub fn with_tag<T>(&self, tag: &Tag<T>, value: &T) -> Result<Self> {
+        let item = self.inner.call_object_method(
+            "withTag",
+            "(Lnet/minestom/server/tag/Tag;Ljava/lang/Object;)Lnet/minestom/server/item/ItemStack;",
+            &[
+                JniValue::Object(tag.java_tag()?.as_obj()?),
+                JniValue::Object(tag.to_java(value)?.as_obj()?),
+            ],
+        )?;
+        Ok(Self { inner: item })
+    }
 
-        // Call withTag
-        let item = env.call_method(
-            self.inner.as_obj()?,
+    /// Returns a copy of this item without `tag`
+    pub fn without_tag<T>(&self, tag: &Tag<T>) -> Result<Self> {
+        let item = self.inner.call_object_method(
             "withTag",
             "(Lnet/minestom/server/tag/Tag;Ljava/lang/Object;)Lnet/minestom/server/item/ItemStack;",
-            &[JValue::Object(&tag_obj), value.into()],
+            &[
+                JniValue::Object(tag.java_tag()?.as_obj()?),
+                JniValue::Object(JObject::null()),
+            ],
         )?;
+        Ok(Self { inner: item })
+    }
 
-        Ok(Self {
-            inner: JavaObject::new(env.new_global_ref(item.l()?)?),
-        })
+    /// Reads the value of `tag`, `None` if it is not set
+    pub fn get_tag<T>(&self, tag: &Tag<T>) -> Result<Option<T>> {
+        let value = self.inner.call_object_method(
+            "getTag",
+            "(Lnet/minestom/server/tag/Tag;)Ljava/lang/Object;",
+            &[JniValue::Object(tag.java_tag()?.as_obj()?)],
+        )?;
+        tag.read_java(value)
+    }
+
+    pub fn has_tag<T>(&self, tag: &Tag<T>) -> Result<bool> {
+        self.inner.call_bool_method(
+            "hasTag",
+            "(Lnet/minestom/server/tag/Tag;)Z",
+            &[JniValue::Object(tag.java_tag()?.as_obj()?)],
+        )
     }
 
     pub fn with_string_tag(&self, tag: &str, value: &str) -> Result<Self> {
@@ -167,11 +183,51 @@ impl ItemStack {
         })
     }
 
+    pub(crate) fn from_java(inner: JavaObject) -> Self {
+        Self { inner }
+    }
+
     pub(crate) fn as_obj(&self) -> &JavaObject {
         &self.inner
     }
 }
 
+/// Slot of a living entity holding an item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EquipmentSlot {
+    MainHand,
+    OffHand,
+    Boots,
+    Leggings,
+    Chestplate,
+    Helmet,
+}
+
+impl EquipmentSlot {
+    fn to_java_field(&self) -> &'static str {
+        match self {
+            EquipmentSlot::MainHand => "MAIN_HAND",
+            EquipmentSlot::OffHand => "OFF_HAND",
+            EquipmentSlot::Boots => "BOOTS",
+            EquipmentSlot::Leggings => "LEGGINGS",
+            EquipmentSlot::Chestplate => "CHESTPLATE",
+            EquipmentSlot::Helmet => "HELMET",
+        }
+    }
+
+    pub(crate) fn to_java(&self) -> Result<JavaObject> {
+        let mut env = get_env()?;
+        let slot = env
+            .get_static_field(
+                "net/minestom/server/entity/EquipmentSlot",
+                self.to_java_field(),
+                "Lnet/minestom/server/entity/EquipmentSlot;",
+            )?
+            .l()?;
+        JavaObject::from_env(&mut env, slot)
+    }
+}
+
 pub trait InventoryHolder {
     fn get_inventory(&self) -> Result<PlayerInventory>;
 }
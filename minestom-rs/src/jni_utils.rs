@@ -1,7 +1,7 @@
 use crate::Result;
 use crate::error::MinestomError;
 use env_logger::from_env;
-use jni::objects::{GlobalRef, JObject, JString, JValueGen};
+use jni::objects::{GlobalRef, JObject, JObjectArray, JString, JValueGen};
 use jni::{JNIEnv, JavaVM};
 use parking_lot::Mutex;
 use std::cell::RefCell;
@@ -395,6 +395,57 @@ impl JavaObject {
         check_exception(&mut env)?;
         Ok(result.d()?)
     }
+
+    /// Calls a method on this object that returns a float.
+    pub fn call_float_method<'arg_local>(
+        &self,
+        name: &str,
+        sig: &str,
+        args: &[JniValue<'arg_local>],
+    ) -> Result<f32> {
+        let mut env = get_env()?;
+        let _frame = env.push_local_frame(32)?;
+        let target_obj_local = env.new_local_ref(&self.as_obj()?)?;
+
+        let mut owned_java_args: Vec<JObject> = Vec::new();
+        for arg in args.iter() {
+            match arg {
+                JniValue::Object(o) => owned_java_args.push(env.new_local_ref(o)?),
+                JniValue::String(s) => owned_java_args.push(env.new_local_ref(s)?),
+                _ => {}
+            }
+        }
+
+        let mut jvalue_args: Vec<jni::objects::JValue> = Vec::with_capacity(args.len());
+        let mut owned_java_args_iter = owned_java_args.iter();
+
+        for arg in args.iter() {
+            match arg {
+                JniValue::Object(_) => {
+                    jvalue_args.push(jni::objects::JValue::from(
+                        owned_java_args_iter.next().unwrap(),
+                    ));
+                }
+                JniValue::String(_) => {
+                    jvalue_args.push(jni::objects::JValue::from(
+                        owned_java_args_iter.next().unwrap(),
+                    ));
+                }
+                JniValue::Int(i) => jvalue_args.push(jni::objects::JValue::Int(*i)),
+                JniValue::Long(l) => jvalue_args.push(jni::objects::JValue::Long(*l)),
+                JniValue::Double(d) => jvalue_args.push(jni::objects::JValue::Double(*d)),
+                JniValue::Float(f) => jvalue_args.push(jni::objects::JValue::Float(*f)),
+                JniValue::Bool(b) => {
+                    jvalue_args.push(jni::objects::JValue::Bool(if *b { 1 } else { 0 }))
+                }
+                JniValue::Void => jvalue_args.push(jni::objects::JValue::Void),
+            }
+        }
+
+        let result = env.call_method(target_obj_local, name, sig, &jvalue_args)?;
+        check_exception(&mut env)?;
+        Ok(result.f()?)
+    }
 }
 
 impl fmt::Debug for JavaObject {
@@ -584,6 +635,29 @@ impl ToJava for JavaObject {
     }
 }
 
+/// Copies the elements of a Java collection.
+pub(crate) fn collection_to_vec(collection: &JavaObject) -> Result<Vec<JavaObject>> {
+    let mut env = get_env()?;
+    let array = env
+        .call_method(
+            collection.as_obj()?,
+            "toArray",
+            "()[Ljava/lang/Object;",
+            &[],
+        )?
+        .l()?;
+    let array = JObjectArray::from(array);
+    let length = env.get_array_length(&array)?;
+    let mut elements = Vec::with_capacity(length as usize);
+
+    for i in 0..length {
+        let element = env.get_object_array_element(&array, i)?;
+        elements.push(JavaObject::from_env(&mut env, element)?);
+    }
+
+    Ok(elements)
+}
+
 /// Check if there's a Java exception and convert it to a Rust error
 pub(crate) fn check_exception(env: &mut JNIEnv) -> Result<()> {
     if env.exception_check()? {
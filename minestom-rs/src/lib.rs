@@ -1,35 +1,54 @@
 pub mod advancement;
 pub mod attribute;
+pub mod batch;
+pub mod biome;
 pub mod block;
+pub mod block_handler;
+pub mod clickable_area;
 pub mod collision;
 pub mod command;
 pub mod cookie;
 pub mod coordinate;
+pub mod damage;
+pub mod dimension;
 pub mod entity;
 pub mod error;
 pub mod event;
+pub mod future;
+pub mod generator;
+pub mod hologram;
 pub mod instance;
+pub mod instance_pool;
 pub mod item;
 pub mod jni_env;
 pub mod jni_utils;
 pub mod material;
+pub mod nbt;
 pub mod particle;
+pub mod potion;
+pub mod raycast;
+pub mod region;
 pub mod resource_pack;
 pub mod scheduler;
+pub mod schematic;
 pub mod server;
 pub mod sound;
 pub mod tag;
 pub mod text;
 pub mod transfer;
+pub mod weather;
+pub mod world_border;
 
 pub use error::MinestomError;
 pub type Result<T> = std::result::Result<T, MinestomError>;
-pub use attribute::{Attribute, AttributeInstance};
-pub use block::{Block, BlockType};
+pub use attribute::{Attribute, AttributeInstance, AttributeModifier, AttributeOperation};
+pub use block::{Block, BlockFace, BlockType};
+pub use clickable_area::{AreaClick, ClickableArea};
 pub use coordinate::{Pos, Position};
 use event::inventory::InventoryPreClickEvent;
 pub use server::MinestomServer;
 pub use sound::{Sound, SoundEvent, Source};
+pub use tag::{Tag, TagHandler, TagValue};
 pub use text::Component;
 use tokio::runtime::Handle;
 
@@ -37,14 +56,23 @@ use tokio::runtime::Handle;
 use crate::event::CALLBACKS;
 use crate::jni_utils::JavaObject;
 pub use command::{Command, EntityFinder, Suggestion, SuggestionEntry, Argument, create_string_arg, create_integer_arg, create_player_arg, create_greedy_string_arg};
-pub use entity::Player;
+pub use entity::{EntityLike, LivingEntityLike, Player};
 pub use event::Event;
+pub use event::entity::{
+    EntityAttackEvent, EntityDamageEvent, EntityDeathEvent, EntityPotionAddEvent,
+    EntityPotionRemoveEvent,
+};
 pub use event::player::{
-    AsyncPlayerConfigurationEvent, PlayerChatEvent, PlayerDisconnectEvent,
-    PlayerEntityInteractEvent, PlayerMoveEvent, PlayerSkinInitEvent, PlayerSpawnEvent,
+    AsyncPlayerConfigurationEvent, PlayerBlockBreakEvent, PlayerBlockPlaceEvent, PlayerChatEvent,
+    PlayerDisconnectEvent, PlayerEntityInteractEvent, PlayerMoveEvent, PlayerSkinInitEvent,
+    PlayerSpawnEvent,
 };
+pub use event::instance::RemoveEntityFromInstanceEvent;
+pub use event::projectile::{ProjectileCollideWithBlockEvent, ProjectileCollideWithEntityEvent};
 pub use event::server::ServerListPingEvent;
-pub use instance::{Instance, InstanceContainer, SharedInstance};
+pub use hologram::{Hologram, HologramLine};
+pub use instance::{AnyInstance, Instance, InstanceContainer, SharedInstance};
+pub use region::{PlayerEnterRegionEvent, PlayerLeaveRegionEvent, Region, RegionFlags, RegionManager};
 use jni::objects::{JObject, JString};
 use jni::sys::{JNIEnv, jlong, jobject};
 use log::{debug, error};
@@ -86,6 +114,18 @@ pub static EVENT_REGISTRY: Lazy<RwLock<HashMap<String, EventConstructor>>> = Laz
     register_event_type::<PlayerChatEvent>(&mut registry);
     register_event_type::<PlayerEntityInteractEvent>(&mut registry);
     register_event_type::<InventoryPreClickEvent>(&mut registry);
+    register_event_type::<PlayerBlockBreakEvent>(&mut registry);
+    register_event_type::<PlayerBlockPlaceEvent>(&mut registry);
+    register_event_type::<PlayerEnterRegionEvent>(&mut registry);
+    register_event_type::<PlayerLeaveRegionEvent>(&mut registry);
+    register_event_type::<EntityAttackEvent>(&mut registry);
+    register_event_type::<EntityDamageEvent>(&mut registry);
+    register_event_type::<EntityDeathEvent>(&mut registry);
+    register_event_type::<EntityPotionAddEvent>(&mut registry);
+    register_event_type::<EntityPotionRemoveEvent>(&mut registry);
+    register_event_type::<ProjectileCollideWithBlockEvent>(&mut registry);
+    register_event_type::<ProjectileCollideWithEntityEvent>(&mut registry);
+    register_event_type::<RemoveEntityFromInstanceEvent>(&mut registry);
 
     RwLock::new(registry)
 });
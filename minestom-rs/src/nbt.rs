@@ -0,0 +1,366 @@
+use crate::Result;
+use crate::error::MinestomError;
+use crate::jni_utils::{JavaObject, get_env};
+use flate2::read::GzDecoder;
+use jni::objects::{JByteArray, JValue};
+use std::collections::HashMap;
+use std::io::{BufReader, Read};
+
+/// A named binary tag value, as stored in schematics, Anvil worlds and item data.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Nbt {
+    Byte(i8),
+    Short(i16),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    ByteArray(Vec<i8>),
+    String(String),
+    List(Vec<Nbt>),
+    Compound(HashMap<String, Nbt>),
+    IntArray(Vec<i32>),
+    LongArray(Vec<i64>),
+}
+
+const TAG_END: u8 = 0;
+const TAG_BYTE: u8 = 1;
+const TAG_SHORT: u8 = 2;
+const TAG_INT: u8 = 3;
+const TAG_LONG: u8 = 4;
+const TAG_FLOAT: u8 = 5;
+const TAG_DOUBLE: u8 = 6;
+const TAG_BYTE_ARRAY: u8 = 7;
+const TAG_STRING: u8 = 8;
+const TAG_LIST: u8 = 9;
+const TAG_COMPOUND: u8 = 10;
+const TAG_INT_ARRAY: u8 = 11;
+const TAG_LONG_ARRAY: u8 = 12;
+
+impl Nbt {
+    /// Reads a root compound from uncompressed NBT bytes.
+    /// Returns the name of the root tag together with its value.
+    pub fn from_reader(reader: impl Read) -> Result<(String, Nbt)> {
+        let mut reader = NbtReader {
+            inner: BufReader::new(reader),
+        };
+        let tag_type = reader.read_u8()?;
+        if tag_type != TAG_COMPOUND {
+            return Err(MinestomError::Nbt(format!(
+                "root tag must be a compound, found type {}",
+                tag_type
+            )));
+        }
+        let name = reader.read_string()?;
+        let value = reader.read_payload(TAG_COMPOUND)?;
+        Ok((name, value))
+    }
+
+    /// Reads a root compound from bytes that may or may not be gzip-compressed.
+    pub fn from_bytes(bytes: &[u8]) -> Result<(String, Nbt)> {
+        if bytes.starts_with(&[0x1f, 0x8b]) {
+            Self::from_reader(GzDecoder::new(bytes))
+        } else {
+            Self::from_reader(bytes)
+        }
+    }
+
+    /// Gets a child of a compound tag by name.
+    pub fn get(&self, key: &str) -> Option<&Nbt> {
+        match self {
+            Nbt::Compound(map) => map.get(key),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as an `i32` if it is any integral numeric tag.
+    pub fn as_i32(&self) -> Option<i32> {
+        match self {
+            Nbt::Byte(v) => Some(*v as i32),
+            Nbt::Short(v) => Some(*v as i32),
+            Nbt::Int(v) => Some(*v),
+            Nbt::Long(v) => i32::try_from(*v).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Nbt::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_compound(&self) -> Option<&HashMap<String, Nbt>> {
+        match self {
+            Nbt::Compound(map) => Some(map),
+            _ => None,
+        }
+    }
+
+    pub fn as_list(&self) -> Option<&[Nbt]> {
+        match self {
+            Nbt::List(list) => Some(list),
+            _ => None,
+        }
+    }
+
+    pub fn as_byte_array(&self) -> Option<&[i8]> {
+        match self {
+            Nbt::ByteArray(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    pub fn as_int_array(&self) -> Option<&[i32]> {
+        match self {
+            Nbt::IntArray(ints) => Some(ints),
+            _ => None,
+        }
+    }
+
+    /// Formats this tag as stringified NBT (SNBT), the format accepted by `/give` and `/data`.
+    /// Fails on NaN and infinite floats, which SNBT cannot represent.
+    pub fn to_snbt(&self) -> Result<String> {
+        let mut out = String::new();
+        self.write_snbt(&mut out)?;
+        Ok(out)
+    }
+
+    fn write_snbt(&self, out: &mut String) -> Result<()> {
+        match self {
+            Nbt::Byte(v) => out.push_str(&format!("{}b", v)),
+            Nbt::Short(v) => out.push_str(&format!("{}s", v)),
+            Nbt::Int(v) => out.push_str(&v.to_string()),
+            Nbt::Long(v) => out.push_str(&format!("{}L", v)),
+            Nbt::Float(v) => out.push_str(&format!("{}f", finite(*v as f64)?)),
+            Nbt::Double(v) => out.push_str(&format!("{}d", finite(*v)?)),
+            Nbt::String(s) => write_quoted(out, s),
+            Nbt::ByteArray(values) => {
+                out.push_str("[B;");
+                join(out, values, |out, v| out.push_str(&format!("{}b", v)));
+                out.push(']');
+            }
+            Nbt::IntArray(values) => {
+                out.push_str("[I;");
+                join(out, values, |out, v| out.push_str(&v.to_string()));
+                out.push(']');
+            }
+            Nbt::LongArray(values) => {
+                out.push_str("[L;");
+                join(out, values, |out, v| out.push_str(&format!("{}L", v)));
+                out.push(']');
+            }
+            Nbt::List(values) => {
+                out.push('[');
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    value.write_snbt(out)?;
+                }
+                out.push(']');
+            }
+            Nbt::Compound(map) => {
+                out.push('{');
+                let mut entries: Vec<_> = map.iter().collect();
+                entries.sort_by(|a, b| a.0.cmp(b.0));
+                for (i, (key, value)) in entries.into_iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    write_quoted(out, key);
+                    out.push(':');
+                    value.write_snbt(out)?;
+                }
+                out.push('}');
+            }
+        }
+        Ok(())
+    }
+
+    /// Converts this tag into an Adventure `BinaryTag` Java object.
+    /// Only compounds can be converted, as that is what Minestom expects for block and item data.
+    pub(crate) fn to_java_compound(&self) -> Result<JavaObject> {
+        if !matches!(self, Nbt::Compound(_)) {
+            return Err(MinestomError::Nbt(
+                "only compound tags can be converted to Java".to_string(),
+            ));
+        }
+
+        let mut env = get_env()?;
+        let snbt = env.new_string(self.to_snbt()?)?;
+        let io = env
+            .call_static_method(
+                "net/kyori/adventure/nbt/TagStringIO",
+                "get",
+                "()Lnet/kyori/adventure/nbt/TagStringIO;",
+                &[],
+            )?
+            .l()?;
+        let compound = env
+            .call_method(
+                &io,
+                "asCompound",
+                "(Ljava/lang/String;)Lnet/kyori/adventure/nbt/CompoundBinaryTag;",
+                &[JValue::Object(&snbt)],
+            )?
+            .l()?;
+
+        JavaObject::from_env(&mut env, compound)
+    }
+
+    /// Reads an Adventure `CompoundBinaryTag` Java object.
+    pub(crate) fn from_java_compound(compound: &JavaObject) -> Result<Nbt> {
+        let mut env = get_env()?;
+        let output = env.new_object("java/io/ByteArrayOutputStream", "()V", &[])?;
+        let writer = env
+            .call_static_method(
+                "net/kyori/adventure/nbt/BinaryTagIO",
+                "writer",
+                "()Lnet/kyori/adventure/nbt/BinaryTagIO$Writer;",
+                &[],
+            )?
+            .l()?;
+        env.call_method(
+            &writer,
+            "write",
+            "(Lnet/kyori/adventure/nbt/CompoundBinaryTag;Ljava/io/OutputStream;)V",
+            &[JValue::Object(&compound.as_obj()?), JValue::Object(&output)],
+        )?;
+        let bytes = env.call_method(&output, "toByteArray", "()[B", &[])?.l()?;
+        let bytes = env.convert_byte_array(JByteArray::from(bytes))?;
+        Ok(Self::from_bytes(&bytes)?.1)
+    }
+}
+
+fn join<T>(out: &mut String, values: &[T], mut write: impl FnMut(&mut String, &T)) {
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write(out, value);
+    }
+}
+
+/// SNBT has no syntax for NaN and infinities
+fn finite(value: f64) -> Result<f64> {
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err(MinestomError::Nbt(format!(
+            "{} cannot be written as SNBT",
+            value
+        )))
+    }
+}
+
+fn write_quoted(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        if c == '"' || c == '\\' {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out.push('"');
+}
+
+struct NbtReader<R: Read> {
+    inner: BufReader<R>,
+}
+
+impl<R: Read> NbtReader<R> {
+    fn read_exact<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut buf = [0u8; N];
+        self.inner.read_exact(&mut buf)?;
+        Ok(buf)
+    }
+
+    fn read_u8(&mut self) -> Result<u8> {
+        Ok(self.read_exact::<1>()?[0])
+    }
+
+    fn read_i16(&mut self) -> Result<i16> {
+        Ok(i16::from_be_bytes(self.read_exact()?))
+    }
+
+    fn read_i32(&mut self) -> Result<i32> {
+        Ok(i32::from_be_bytes(self.read_exact()?))
+    }
+
+    fn read_i64(&mut self) -> Result<i64> {
+        Ok(i64::from_be_bytes(self.read_exact()?))
+    }
+
+    fn read_len(&mut self) -> Result<usize> {
+        let len = self.read_i32()?;
+        usize::try_from(len).map_err(|_| MinestomError::Nbt(format!("negative length {}", len)))
+    }
+
+    fn read_string(&mut self) -> Result<String> {
+        let len = self.read_i16()? as u16 as usize;
+        let mut buf = vec![0u8; len];
+        self.inner.read_exact(&mut buf)?;
+        // Java writes modified UTF-8, which only differs from UTF-8 for NUL and
+        // supplementary characters; a lossy conversion is fine for identifiers.
+        Ok(String::from_utf8_lossy(&buf).into_owned())
+    }
+
+    fn read_payload(&mut self, tag_type: u8) -> Result<Nbt> {
+        Ok(match tag_type {
+            TAG_BYTE => Nbt::Byte(self.read_u8()? as i8),
+            TAG_SHORT => Nbt::Short(self.read_i16()?),
+            TAG_INT => Nbt::Int(self.read_i32()?),
+            TAG_LONG => Nbt::Long(self.read_i64()?),
+            TAG_FLOAT => Nbt::Float(f32::from_be_bytes(self.read_exact()?)),
+            TAG_DOUBLE => Nbt::Double(f64::from_be_bytes(self.read_exact()?)),
+            TAG_BYTE_ARRAY => {
+                let len = self.read_len()?;
+                let mut buf = vec![0u8; len];
+                self.inner.read_exact(&mut buf)?;
+                Nbt::ByteArray(buf.into_iter().map(|b| b as i8).collect())
+            }
+            TAG_STRING => Nbt::String(self.read_string()?),
+            TAG_LIST => {
+                let element_type = self.read_u8()?;
+                let len = self.read_len()?;
+                let mut list = Vec::with_capacity(len.min(4096));
+                for _ in 0..len {
+                    list.push(self.read_payload(element_type)?);
+                }
+                Nbt::List(list)
+            }
+            TAG_COMPOUND => {
+                let mut map = HashMap::new();
+                loop {
+                    let child_type = self.read_u8()?;
+                    if child_type == TAG_END {
+                        break;
+                    }
+                    let name = self.read_string()?;
+                    let value = self.read_payload(child_type)?;
+                    map.insert(name, value);
+                }
+                Nbt::Compound(map)
+            }
+            TAG_INT_ARRAY => {
+                let len = self.read_len()?;
+                let mut ints = Vec::with_capacity(len.min(4096));
+                for _ in 0..len {
+                    ints.push(self.read_i32()?);
+                }
+                Nbt::IntArray(ints)
+            }
+            TAG_LONG_ARRAY => {
+                let len = self.read_len()?;
+                let mut longs = Vec::with_capacity(len.min(4096));
+                for _ in 0..len {
+                    longs.push(self.read_i64()?);
+                }
+                Nbt::LongArray(longs)
+            }
+            other => return Err(MinestomError::Nbt(format!("unknown tag type {}", other))),
+        })
+    }
+}
@@ -0,0 +1,269 @@
+use jni::objects::{JString, JValue};
+
+use crate::Result;
+use crate::error::MinestomError;
+use crate::jni_utils::{JavaObject, get_env};
+
+/// Status effects that can be applied to living entities.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PotionEffect {
+    Absorption,
+    BadOmen,
+    Blindness,
+    ConduitPower,
+    Darkness,
+    DolphinsGrace,
+    FireResistance,
+    Glowing,
+    Haste,
+    HealthBoost,
+    HeroOfTheVillage,
+    Hunger,
+    Infested,
+    InstantDamage,
+    InstantHealth,
+    Invisibility,
+    JumpBoost,
+    Levitation,
+    Luck,
+    MiningFatigue,
+    Nausea,
+    NightVision,
+    Oozing,
+    Poison,
+    RaidOmen,
+    Regeneration,
+    Resistance,
+    Saturation,
+    SlowFalling,
+    Slowness,
+    Speed,
+    Strength,
+    TrialOmen,
+    Unluck,
+    WaterBreathing,
+    Weakness,
+    Weaving,
+    WindCharged,
+    Wither,
+}
+
+impl PotionEffect {
+    pub fn to_java_name(&self) -> &'static str {
+        match self {
+            PotionEffect::Absorption => "minecraft:absorption",
+            PotionEffect::BadOmen => "minecraft:bad_omen",
+            PotionEffect::Blindness => "minecraft:blindness",
+            PotionEffect::ConduitPower => "minecraft:conduit_power",
+            PotionEffect::Darkness => "minecraft:darkness",
+            PotionEffect::DolphinsGrace => "minecraft:dolphins_grace",
+            PotionEffect::FireResistance => "minecraft:fire_resistance",
+            PotionEffect::Glowing => "minecraft:glowing",
+            PotionEffect::Haste => "minecraft:haste",
+            PotionEffect::HealthBoost => "minecraft:health_boost",
+            PotionEffect::HeroOfTheVillage => "minecraft:hero_of_the_village",
+            PotionEffect::Hunger => "minecraft:hunger",
+            PotionEffect::Infested => "minecraft:infested",
+            PotionEffect::InstantDamage => "minecraft:instant_damage",
+            PotionEffect::InstantHealth => "minecraft:instant_health",
+            PotionEffect::Invisibility => "minecraft:invisibility",
+            PotionEffect::JumpBoost => "minecraft:jump_boost",
+            PotionEffect::Levitation => "minecraft:levitation",
+            PotionEffect::Luck => "minecraft:luck",
+            PotionEffect::MiningFatigue => "minecraft:mining_fatigue",
+            PotionEffect::Nausea => "minecraft:nausea",
+            PotionEffect::NightVision => "minecraft:night_vision",
+            PotionEffect::Oozing => "minecraft:oozing",
+            PotionEffect::Poison => "minecraft:poison",
+            PotionEffect::RaidOmen => "minecraft:raid_omen",
+            PotionEffect::Regeneration => "minecraft:regeneration",
+            PotionEffect::Resistance => "minecraft:resistance",
+            PotionEffect::Saturation => "minecraft:saturation",
+            PotionEffect::SlowFalling => "minecraft:slow_falling",
+            PotionEffect::Slowness => "minecraft:slowness",
+            PotionEffect::Speed => "minecraft:speed",
+            PotionEffect::Strength => "minecraft:strength",
+            PotionEffect::TrialOmen => "minecraft:trial_omen",
+            PotionEffect::Unluck => "minecraft:unluck",
+            PotionEffect::WaterBreathing => "minecraft:water_breathing",
+            PotionEffect::Weakness => "minecraft:weakness",
+            PotionEffect::Weaving => "minecraft:weaving",
+            PotionEffect::WindCharged => "minecraft:wind_charged",
+            PotionEffect::Wither => "minecraft:wither",
+        }
+    }
+
+    pub fn from_java_name(name: &str) -> Option<Self> {
+        match name {
+            "minecraft:absorption" => Some(PotionEffect::Absorption),
+            "minecraft:bad_omen" => Some(PotionEffect::BadOmen),
+            "minecraft:blindness" => Some(PotionEffect::Blindness),
+            "minecraft:conduit_power" => Some(PotionEffect::ConduitPower),
+            "minecraft:darkness" => Some(PotionEffect::Darkness),
+            "minecraft:dolphins_grace" => Some(PotionEffect::DolphinsGrace),
+            "minecraft:fire_resistance" => Some(PotionEffect::FireResistance),
+            "minecraft:glowing" => Some(PotionEffect::Glowing),
+            "minecraft:haste" => Some(PotionEffect::Haste),
+            "minecraft:health_boost" => Some(PotionEffect::HealthBoost),
+            "minecraft:hero_of_the_village" => Some(PotionEffect::HeroOfTheVillage),
+            "minecraft:hunger" => Some(PotionEffect::Hunger),
+            "minecraft:infested" => Some(PotionEffect::Infested),
+            "minecraft:instant_damage" => Some(PotionEffect::InstantDamage),
+            "minecraft:instant_health" => Some(PotionEffect::InstantHealth),
+            "minecraft:invisibility" => Some(PotionEffect::Invisibility),
+            "minecraft:jump_boost" => Some(PotionEffect::JumpBoost),
+            "minecraft:levitation" => Some(PotionEffect::Levitation),
+            "minecraft:luck" => Some(PotionEffect::Luck),
+            "minecraft:mining_fatigue" => Some(PotionEffect::MiningFatigue),
+            "minecraft:nausea" => Some(PotionEffect::Nausea),
+            "minecraft:night_vision" => Some(PotionEffect::NightVision),
+            "minecraft:oozing" => Some(PotionEffect::Oozing),
+            "minecraft:poison" => Some(PotionEffect::Poison),
+            "minecraft:raid_omen" => Some(PotionEffect::RaidOmen),
+            "minecraft:regeneration" => Some(PotionEffect::Regeneration),
+            "minecraft:resistance" => Some(PotionEffect::Resistance),
+            "minecraft:saturation" => Some(PotionEffect::Saturation),
+            "minecraft:slow_falling" => Some(PotionEffect::SlowFalling),
+            "minecraft:slowness" => Some(PotionEffect::Slowness),
+            "minecraft:speed" => Some(PotionEffect::Speed),
+            "minecraft:strength" => Some(PotionEffect::Strength),
+            "minecraft:trial_omen" => Some(PotionEffect::TrialOmen),
+            "minecraft:unluck" => Some(PotionEffect::Unluck),
+            "minecraft:water_breathing" => Some(PotionEffect::WaterBreathing),
+            "minecraft:weakness" => Some(PotionEffect::Weakness),
+            "minecraft:weaving" => Some(PotionEffect::Weaving),
+            "minecraft:wind_charged" => Some(PotionEffect::WindCharged),
+            "minecraft:wither" => Some(PotionEffect::Wither),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn to_java(&self) -> Result<JavaObject> {
+        let mut env = get_env()?;
+        let j_name = env.new_string(self.to_java_name())?;
+        let effect = env
+            .call_static_method(
+                "net/minestom/server/potion/PotionEffect",
+                "fromKey",
+                "(Ljava/lang/String;)Lnet/minestom/server/potion/PotionEffect;",
+                &[JValue::Object(&j_name)],
+            )?
+            .l()?;
+        JavaObject::from_env(&mut env, effect)
+    }
+
+    pub(crate) fn from_java(effect: &JavaObject) -> Result<Self> {
+        let mut env = get_env()?;
+        let name = effect.call_object_method("name", "()Ljava/lang/String;", &[])?;
+        let name = JString::from(env.new_local_ref(name.as_obj()?)?);
+        let name: String = env.get_string(&name)?.into();
+        Self::from_java_name(&name)
+            .ok_or_else(|| MinestomError::Conversion(format!("Unknown potion effect: {}", name)))
+    }
+}
+
+const AMBIENT_FLAG: i8 = 0x01;
+const PARTICLES_FLAG: i8 = 0x02;
+const ICON_FLAG: i8 = 0x04;
+
+/// A status effect with its level and duration.
+///
+/// # Example
+/// ```rust,no_run
+/// let boost = Potion::new(PotionEffect::Speed, 1, 60).with_particles(false);
+/// player.add_effect(&boost)?;
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Potion {
+    pub effect: PotionEffect,
+    /// Level of the effect minus one, e.g. 1 for Speed II
+    pub amplifier: i32,
+    /// Duration in ticks, [`Potion::INFINITE`] for no limit
+    pub duration: i32,
+    /// Ambient effects show fewer particles, like those of beacons
+    pub ambient: bool,
+    pub particles: bool,
+    /// Whether the effect is shown in the inventory and the HUD
+    pub icon: bool,
+}
+
+impl Potion {
+    pub const INFINITE: i32 = -1;
+
+    /// Creates a potion showing particles and an icon.
+    pub fn new(effect: PotionEffect, amplifier: i32, duration: i32) -> Self {
+        Self {
+            effect,
+            amplifier,
+            duration,
+            ambient: false,
+            particles: true,
+            icon: true,
+        }
+    }
+
+    pub fn with_ambient(mut self, ambient: bool) -> Self {
+        self.ambient = ambient;
+        self
+    }
+
+    pub fn with_particles(mut self, particles: bool) -> Self {
+        self.particles = particles;
+        self
+    }
+
+    pub fn with_icon(mut self, icon: bool) -> Self {
+        self.icon = icon;
+        self
+    }
+
+    fn flags(&self) -> i8 {
+        let mut flags = 0;
+        if self.ambient {
+            flags |= AMBIENT_FLAG;
+        }
+        if self.particles {
+            flags |= PARTICLES_FLAG;
+        }
+        if self.icon {
+            flags |= ICON_FLAG;
+        }
+        flags
+    }
+
+    pub(crate) fn to_java(&self) -> Result<JavaObject> {
+        let mut env = get_env()?;
+        let effect = self.effect.to_java()?;
+        let potion = env.new_object(
+            "net/minestom/server/potion/Potion",
+            "(Lnet/minestom/server/potion/PotionEffect;IIB)V",
+            &[
+                JValue::Object(&effect.as_obj()?),
+                JValue::Int(self.amplifier),
+                JValue::Int(self.duration),
+                JValue::Byte(self.flags()),
+            ],
+        )?;
+        JavaObject::from_env(&mut env, potion)
+    }
+
+    pub(crate) fn from_java(potion: &JavaObject) -> Result<Self> {
+        let mut env = get_env()?;
+        let effect = potion.call_object_method(
+            "effect",
+            "()Lnet/minestom/server/potion/PotionEffect;",
+            &[],
+        )?;
+        let flags = env
+            .call_method(potion.as_obj()?, "flags", "()B", &[])?
+            .b()?;
+        Ok(Self {
+            effect: PotionEffect::from_java(&effect)?,
+            amplifier: potion.call_int_method("amplifier", "()I", &[])?,
+            duration: potion.call_int_method("duration", "()I", &[])?,
+            ambient: flags & AMBIENT_FLAG != 0,
+            particles: flags & PARTICLES_FLAG != 0,
+            icon: flags & ICON_FLAG != 0,
+        })
+    }
+}
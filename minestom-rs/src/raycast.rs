@@ -0,0 +1,271 @@
+use crate::Result;
+use crate::block::{Block, BlockFace};
+use crate::coordinate::{Pos, Position};
+use crate::entity::entity::Entity;
+use crate::error::MinestomError;
+use crate::instance::Instance;
+use crate::jni_utils::{JniValue, collection_to_vec};
+
+/// A block hit by [`Instance::raycast_blocks`].
+#[derive(Debug, Clone)]
+pub struct BlockRaycastHit {
+    /// Exact point where the ray entered the block
+    pub position: Position,
+    /// Coordinates of the block that was hit
+    pub block_position: (i32, i32, i32),
+    /// Face of the block the ray went through
+    pub face: BlockFace,
+    pub block: Block,
+    /// Distance from the origin of the ray
+    pub distance: f64,
+}
+
+/// An entity hit by [`Instance::raycast_entities`].
+#[derive(Clone)]
+pub struct EntityRaycastHit {
+    /// Exact point where the ray entered the bounding box of the entity
+    pub position: Position,
+    pub entity: Entity,
+    /// Distance from the origin of the ray
+    pub distance: f64,
+}
+
+/// Returns the normalized direction, or `None` for a zero vector.
+fn normalize(direction: &Position) -> Option<[f64; 3]> {
+    let length =
+        (direction.x * direction.x + direction.y * direction.y + direction.z * direction.z).sqrt();
+    if length == 0.0 || !length.is_finite() {
+        return None;
+    }
+    Some([
+        direction.x / length,
+        direction.y / length,
+        direction.z / length,
+    ])
+}
+
+fn point_at(origin: &Position, direction: &[f64; 3], distance: f64) -> Position {
+    Position::new(
+        origin.x + direction[0] * distance,
+        origin.y + direction[1] * distance,
+        origin.z + direction[2] * distance,
+    )
+}
+
+/// Rejects distances the traversals would never reach.
+fn check_distance(max_distance: f64) -> Result<()> {
+    if max_distance.is_finite() {
+        Ok(())
+    } else {
+        Err(MinestomError::InvalidArgument(format!(
+            "raycast distance must be finite, got {}",
+            max_distance
+        )))
+    }
+}
+
+/// Unit vector looking in the direction of the given yaw and pitch, in degrees.
+pub(crate) fn direction_from_rotation(yaw: f32, pitch: f32) -> Position {
+    let yaw = (yaw as f64).to_radians();
+    let pitch = (pitch as f64).to_radians();
+    Position::new(
+        -yaw.sin() * pitch.cos(),
+        -pitch.sin(),
+        yaw.cos() * pitch.cos(),
+    )
+}
+
+/// Walks the blocks crossed by the ray (Amanatides & Woo traversal) and returns
+/// the first one accepted by `filter`. Stops at unloaded chunks.
+pub(crate) fn raycast_blocks<I: Instance + ?Sized>(
+    instance: &I,
+    origin: &Position,
+    direction: &Position,
+    max_distance: f64,
+    filter: &dyn Fn(&Block) -> Result<bool>,
+) -> Result<Option<BlockRaycastHit>> {
+    check_distance(max_distance)?;
+    let Some(dir) = normalize(direction) else {
+        return Ok(None);
+    };
+    let start = [origin.x, origin.y, origin.z];
+
+    let mut cell = [0i32; 3];
+    let mut step = [0i32; 3];
+    let mut t_max = [f64::INFINITY; 3];
+    let mut t_delta = [f64::INFINITY; 3];
+    for axis in 0..3 {
+        cell[axis] = start[axis].floor() as i32;
+        if dir[axis] > 0.0 {
+            step[axis] = 1;
+            t_max[axis] = (cell[axis] as f64 + 1.0 - start[axis]) / dir[axis];
+            t_delta[axis] = 1.0 / dir[axis];
+        } else if dir[axis] < 0.0 {
+            step[axis] = -1;
+            t_max[axis] = (cell[axis] as f64 - start[axis]) / dir[axis];
+            t_delta[axis] = -1.0 / dir[axis];
+        }
+    }
+
+    // If the ray starts inside a block, report the face opposite to the main direction
+    let main_axis = (0..3)
+        .max_by(|a, b| dir[*a].abs().total_cmp(&dir[*b].abs()))
+        .unwrap();
+    let mut face = entered_face(main_axis, step[main_axis]);
+    let mut distance = 0.0;
+    let mut loaded_chunk: Option<(i32, i32)> = None;
+
+    loop {
+        let chunk = (cell[0] >> 4, cell[2] >> 4);
+        if loaded_chunk != Some(chunk) {
+            if !instance.get_chunk(chunk.0, chunk.1)? {
+                return Ok(None);
+            }
+            loaded_chunk = Some(chunk);
+        }
+
+        let block = instance.get_block(cell[0], cell[1], cell[2])?;
+        if !block.is_air()? && filter(&block)? {
+            return Ok(Some(BlockRaycastHit {
+                position: point_at(origin, &dir, distance),
+                block_position: (cell[0], cell[1], cell[2]),
+                face,
+                block,
+                distance,
+            }));
+        }
+
+        let axis = if t_max[0] < t_max[1] {
+            if t_max[0] < t_max[2] { 0 } else { 2 }
+        } else if t_max[1] < t_max[2] {
+            1
+        } else {
+            2
+        };
+        distance = t_max[axis];
+        if distance > max_distance {
+            return Ok(None);
+        }
+        cell[axis] += step[axis];
+        t_max[axis] += t_delta[axis];
+        face = entered_face(axis, step[axis]);
+    }
+}
+
+/// Face through which a ray moving by `step` along `axis` enters a block.
+fn entered_face(axis: usize, step: i32) -> BlockFace {
+    match (axis, step > 0) {
+        (0, true) => BlockFace::West,
+        (0, false) => BlockFace::East,
+        (1, true) => BlockFace::Bottom,
+        (1, false) => BlockFace::Top,
+        (_, true) => BlockFace::North,
+        (_, false) => BlockFace::South,
+    }
+}
+
+/// Slab test of a ray against an axis-aligned box.
+/// Returns the distance at which the ray enters the box, 0 if it starts inside.
+fn intersect_box(start: &[f64; 3], dir: &[f64; 3], min: &[f64; 3], max: &[f64; 3]) -> Option<f64> {
+    let mut t_enter = 0.0f64;
+    let mut t_exit = f64::INFINITY;
+    for axis in 0..3 {
+        if dir[axis] == 0.0 {
+            if start[axis] < min[axis] || start[axis] > max[axis] {
+                return None;
+            }
+            continue;
+        }
+        let t1 = (min[axis] - start[axis]) / dir[axis];
+        let t2 = (max[axis] - start[axis]) / dir[axis];
+        t_enter = t_enter.max(t1.min(t2));
+        t_exit = t_exit.min(t1.max(t2));
+        if t_enter > t_exit {
+            return None;
+        }
+    }
+    Some(t_enter)
+}
+
+/// Point where the ray enters the box spanning from `min` to `max`, if it crosses it.
+pub(crate) fn ray_box_entry(
+    origin: &Position,
+    direction: &Position,
+    min: &Position,
+    max: &Position,
+) -> Option<Position> {
+    let dir = normalize(direction)?;
+    let distance = intersect_box(
+        &[origin.x, origin.y, origin.z],
+        &dir,
+        &[min.x, min.y, min.z],
+        &[max.x, max.y, max.z],
+    )?;
+    Some(point_at(origin, &dir, distance))
+}
+
+/// Returns the closest entity whose bounding box is crossed by the ray and accepted by `filter`.
+pub(crate) fn raycast_entities<I: Instance + ?Sized>(
+    instance: &I,
+    origin: &Position,
+    direction: &Position,
+    max_distance: f64,
+    filter: &dyn Fn(&Entity) -> Result<bool>,
+) -> Result<Option<EntityRaycastHit>> {
+    check_distance(max_distance)?;
+    let Some(dir) = normalize(direction) else {
+        return Ok(None);
+    };
+    let start = [origin.x, origin.y, origin.z];
+
+    // Only look at entities around the segment, with some margin for their size
+    let center = point_at(origin, &dir, max_distance / 2.0).to_pos()?;
+    let candidates = instance.java_object().call_object_method(
+        "getNearbyEntities",
+        "(Lnet/minestom/server/coordinate/Point;D)Ljava/util/Collection;",
+        &[
+            JniValue::Object(center.inner()?),
+            JniValue::Double(max_distance / 2.0 + 4.0),
+        ],
+    )?;
+
+    let mut closest: Option<EntityRaycastHit> = None;
+    for entity in collection_to_vec(&candidates)?.into_iter().map(Entity::new) {
+        let position = Pos::new(entity.inner.call_object_method(
+            "getPosition",
+            "()Lnet/minestom/server/coordinate/Pos;",
+            &[],
+        )?)
+        .to_position()?;
+        let bounding_box = entity.get_bounding_box()?;
+        let relative_start = bounding_box.relative_start()?;
+        let relative_end = bounding_box.relative_end()?;
+        let min = [
+            position.x + relative_start.x,
+            position.y + relative_start.y,
+            position.z + relative_start.z,
+        ];
+        let max = [
+            position.x + relative_end.x,
+            position.y + relative_end.y,
+            position.z + relative_end.z,
+        ];
+
+        let Some(distance) = intersect_box(&start, &dir, &min, &max) else {
+            continue;
+        };
+        if distance > max_distance || closest.as_ref().is_some_and(|c| c.distance <= distance) {
+            continue;
+        }
+        if !filter(&entity)? {
+            continue;
+        }
+        closest = Some(EntityRaycastHit {
+            position: point_at(origin, &dir, distance),
+            entity,
+            distance,
+        });
+    }
+
+    Ok(closest)
+}
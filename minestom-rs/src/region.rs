@@ -0,0 +1,537 @@
+use crate::Result;
+use crate::coordinate::Position;
+use crate::entity::Player;
+use crate::event::Event;
+use crate::event::instance::RemoveEntityFromInstanceEvent;
+use crate::event::player::{
+    PlayerBlockBreakEvent, PlayerBlockPlaceEvent, PlayerDisconnectEvent, PlayerMoveEvent,
+    PlayerSpawnEvent,
+};
+use crate::instance::Instance;
+use crate::jni_utils::{JavaObject, get_env};
+use crate::sound::Sound;
+use jni::objects::{JString, JValue};
+use parking_lot::{Mutex, RwLock};
+use std::any::Any;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Shape of a region, in absolute block coordinates.
+#[derive(Debug, Clone)]
+pub enum Region {
+    /// Box between two opposite corners, both inclusive
+    Cuboid {
+        min: Position,
+        max: Position,
+    },
+    Sphere {
+        center: Position,
+        radius: f64,
+    },
+    /// Polygon in the XZ plane extruded between two heights
+    PolygonPrism {
+        points: Vec<(f64, f64)>,
+        min_y: f64,
+        max_y: f64,
+    },
+}
+
+impl Region {
+    /// Creates a cuboid from any two opposite corners.
+    pub fn cuboid(a: Position, b: Position) -> Self {
+        Region::Cuboid {
+            min: Position::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z)),
+            max: Position::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z)),
+        }
+    }
+
+    pub fn sphere(center: Position, radius: f64) -> Self {
+        Region::Sphere { center, radius }
+    }
+
+    /// Creates a prism from the `(x, z)` vertices of its base, in order.
+    pub fn polygon_prism(points: Vec<(f64, f64)>, min_y: f64, max_y: f64) -> Self {
+        Region::PolygonPrism {
+            points,
+            min_y: min_y.min(max_y),
+            max_y: min_y.max(max_y),
+        }
+    }
+
+    /// Returns true if the position is inside the region.
+    pub fn contains(&self, pos: &Position) -> bool {
+        match self {
+            Region::Cuboid { min, max } => {
+                pos.x >= min.x
+                    && pos.x <= max.x
+                    && pos.y >= min.y
+                    && pos.y <= max.y
+                    && pos.z >= min.z
+                    && pos.z <= max.z
+            }
+            Region::Sphere { center, radius } => {
+                let (dx, dy, dz) = (pos.x - center.x, pos.y - center.y, pos.z - center.z);
+                dx * dx + dy * dy + dz * dz <= radius * radius
+            }
+            Region::PolygonPrism {
+                points,
+                min_y,
+                max_y,
+            } => pos.y >= *min_y && pos.y <= *max_y && polygon_contains(points, pos.x, pos.z),
+        }
+    }
+
+    /// Smallest box containing the region, as `(min, max)` corners.
+    pub fn bounds(&self) -> (Position, Position) {
+        match self {
+            Region::Cuboid { min, max } => (min.clone(), max.clone()),
+            Region::Sphere { center, radius } => (
+                Position::new(center.x - radius, center.y - radius, center.z - radius),
+                Position::new(center.x + radius, center.y + radius, center.z + radius),
+            ),
+            Region::PolygonPrism {
+                points,
+                min_y,
+                max_y,
+            } => {
+                let mut min = Position::new(f64::INFINITY, *min_y, f64::INFINITY);
+                let mut max = Position::new(f64::NEG_INFINITY, *max_y, f64::NEG_INFINITY);
+                for (x, z) in points {
+                    min.x = min.x.min(*x);
+                    min.z = min.z.min(*z);
+                    max.x = max.x.max(*x);
+                    max.z = max.z.max(*z);
+                }
+                (min, max)
+            }
+        }
+    }
+}
+
+/// Even-odd rule, points on the edges may be reported either way.
+fn polygon_contains(points: &[(f64, f64)], x: f64, z: f64) -> bool {
+    let mut inside = false;
+    let mut j = points.len().wrapping_sub(1);
+    for i in 0..points.len() {
+        let (xi, zi) = points[i];
+        let (xj, zj) = points[j];
+        if (zi > z) != (zj > z) && x < (xj - xi) * (z - zi) / (zj - zi) + xi {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+/// Rules applied inside a region.
+#[derive(Debug, Clone, Default)]
+pub struct RegionFlags {
+    /// Players cannot break or place blocks
+    pub no_build: bool,
+    /// Players should not be able to hurt each other, see [`RegionManager::allows_pvp`]
+    pub no_pvp: bool,
+    /// Played to players entering the region and stopped when they leave it
+    pub music: Option<Sound>,
+}
+
+struct RegionEntry {
+    region: Region,
+    flags: RegionFlags,
+}
+
+/// Regions whose bounds overlap more chunks are not indexed by chunk
+/// but checked at every query, so huge regions do not fill the grid
+const MAX_INDEXED_CHUNKS: i64 = 4096;
+
+/// Regions indexed by the chunks their bounds overlap
+#[derive(Default)]
+struct ChunkGrid {
+    cells: HashMap<(i32, i32), Vec<String>>,
+    /// Regions too large to be indexed
+    large: Vec<String>,
+}
+
+/// Chunk coordinates covered by a region, as inclusive `(min, max)` corners.
+fn chunk_bounds(region: &Region) -> ((i32, i32), (i32, i32)) {
+    let (min, max) = region.bounds();
+    (
+        (min.x.floor() as i32 >> 4, min.z.floor() as i32 >> 4),
+        (max.x.floor() as i32 >> 4, max.z.floor() as i32 >> 4),
+    )
+}
+
+fn chunk_count(((min_x, min_z), (max_x, max_z)): ((i32, i32), (i32, i32))) -> i64 {
+    (max_x as i64 - min_x as i64 + 1) * (max_z as i64 - min_z as i64 + 1)
+}
+
+/// Named regions of an instance.
+///
+/// The manager listens to the instance events to track which regions each player is in,
+/// and calls a [`PlayerEnterRegionEvent`] or a [`PlayerLeaveRegionEvent`] when that
+/// changes. Players leaving the instance leave all of its regions.
+/// These events go through the usual event nodes.
+///
+/// # Example
+/// ```rust,no_run
+/// let regions = RegionManager::new(&instance)?;
+/// regions.add(
+///     "spawn",
+///     Region::sphere(Position::new(0.0, 40.0, 0.0), 30.0),
+///     RegionFlags {
+///         no_build: true,
+///         no_pvp: true,
+///         ..Default::default()
+///     },
+/// );
+///
+/// instance.event_node()?.listen(|event: &PlayerEnterRegionEvent| {
+///     if event.region()? == "spawn" {
+///         event.player()?.send_message(&Component::text("Welcome back"))?;
+///     }
+///     Ok(())
+/// })?;
+/// ```
+#[derive(Clone)]
+pub struct RegionManager {
+    inner: Arc<ManagerInner>,
+}
+
+struct ManagerInner {
+    regions: RwLock<HashMap<String, RegionEntry>>,
+    grid: RwLock<ChunkGrid>,
+    /// Regions each player was in at their last known position
+    players: Mutex<HashMap<Uuid, HashSet<String>>>,
+}
+
+impl RegionManager {
+    /// Creates an empty manager and starts tracking the players of the instance.
+    pub fn new(instance: &dyn Instance) -> Result<Self> {
+        let manager = Self {
+            inner: Arc::new(ManagerInner {
+                regions: RwLock::new(HashMap::new()),
+                grid: RwLock::new(ChunkGrid::default()),
+                players: Mutex::new(HashMap::new()),
+            }),
+        };
+        let event_node = instance.event_node()?;
+
+        let regions = manager.clone();
+        event_node.listen(move |event: &PlayerMoveEvent| {
+            regions.update_player(&event.player()?, &event.new_position()?)
+        })?;
+
+        let regions = manager.clone();
+        event_node.listen(move |event: &PlayerSpawnEvent| {
+            let player = event.player()?;
+            regions.update_player(&player, &player.get_position()?)
+        })?;
+
+        let regions = manager.clone();
+        event_node.listen(move |event: &RemoveEntityFromInstanceEvent| {
+            match event.entity()?.as_player()? {
+                Some(player) => regions.leave_all(&player),
+                None => Ok(()),
+            }
+        })?;
+
+        let regions = manager.clone();
+        event_node.listen(move |event: &PlayerDisconnectEvent| {
+            let uuid = event.player()?.get_uuid()?;
+            regions.inner.players.lock().remove(&uuid);
+            Ok(())
+        })?;
+
+        let regions = manager.clone();
+        event_node.listen(move |event: &PlayerBlockBreakEvent| {
+            if !regions.allows_build(&block_center(&event.block_position()?)) {
+                event.cancel()?;
+            }
+            Ok(())
+        })?;
+
+        let regions = manager.clone();
+        event_node.listen(move |event: &PlayerBlockPlaceEvent| {
+            if !regions.allows_build(&block_center(&event.block_position()?)) {
+                event.cancel()?;
+            }
+            Ok(())
+        })?;
+
+        Ok(manager)
+    }
+
+    /// Adds a region, replacing any region with the same name.
+    /// Players already inside get the enter event on their next move.
+    pub fn add(&self, name: &str, region: Region, flags: RegionFlags) {
+        self.remove(name);
+
+        let bounds = chunk_bounds(&region);
+        let mut grid = self.inner.grid.write();
+        if chunk_count(bounds) > MAX_INDEXED_CHUNKS {
+            grid.large.push(name.to_string());
+        } else {
+            let ((min_x, min_z), (max_x, max_z)) = bounds;
+            for chunk_x in min_x..=max_x {
+                for chunk_z in min_z..=max_z {
+                    grid.cells
+                        .entry((chunk_x, chunk_z))
+                        .or_default()
+                        .push(name.to_string());
+                }
+            }
+        }
+        self.inner
+            .regions
+            .write()
+            .insert(name.to_string(), RegionEntry { region, flags });
+    }
+
+    /// Removes a region, returns false if it did not exist.
+    /// Players inside get the leave event on their next move.
+    pub fn remove(&self, name: &str) -> bool {
+        let Some(entry) = self.inner.regions.write().remove(name) else {
+            return false;
+        };
+
+        let bounds = chunk_bounds(&entry.region);
+        let mut grid = self.inner.grid.write();
+        if chunk_count(bounds) > MAX_INDEXED_CHUNKS {
+            grid.large.retain(|n| n != name);
+            return true;
+        }
+        let ((min_x, min_z), (max_x, max_z)) = bounds;
+        for chunk_x in min_x..=max_x {
+            for chunk_z in min_z..=max_z {
+                if let Some(names) = grid.cells.get_mut(&(chunk_x, chunk_z)) {
+                    names.retain(|n| n != name);
+                    if names.is_empty() {
+                        grid.cells.remove(&(chunk_x, chunk_z));
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    /// Gets the shape of a region.
+    pub fn get_region(&self, name: &str) -> Option<Region> {
+        self.inner
+            .regions
+            .read()
+            .get(name)
+            .map(|entry| entry.region.clone())
+    }
+
+    /// Gets the flags of a region.
+    pub fn get_flags(&self, name: &str) -> Option<RegionFlags> {
+        self.inner
+            .regions
+            .read()
+            .get(name)
+            .map(|entry| entry.flags.clone())
+    }
+
+    /// Names of the regions containing the position.
+    pub fn regions_at(&self, pos: &Position) -> Vec<String> {
+        let chunk = (pos.x.floor() as i32 >> 4, pos.z.floor() as i32 >> 4);
+        let grid = self.inner.grid.read();
+        let indexed = grid.cells.get(&chunk).into_iter().flatten();
+        let regions = self.inner.regions.read();
+        indexed
+            .chain(&grid.large)
+            .filter(|name| {
+                regions
+                    .get(name.as_str())
+                    .is_some_and(|entry| entry.region.contains(pos))
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Names of the regions the player was in at their last move.
+    pub fn regions_of(&self, player: &Player) -> Result<Vec<String>> {
+        let uuid = player.get_uuid()?;
+        Ok(self
+            .inner
+            .players
+            .lock()
+            .get(&uuid)
+            .map(|names| names.iter().cloned().collect())
+            .unwrap_or_default())
+    }
+
+    /// Returns false if a region containing the position has the no-build flag.
+    pub fn allows_build(&self, pos: &Position) -> bool {
+        !self.any_flag(pos, |flags| flags.no_build)
+    }
+
+    /// Returns false if a region containing either position has the no-PvP flag.
+    ///
+    /// Minestom does not implement combat, so this must be checked by the code
+    /// dealing damage between players.
+    pub fn allows_pvp(&self, attacker: &Position, victim: &Position) -> bool {
+        !self.any_flag(attacker, |flags| flags.no_pvp)
+            && !self.any_flag(victim, |flags| flags.no_pvp)
+    }
+
+    fn any_flag(&self, pos: &Position, flag: impl Fn(&RegionFlags) -> bool) -> bool {
+        // `regions_at` locks the grid before the regions, release both first
+        let names = self.regions_at(pos);
+        let regions = self.inner.regions.read();
+        names
+            .iter()
+            .filter_map(|name| regions.get(name))
+            .any(|entry| flag(&entry.flags))
+    }
+
+    fn update_player(&self, player: &Player, pos: &Position) -> Result<()> {
+        let uuid = player.get_uuid()?;
+        let current: HashSet<String> = self.regions_at(pos).into_iter().collect();
+
+        let (entered, left) = {
+            let mut players = self.inner.players.lock();
+            let previous = players.entry(uuid).or_default();
+            if *previous == current {
+                return Ok(());
+            }
+            let entered: Vec<String> = current.difference(previous).cloned().collect();
+            let left: Vec<String> = previous.difference(&current).cloned().collect();
+            *previous = current;
+            (entered, left)
+        };
+
+        // Events are called without holding any lock, listeners may query the manager
+        for name in left {
+            self.leave(player, &name)?;
+        }
+        for name in entered {
+            if let Some(music) = self.get_flags(&name).and_then(|flags| flags.music) {
+                player.play_sound(&music)?;
+            }
+            call_region_event(PlayerEnterRegionEvent::java_class_name(), player, &name)?;
+        }
+        Ok(())
+    }
+
+    /// Makes a player leaving the instance leave every region they were in.
+    fn leave_all(&self, player: &Player) -> Result<()> {
+        let uuid = player.get_uuid()?;
+        let left = self.inner.players.lock().remove(&uuid).unwrap_or_default();
+        for name in left {
+            self.leave(player, &name)?;
+        }
+        Ok(())
+    }
+
+    fn leave(&self, player: &Player, name: &str) -> Result<()> {
+        if let Some(music) = self.get_flags(name).and_then(|flags| flags.music) {
+            player.stop_sound(&music)?;
+        }
+        call_region_event(PlayerLeaveRegionEvent::java_class_name(), player, name)
+    }
+}
+
+fn block_center(block_position: &Position) -> Position {
+    Position::new(
+        block_position.x.floor() + 0.5,
+        block_position.y.floor() + 0.5,
+        block_position.z.floor() + 0.5,
+    )
+}
+
+/// Creates one of the Java region events and calls it through the global event handler.
+fn call_region_event(class: &str, player: &Player, region: &str) -> Result<()> {
+    let mut env = get_env()?;
+    let j_region = env.new_string(region)?;
+    let event = env.new_object(
+        class,
+        "(Lnet/minestom/server/entity/Player;Ljava/lang/String;)V",
+        &[JValue::Object(&player.inner()?), JValue::Object(&j_region)],
+    )?;
+    env.call_static_method(
+        "net/minestom/server/event/EventDispatcher",
+        "call",
+        "(Lnet/minestom/server/event/Event;)V",
+        &[JValue::Object(&event)],
+    )?;
+    Ok(())
+}
+
+fn event_player(inner: &JavaObject) -> Result<Player> {
+    let mut env = get_env()?;
+    let result =
+        inner.call_object_method("getPlayer", "()Lnet/minestom/server/entity/Player;", &[])?;
+    Ok(Player::new(JavaObject::from_env(
+        &mut env,
+        result.as_obj()?,
+    )?))
+}
+
+fn event_region(inner: &JavaObject) -> Result<String> {
+    let mut env = get_env()?;
+    let region = inner.call_object_method("getRegion", "()Ljava/lang/String;", &[])?;
+    let region = JString::from(env.new_local_ref(region.as_obj()?)?);
+    Ok(env.get_string(&region)?.into())
+}
+
+/// Event called when a player enters a region of a [`RegionManager`].
+pub struct PlayerEnterRegionEvent {
+    inner: JavaObject,
+}
+
+impl PlayerEnterRegionEvent {
+    pub fn player(&self) -> Result<Player> {
+        event_player(&self.inner)
+    }
+
+    /// Name of the region
+    pub fn region(&self) -> Result<String> {
+        event_region(&self.inner)
+    }
+}
+
+impl Event for PlayerEnterRegionEvent {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn java_class_name() -> &'static str {
+        "rust/minestom/PlayerEnterRegionEvent"
+    }
+
+    fn new(inner: JavaObject) -> Self {
+        Self { inner }
+    }
+}
+
+/// Event called when a player leaves a region of a [`RegionManager`],
+/// or when the region is removed while they are inside.
+pub struct PlayerLeaveRegionEvent {
+    inner: JavaObject,
+}
+
+impl PlayerLeaveRegionEvent {
+    pub fn player(&self) -> Result<Player> {
+        event_player(&self.inner)
+    }
+
+    /// Name of the region
+    pub fn region(&self) -> Result<String> {
+        event_region(&self.inner)
+    }
+}
+
+impl Event for PlayerLeaveRegionEvent {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn java_class_name() -> &'static str {
+        "rust/minestom/PlayerLeaveRegionEvent"
+    }
+
+    fn new(inner: JavaObject) -> Self {
+        Self { inner }
+    }
+}
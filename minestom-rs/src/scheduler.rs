@@ -275,6 +275,11 @@ impl TaskBuilder {
         Ok(self)
     }
 
+    /// Cancels the task, it will not run anymore
+    pub fn cancel(&self) -> Result<()> {
+        self.inner.call_void_method("cancel", "()V", &[])
+    }
+
     pub fn event_node(&self) -> Result<EventNode> {
         let mut env = get_env()?;
         let event_node = self.inner.call_object_method(
@@ -602,3 +607,70 @@ pub unsafe extern "system" fn Java_rust_minestom_TaskExecutorCallback_executeTas
 
     // The frame will be automatically popped when _frame is dropped
 }
+
+type Step = Box<dyn FnMut() -> Option<u32> + Send>;
+
+// Step functions of tasks submitted with `submit_stepped_task`
+static STEPPED_TASKS: Lazy<parking_lot::Mutex<HashMap<u64, Step>>> =
+    Lazy::new(|| parking_lot::Mutex::new(HashMap::new()));
+
+/// Submits a task calling `step` on the next tick, then again after the number of
+/// ticks it returns, until it returns `None`.
+pub(crate) fn submit_stepped_task<F>(step: F) -> Result<()>
+where
+    F: FnMut() -> Option<u32> + Send + 'static,
+{
+    let callback_id = NEXT_CALLBACK_ID.fetch_add(1, Ordering::SeqCst);
+    STEPPED_TASKS.lock().insert(callback_id, Box::new(step));
+
+    let mut env = get_env()?;
+    let supplier = env.new_object(
+        "rust/minestom/SteppedTaskCallback",
+        "(J)V",
+        &[JniValue::Long(callback_id as i64).as_jvalue()],
+    )?;
+    let scheduler = env
+        .call_static_method(
+            "net/minestom/server/MinecraftServer",
+            "getSchedulerManager",
+            "()Lnet/minestom/server/timer/SchedulerManager;",
+            &[],
+        )?
+        .l()?;
+    let submitted = env.call_method(
+        &scheduler,
+        "submitTask",
+        "(Ljava/util/function/Supplier;)Lnet/minestom/server/timer/Task;",
+        &[jni::objects::JValue::Object(&supplier)],
+    );
+    if let Err(e) = submitted {
+        STEPPED_TASKS.lock().remove(&callback_id);
+        return Err(e.into());
+    }
+    Ok(())
+}
+
+/// JNI callback: step() → ticks until the next step, negative to stop
+#[unsafe(no_mangle)]
+pub unsafe extern "system" fn Java_rust_minestom_SteppedTaskCallback_nativeStep(
+    _raw_env: *mut jni::sys::JNIEnv,
+    _class: jni::objects::JClass,
+    callback_id: jni::sys::jlong,
+) -> jni::sys::jint {
+    let id = callback_id as u64;
+    // Taken out of the registry while running, so that steps can submit other tasks
+    let Some(mut step) = STEPPED_TASKS.lock().remove(&id) else {
+        return -1;
+    };
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| step())) {
+        Ok(Some(ticks)) => {
+            STEPPED_TASKS.lock().insert(id, step);
+            ticks.min(i32::MAX as u32) as jni::sys::jint
+        }
+        Ok(None) => -1,
+        Err(_) => {
+            error!("Panic in stepped task {}", id);
+            -1
+        }
+    }
+}
@@ -0,0 +1,471 @@
+use crate::Result;
+use crate::batch::BlockBatch;
+use crate::block::{Block, parse_block_state};
+use crate::error::MinestomError;
+use crate::instance::Instance;
+use crate::nbt::Nbt;
+use log::debug;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Rotation applied when pasting a schematic, clockwise when seen from above.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rotation {
+    #[default]
+    None,
+    Clockwise90,
+    Clockwise180,
+    Clockwise270,
+}
+
+impl Rotation {
+    fn steps(&self) -> u8 {
+        match self {
+            Rotation::None => 0,
+            Rotation::Clockwise90 => 1,
+            Rotation::Clockwise180 => 2,
+            Rotation::Clockwise270 => 3,
+        }
+    }
+}
+
+/// Mirroring applied when pasting a schematic, before the rotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mirror {
+    #[default]
+    None,
+    /// Flips the X axis (east <-> west).
+    FrontBack,
+    /// Flips the Z axis (north <-> south).
+    LeftRight,
+}
+
+/// Options used by [`Schematic::paste`].
+#[derive(Debug, Clone, Default)]
+pub struct PasteOptions {
+    pub rotation: Rotation,
+    pub mirror: Mirror,
+    /// Skips air blocks so the schematic does not carve into existing terrain.
+    pub ignore_air: bool,
+}
+
+/// A block entity stored in a schematic, with coordinates relative to the schematic origin.
+#[derive(Debug, Clone)]
+pub struct BlockEntity {
+    pub id: String,
+    pub data: Nbt,
+}
+
+/// A Sponge schematic (`.schem`), versions 1 to 3.
+///
+/// The file is parsed entirely in Rust; the JVM is only needed to paste it.
+///
+/// # Example
+/// ```rust,no_run
+/// let schematic = Schematic::load("structures/arena.schem")?;
+/// schematic.paste(&instance, 0, 64, 0, &PasteOptions {
+///     rotation: Rotation::Clockwise90,
+///     ..Default::default()
+/// })?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct Schematic {
+    version: i32,
+    width: i32,
+    height: i32,
+    length: i32,
+    offset: (i32, i32, i32),
+    palette: Vec<String>,
+    blocks: Vec<u32>,
+    block_entities: HashMap<(i32, i32, i32), BlockEntity>,
+}
+
+impl Schematic {
+    /// Loads a schematic from a (usually gzip-compressed) file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let bytes = std::fs::read(path)?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Parses a schematic from raw file contents.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let (_, root) = Nbt::from_bytes(bytes)?;
+        // Version 3 wraps everything in a "Schematic" compound
+        let schematic = match root.get("Schematic") {
+            Some(inner @ Nbt::Compound(_)) => inner,
+            _ => &root,
+        };
+
+        let version = schematic.get("Version").and_then(Nbt::as_i32).unwrap_or(1);
+        let width = required_i32(schematic, "Width")? as u16 as i32;
+        let height = required_i32(schematic, "Height")? as u16 as i32;
+        let length = required_i32(schematic, "Length")? as u16 as i32;
+        let offset = match schematic.get("Offset").and_then(Nbt::as_int_array) {
+            Some([x, y, z]) => (*x, *y, *z),
+            _ => (0, 0, 0),
+        };
+
+        // Version 3 moved the palette and block data into a "Blocks" container
+        let container = if version >= 3 {
+            schematic
+                .get("Blocks")
+                .ok_or_else(|| schematic_error("missing Blocks container"))?
+        } else {
+            schematic
+        };
+        let palette_tag = container
+            .get("Palette")
+            .and_then(Nbt::as_compound)
+            .ok_or_else(|| schematic_error("missing Palette"))?;
+        let data_key = if version >= 3 { "Data" } else { "BlockData" };
+        let data = container
+            .get(data_key)
+            .and_then(Nbt::as_byte_array)
+            .ok_or_else(|| schematic_error("missing block data"))?;
+
+        let mut palette = vec![String::new(); palette_tag.len()];
+        for (state, index) in palette_tag {
+            let index = index
+                .as_i32()
+                .and_then(|i| usize::try_from(i).ok())
+                .filter(|i| *i < palette.len())
+                .ok_or_else(|| schematic_error(&format!("invalid palette index for {}", state)))?;
+            palette[index] = state.clone();
+        }
+
+        let volume = width
+            .checked_mul(height)
+            .and_then(|area| area.checked_mul(length))
+            .ok_or_else(|| {
+                schematic_error(&format!(
+                    "size {}x{}x{} is too large",
+                    width, height, length
+                ))
+            })? as usize;
+        let blocks = decode_varints(data, volume)?;
+        if let Some(bad) = blocks.iter().find(|id| **id as usize >= palette.len()) {
+            return Err(schematic_error(&format!("block id {} not in palette", bad)));
+        }
+
+        let entities_tag = container
+            .get("BlockEntities")
+            .or_else(|| container.get("TileEntities"))
+            .and_then(Nbt::as_list)
+            .unwrap_or(&[]);
+        let mut block_entities = HashMap::new();
+        for entity in entities_tag {
+            let Some(fields) = entity.as_compound() else {
+                continue;
+            };
+            let Some([x, y, z]) = entity.get("Pos").and_then(Nbt::as_int_array) else {
+                continue;
+            };
+            let id = entity
+                .get("Id")
+                .and_then(Nbt::as_str)
+                .unwrap_or_default()
+                .to_string();
+            let data = if version >= 3 {
+                entity
+                    .get("Data")
+                    .cloned()
+                    .unwrap_or_else(|| Nbt::Compound(HashMap::new()))
+            } else {
+                let mut fields = fields.clone();
+                fields.remove("Pos");
+                fields.remove("Id");
+                Nbt::Compound(fields)
+            };
+            block_entities.insert((*x, *y, *z), BlockEntity { id, data });
+        }
+
+        debug!(
+            "Parsed schematic v{} ({}x{}x{}, {} palette entries, {} block entities)",
+            version,
+            width,
+            height,
+            length,
+            palette.len(),
+            block_entities.len()
+        );
+
+        Ok(Self {
+            version,
+            width,
+            height,
+            length,
+            offset,
+            palette,
+            blocks,
+            block_entities,
+        })
+    }
+
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+
+    /// Size along the X axis.
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    /// Size along the Y axis.
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    /// Size along the Z axis.
+    pub fn length(&self) -> i32 {
+        self.length
+    }
+
+    /// Offset of the schematic relative to the paste position.
+    pub fn offset(&self) -> (i32, i32, i32) {
+        self.offset
+    }
+
+    /// Block states used by this schematic.
+    pub fn palette(&self) -> &[String] {
+        &self.palette
+    }
+
+    /// Block entities keyed by their position relative to the schematic origin.
+    pub fn block_entities(&self) -> &HashMap<(i32, i32, i32), BlockEntity> {
+        &self.block_entities
+    }
+
+    /// Gets the block state at a position relative to the schematic origin.
+    pub fn get_block(&self, x: i32, y: i32, z: i32) -> Option<&str> {
+        if x < 0 || y < 0 || z < 0 || x >= self.width || y >= self.height || z >= self.length {
+            return None;
+        }
+        let index = (x + z * self.width + y * self.width * self.length) as usize;
+        Some(&self.palette[self.blocks[index] as usize])
+    }
+
+    /// Builds a block batch placing this schematic at the given position.
+    pub fn to_batch(&self, x: i32, y: i32, z: i32, options: &PasteOptions) -> Result<BlockBatch> {
+        let batch = BlockBatch::new()?;
+
+        // Resolve every palette entry once, already rotated and mirrored
+        let mut resolved: Vec<Option<Block>> = Vec::with_capacity(self.palette.len());
+        for state in &self.palette {
+            let key = parse_block_state(state).0;
+            if key == "minecraft:structure_void" || (options.ignore_air && is_air(key)) {
+                resolved.push(None);
+            } else {
+                let state = transform_state(state, options.mirror, options.rotation);
+                resolved.push(Some(Block::from_state(&state)?));
+            }
+        }
+
+        for by in 0..self.height {
+            for bz in 0..self.length {
+                for bx in 0..self.width {
+                    let index = (bx + bz * self.width + by * self.width * self.length) as usize;
+                    let Some(block) = &resolved[self.blocks[index] as usize] else {
+                        continue;
+                    };
+                    let (dx, dy, dz) = transform_position(
+                        (bx + self.offset.0, by + self.offset.1, bz + self.offset.2),
+                        options.mirror,
+                        options.rotation,
+                    );
+
+                    match self.block_entities.get(&(bx, by, bz)) {
+                        Some(entity) => {
+                            let block = block.with_nbt(&entity.data)?;
+                            batch.set_block(x + dx, y + dy, z + dz, &block)?;
+                        }
+                        None => batch.set_block(x + dx, y + dy, z + dz, block)?,
+                    }
+                }
+            }
+        }
+
+        Ok(batch)
+    }
+
+    /// Pastes this schematic into an instance at the given position.
+    pub fn paste(
+        &self,
+        instance: &dyn Instance,
+        x: i32,
+        y: i32,
+        z: i32,
+        options: &PasteOptions,
+    ) -> Result<()> {
+        self.to_batch(x, y, z, options)?.apply(instance)
+    }
+}
+
+fn schematic_error(message: &str) -> MinestomError {
+    MinestomError::Schematic(message.to_string())
+}
+
+fn required_i32(tag: &Nbt, key: &str) -> Result<i32> {
+    tag.get(key)
+        .and_then(Nbt::as_i32)
+        .ok_or_else(|| schematic_error(&format!("missing {}", key)))
+}
+
+fn is_air(key: &str) -> bool {
+    matches!(
+        key,
+        "minecraft:air" | "minecraft:cave_air" | "minecraft:void_air"
+    )
+}
+
+/// Decodes the varint-encoded palette indices of the block data array.
+fn decode_varints(data: &[i8], expected: usize) -> Result<Vec<u32>> {
+    // Every value takes at least one byte, do not trust the size on its own
+    let mut values = Vec::with_capacity(expected.min(data.len()));
+    let mut value: u32 = 0;
+    let mut shift = 0;
+    for byte in data.iter().map(|b| *b as u8) {
+        value |= ((byte & 0x7f) as u32) << shift;
+        if byte & 0x80 == 0 {
+            values.push(value);
+            value = 0;
+            shift = 0;
+        } else {
+            shift += 7;
+            if shift > 28 {
+                return Err(schematic_error("varint too long in block data"));
+            }
+        }
+    }
+    if values.len() != expected {
+        return Err(schematic_error(&format!(
+            "expected {} blocks, found {}",
+            expected,
+            values.len()
+        )));
+    }
+    Ok(values)
+}
+
+fn transform_position(
+    (mut x, y, mut z): (i32, i32, i32),
+    mirror: Mirror,
+    rotation: Rotation,
+) -> (i32, i32, i32) {
+    match mirror {
+        Mirror::None => {}
+        Mirror::FrontBack => x = -x,
+        Mirror::LeftRight => z = -z,
+    }
+    for _ in 0..rotation.steps() {
+        (x, z) = (-z, x);
+    }
+    (x, y, z)
+}
+
+const HORIZONTAL: [&str; 4] = ["north", "east", "south", "west"];
+
+fn transform_direction(direction: &str, mirror: Mirror, rotation: Rotation) -> String {
+    let Some(mut index) = HORIZONTAL.iter().position(|d| *d == direction) else {
+        return direction.to_string();
+    };
+    index = match (mirror, index) {
+        (Mirror::FrontBack, 1) => 3,
+        (Mirror::FrontBack, 3) => 1,
+        (Mirror::LeftRight, 0) => 2,
+        (Mirror::LeftRight, 2) => 0,
+        _ => index,
+    };
+    HORIZONTAL[(index + rotation.steps() as usize) % 4].to_string()
+}
+
+/// Swaps the sides of stair shapes, door hinges and double chests.
+fn swap_left_right(value: &str) -> String {
+    if value.contains("left") {
+        value.replace("left", "right")
+    } else {
+        value.replace("right", "left")
+    }
+}
+
+/// Rewrites the shape of a rail, e.g. `north_east` or `ascending_west`.
+/// Returns `None` for other shapes, such as the ones of stairs.
+fn transform_rail_shape(shape: &str, mirror: Mirror, rotation: Rotation) -> Option<String> {
+    if let Some(direction) = shape.strip_prefix("ascending_") {
+        let direction = transform_direction(direction, mirror, rotation);
+        return Some(format!("ascending_{}", direction));
+    }
+    let (a, b) = shape.split_once('_')?;
+    if !HORIZONTAL.contains(&a) || !HORIZONTAL.contains(&b) {
+        return None;
+    }
+    // Shapes name north and south first, e.g. `north_south` and `south_west`
+    const ORDER: [&str; 4] = ["north", "south", "east", "west"];
+    let mut directions = [
+        transform_direction(a, mirror, rotation),
+        transform_direction(b, mirror, rotation),
+    ];
+    directions.sort_by_key(|d| ORDER.iter().position(|o| o == d));
+    Some(directions.join("_"))
+}
+
+/// Rewrites the direction-dependent properties of a block state.
+fn transform_state(state: &str, mirror: Mirror, rotation: Rotation) -> String {
+    let (key, properties) = parse_block_state(state);
+    if properties.is_empty() || (mirror == Mirror::None && rotation == Rotation::None) {
+        return state.to_string();
+    }
+    let mirrored = mirror != Mirror::None;
+
+    let properties: Vec<(String, String)> = properties
+        .into_iter()
+        .map(|(name, value)| match name.as_str() {
+            "facing" => {
+                let value = transform_direction(&value, mirror, rotation);
+                (name, value)
+            }
+            "axis" if rotation.steps() % 2 == 1 => {
+                let value = match value.as_str() {
+                    "x" => "z".to_string(),
+                    "z" => "x".to_string(),
+                    _ => value,
+                };
+                (name, value)
+            }
+            "rotation" => {
+                let mut r: i32 = value.parse().unwrap_or(0);
+                r = match mirror {
+                    Mirror::None => r,
+                    Mirror::FrontBack => 16 - r,
+                    Mirror::LeftRight => 8 - r,
+                };
+                r = (r + 4 * rotation.steps() as i32).rem_euclid(16);
+                (name, r.to_string())
+            }
+            "shape" => {
+                let value = match transform_rail_shape(&value, mirror, rotation) {
+                    Some(shape) => shape,
+                    None if mirrored => swap_left_right(&value),
+                    None => value,
+                };
+                (name, value)
+            }
+            // Doors, and the halves of double chests
+            "hinge" | "type" if mirrored => {
+                let value = swap_left_right(&value);
+                (name, value)
+            }
+            // Connection properties of fences, walls, panes and redstone
+            "north" | "east" | "south" | "west" => {
+                let name = transform_direction(&name, mirror, rotation);
+                (name, value)
+            }
+            _ => (name, value),
+        })
+        .collect();
+
+    let properties: Vec<String> = properties
+        .iter()
+        .map(|(name, value)| format!("{}={}", name, value))
+        .collect();
+    format!("{}[{}]", key, properties.join(","))
+}
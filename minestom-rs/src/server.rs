@@ -1,5 +1,6 @@
 use crate::Result;
 use crate::advancement::AdvancementManager;
+use crate::block_handler::BlockManager;
 use crate::command::CommandManager;
 use crate::entity::Player;
 use crate::event::EventNode;
@@ -177,4 +178,19 @@ impl MinestomServer {
             scheduler_manager_global,
         )))
     }
+
+    /// Gets the block manager for registering block handlers
+    pub fn block_manager(&self) -> Result<BlockManager> {
+        let mut env = get_env()?;
+        let server_class = env.find_class("net/minestom/server/MinecraftServer")?;
+        let block_manager = env.call_static_method(
+            &server_class,
+            "getBlockManager",
+            "()Lnet/minestom/server/instance/block/BlockManager;",
+            &[],
+        )?;
+        let block_manager_obj = block_manager.l()?;
+        let block_manager_global = env.new_global_ref(block_manager_obj)?;
+        Ok(BlockManager::new(JavaObject::new(block_manager_global)))
+    }
 }
@@ -1,92 +1,837 @@
 use crate::Result;
-use crate::jni_utils::{JavaObject, get_env};
+use crate::error::MinestomError;
+use crate::item::ItemStack;
+use crate::jni_utils::{JavaObject, JniValue, get_env};
+use crate::nbt::Nbt;
+use crate::text::Component;
 use jni::objects::{JObject, JString, JValue};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde::ser;
+use serde_json::{Map, Number, Value};
+use std::collections::HashMap;
+use uuid::Uuid;
 
-/// Handler for entity tags (String-keyed, String-valued tags).
+/// A value which can be stored in a [`TagHandler`] with [`Tag::new`].
+///
+/// Implemented for `i32`, `i64`, `f32`, `f64`, `bool`, `String`, `Uuid`, [`ItemStack`],
+/// [`Component`], nested [`TagHandler`] structures and lists of them.
+pub trait TagValue: Sized {
+    /// Creates the Java `Tag` for this type
+    #[doc(hidden)]
+    fn java_tag(name: &str) -> Result<JavaObject>;
+
+    #[doc(hidden)]
+    fn to_java(&self) -> Result<JavaObject>;
+
+    #[doc(hidden)]
+    fn from_java(value: JavaObject) -> Result<Self>;
+}
+
+/// A typed key to read and write values in a [`TagHandler`] or on an [`ItemStack`].
+///
+/// # Example
+/// ```rust,no_run
+/// #[derive(Serialize, Deserialize)]
+/// struct Stats {
+///     games: u32,
+///     best_time: Option<f64>,
+/// }
+///
+/// let handler = player.tag_handler()?;
+/// let kills = Tag::<i32>::new("kills");
+/// handler.set(&kills, &(handler.get(&kills)?.unwrap_or(0) + 1))?;
+/// handler.set(&Tag::serde("stats"), &Stats { games: 3, best_time: None })?;
+/// ```
+pub struct Tag<T> {
+    name: String,
+    java_tag: fn(&str) -> Result<JavaObject>,
+    to_java: fn(&T) -> Result<JavaObject>,
+    from_java: fn(JavaObject) -> Result<T>,
+}
+
+impl<T> Clone for Tag<T> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            java_tag: self.java_tag,
+            to_java: self.to_java,
+            from_java: self.from_java,
+        }
+    }
+}
+
+impl<T: TagValue> Tag<T> {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            java_tag: T::java_tag,
+            to_java: T::to_java,
+            from_java: T::from_java,
+        }
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> Tag<T> {
+    /// Creates a tag storing any serde value as an NBT compound.
+    ///
+    /// `T` must serialize as a struct or a map. Writing fails on NaN or infinite floats,
+    /// `None` inside a list and integers above `i64::MAX`, which NBT cannot hold.
+    /// `None` fields are left out and read back as `None`.
+    pub fn serde(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            java_tag: nbt_tag,
+            to_java: serde_to_java::<T>,
+            from_java: serde_from_java::<T>,
+        }
+    }
+}
+
+impl<T> Tag<T> {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn java_tag(&self) -> Result<JavaObject> {
+        (self.java_tag)(&self.name)
+    }
+
+    pub(crate) fn to_java(&self, value: &T) -> Result<JavaObject> {
+        (self.to_java)(value)
+    }
+
+    /// Converts the value returned by `getTag`, `None` if it is null
+    pub(crate) fn read_java(&self, value: JavaObject) -> Result<Option<T>> {
+        if value.as_obj()?.is_null() {
+            return Ok(None);
+        }
+        (self.from_java)(value).map(Some)
+    }
+}
+
+/// Handler for the tags of an entity, an instance or a nested structure.
 pub struct TagHandler {
     pub(crate) inner: JavaObject,
 }
 
 impl TagHandler {
-    /// Internal helper: create a net.minestom.server.tag.Tag<String> from a Rust &str.
-    fn make_tag(&self, env: &mut jni::JNIEnv, name: &str) -> Result<JavaObject> {
-        let jname = env.new_string(name)?;
-        let tag_obj = env
+    /// Creates an empty handler, to be stored as a nested structure in another one.
+    pub fn new() -> Result<Self> {
+        let mut env = get_env()?;
+        let handler = env
             .call_static_method(
-                "net/minestom/server/tag/Tag",
-                "String",
-                "(Ljava/lang/String;)Lnet/minestom/server/tag/Tag;",
-                &[JValue::Object(&JObject::from(jname))],
+                "net/minestom/server/tag/TagHandler",
+                "newHandler",
+                "()Lnet/minestom/server/tag/TagHandler;",
+                &[],
             )?
             .l()?;
-        Ok(JavaObject::from_env(env, tag_obj)?)
+        Ok(Self {
+            inner: JavaObject::from_env(&mut env, handler)?,
+        })
     }
 
-    /// Reads the specified tag. Returns Some(value) or None if not present.
-    pub fn get_tag(&self, key: &str) -> Result<Option<String>> {
-        let mut env = get_env()?;
-        let tag = self.make_tag(&mut env, key)?;
-        let result = env.call_method(
-            self.inner.as_obj()?,
+    /// Reads the value of `tag`, `None` if it is not set.
+    pub fn get<T>(&self, tag: &Tag<T>) -> Result<Option<T>> {
+        let value = self.inner.call_object_method(
             "getTag",
             "(Lnet/minestom/server/tag/Tag;)Ljava/lang/Object;",
-            &[JValue::Object(&tag.as_obj()?)],
+            &[JniValue::Object(tag.java_tag()?.as_obj()?)],
         )?;
-        let obj = result.l()?;
-        if obj.is_null() {
-            Ok(None)
-        } else {
-            let jstr = JString::from(obj);
-            Ok(Some(env.get_string(&jstr)?.into()))
-        }
+        tag.read_java(value)
+    }
+
+    pub fn set<T>(&self, tag: &Tag<T>, value: &T) -> Result<()> {
+        self.inner.call_void_method(
+            "setTag",
+            "(Lnet/minestom/server/tag/Tag;Ljava/lang/Object;)V",
+            &[
+                JniValue::Object(tag.java_tag()?.as_obj()?),
+                JniValue::Object(tag.to_java(value)?.as_obj()?),
+            ],
+        )
+    }
+
+    pub fn has<T>(&self, tag: &Tag<T>) -> Result<bool> {
+        self.inner.call_bool_method(
+            "hasTag",
+            "(Lnet/minestom/server/tag/Tag;)Z",
+            &[JniValue::Object(tag.java_tag()?.as_obj()?)],
+        )
+    }
+
+    pub fn remove<T>(&self, tag: &Tag<T>) -> Result<()> {
+        self.inner.call_void_method(
+            "removeTag",
+            "(Lnet/minestom/server/tag/Tag;)V",
+            &[JniValue::Object(tag.java_tag()?.as_obj()?)],
+        )
     }
 
-    /// Returns true if the specified tag is present.
+    /// Reads the specified string tag. Returns Some(value) or None if not present.
+    pub fn get_tag(&self, key: &str) -> Result<Option<String>> {
+        self.get(&Tag::<String>::new(key))
+    }
+
+    /// Returns true if the specified string tag is present.
     pub fn has_tag(&self, key: &str) -> Result<bool> {
+        self.has(&Tag::<String>::new(key))
+    }
+
+    /// Writes the specified string tag value; None to remove.
+    pub fn set_tag(&self, key: &str, value: Option<&str>) -> Result<()> {
+        let tag = Tag::<String>::new(key);
+        match value {
+            Some(value) => self.set(&tag, &value.to_string()),
+            None => self.remove(&tag),
+        }
+    }
+
+    /// Removes the specified string tag.
+    pub fn remove_tag(&self, key: &str) -> Result<()> {
+        self.remove(&Tag::<String>::new(key))
+    }
+
+    /// Gets a copy of all the tags as NBT
+    pub fn to_nbt(&self) -> Result<Nbt> {
+        let compound = self.as_compound()?;
+        Nbt::from_java_compound(&compound)
+    }
+
+    fn as_compound(&self) -> Result<JavaObject> {
+        self.inner.call_object_method(
+            "asCompound",
+            "()Lnet/kyori/adventure/nbt/CompoundBinaryTag;",
+            &[],
+        )
+    }
+}
+
+fn simple_tag(factory: &str, name: &str) -> Result<JavaObject> {
+    let mut env = get_env()?;
+    let name = env.new_string(name)?;
+    let tag = env
+        .call_static_method(
+            "net/minestom/server/tag/Tag",
+            factory,
+            "(Ljava/lang/String;)Lnet/minestom/server/tag/Tag;",
+            &[JValue::Object(&name)],
+        )?
+        .l()?;
+    JavaObject::from_env(&mut env, tag)
+}
+
+/// Boxes a primitive with `valueOf`, e.g. an `int` into an `Integer`
+fn boxed(class: &str, sig: &str, value: JValue) -> Result<JavaObject> {
+    let mut env = get_env()?;
+    let boxed = env
+        .call_static_method(class, "valueOf", sig, &[value])?
+        .l()?;
+    JavaObject::from_env(&mut env, boxed)
+}
+
+impl TagValue for i32 {
+    fn java_tag(name: &str) -> Result<JavaObject> {
+        simple_tag("Integer", name)
+    }
+
+    fn to_java(&self) -> Result<JavaObject> {
+        boxed(
+            "java/lang/Integer",
+            "(I)Ljava/lang/Integer;",
+            JValue::Int(*self),
+        )
+    }
+
+    fn from_java(value: JavaObject) -> Result<Self> {
+        value.call_int_method("intValue", "()I", &[])
+    }
+}
+
+impl TagValue for i64 {
+    fn java_tag(name: &str) -> Result<JavaObject> {
+        simple_tag("Long", name)
+    }
+
+    fn to_java(&self) -> Result<JavaObject> {
+        boxed("java/lang/Long", "(J)Ljava/lang/Long;", JValue::Long(*self))
+    }
+
+    fn from_java(value: JavaObject) -> Result<Self> {
         let mut env = get_env()?;
-        let tag = self.make_tag(&mut env, key)?;
-        let flag = env
-            .call_method(
-                self.inner.as_obj()?,
-                "hasTag",
-                "(Lnet/minestom/server/tag/Tag;)Z",
-                &[JValue::Object(&tag.as_obj()?)],
+        Ok(env
+            .call_method(value.as_obj()?, "longValue", "()J", &[])?
+            .j()?)
+    }
+}
+
+impl TagValue for f32 {
+    fn java_tag(name: &str) -> Result<JavaObject> {
+        simple_tag("Float", name)
+    }
+
+    fn to_java(&self) -> Result<JavaObject> {
+        boxed(
+            "java/lang/Float",
+            "(F)Ljava/lang/Float;",
+            JValue::Float(*self),
+        )
+    }
+
+    fn from_java(value: JavaObject) -> Result<Self> {
+        value.call_float_method("floatValue", "()F", &[])
+    }
+}
+
+impl TagValue for f64 {
+    fn java_tag(name: &str) -> Result<JavaObject> {
+        simple_tag("Double", name)
+    }
+
+    fn to_java(&self) -> Result<JavaObject> {
+        boxed(
+            "java/lang/Double",
+            "(D)Ljava/lang/Double;",
+            JValue::Double(*self),
+        )
+    }
+
+    fn from_java(value: JavaObject) -> Result<Self> {
+        value.call_double_method("doubleValue", "()D", &[])
+    }
+}
+
+impl TagValue for bool {
+    fn java_tag(name: &str) -> Result<JavaObject> {
+        simple_tag("Boolean", name)
+    }
+
+    fn to_java(&self) -> Result<JavaObject> {
+        boxed(
+            "java/lang/Boolean",
+            "(Z)Ljava/lang/Boolean;",
+            JValue::Bool(*self as u8),
+        )
+    }
+
+    fn from_java(value: JavaObject) -> Result<Self> {
+        value.call_bool_method("booleanValue", "()Z", &[])
+    }
+}
+
+impl TagValue for String {
+    fn java_tag(name: &str) -> Result<JavaObject> {
+        simple_tag("String", name)
+    }
+
+    fn to_java(&self) -> Result<JavaObject> {
+        let mut env = get_env()?;
+        let string = env.new_string(self)?;
+        JavaObject::from_env(&mut env, JObject::from(string))
+    }
+
+    fn from_java(value: JavaObject) -> Result<Self> {
+        let mut env = get_env()?;
+        let string = JString::from(value.as_obj()?);
+        Ok(env.get_string(&string)?.into())
+    }
+}
+
+impl TagValue for Uuid {
+    fn java_tag(name: &str) -> Result<JavaObject> {
+        simple_tag("UUID", name)
+    }
+
+    fn to_java(&self) -> Result<JavaObject> {
+        let mut env = get_env()?;
+        let string = env.new_string(self.to_string())?;
+        let uuid = env
+            .call_static_method(
+                "java/util/UUID",
+                "fromString",
+                "(Ljava/lang/String;)Ljava/util/UUID;",
+                &[JValue::Object(&string)],
             )?
-            .z()?;
-        Ok(flag)
+            .l()?;
+        JavaObject::from_env(&mut env, uuid)
     }
 
-    /// Writes the specified tag value; None to remove.
-    pub fn set_tag(&self, key: &str, value: Option<&str>) -> Result<()> {
+    fn from_java(value: JavaObject) -> Result<Self> {
+        let mut env = get_env()?;
+        let string = value.call_object_method("toString", "()Ljava/lang/String;", &[])?;
+        let string: String = env.get_string(&JString::from(string.as_obj()?))?.into();
+        Ok(Uuid::parse_str(&string)?)
+    }
+}
+
+impl TagValue for ItemStack {
+    fn java_tag(name: &str) -> Result<JavaObject> {
+        simple_tag("ItemStack", name)
+    }
+
+    fn to_java(&self) -> Result<JavaObject> {
+        Ok(self.as_obj().clone())
+    }
+
+    fn from_java(value: JavaObject) -> Result<Self> {
+        Ok(ItemStack::from_java(value))
+    }
+}
+
+impl TagValue for Component {
+    fn java_tag(name: &str) -> Result<JavaObject> {
+        simple_tag("Component", name)
+    }
+
+    fn to_java(&self) -> Result<JavaObject> {
+        Ok(self.as_obj().clone())
+    }
+
+    fn from_java(value: JavaObject) -> Result<Self> {
+        Ok(Component::from_java_object(value))
+    }
+}
+
+/// A nested structure, stored with `Tag.Structure`.
+///
+/// The value read is a copy: changes are saved by setting the structure again.
+impl TagValue for TagHandler {
+    fn java_tag(name: &str) -> Result<JavaObject> {
         let mut env = get_env()?;
-        let tag = self.make_tag(&mut env, key)?;
-        // Prepare the Java value
-        let jobject_val = if let Some(s) = value {
-            // New Java String
-            let js = env.new_string(s)?;
-            JObject::from(js)
+        let name = env.new_string(name)?;
+        let serializer = env
+            .get_static_field(
+                "net/minestom/server/tag/TagSerializer",
+                "COMPOUND",
+                "Lnet/minestom/server/tag/TagSerializer;",
+            )?
+            .l()?;
+        let tag = env
+            .call_static_method(
+                "net/minestom/server/tag/Tag",
+                "Structure",
+                "(Ljava/lang/String;Lnet/minestom/server/tag/TagSerializer;)Lnet/minestom/server/tag/Tag;",
+                &[JValue::Object(&name), JValue::Object(&serializer)],
+            )?
+            .l()?;
+        JavaObject::from_env(&mut env, tag)
+    }
+
+    fn to_java(&self) -> Result<JavaObject> {
+        self.as_compound()
+    }
+
+    fn from_java(value: JavaObject) -> Result<Self> {
+        let mut env = get_env()?;
+        let handler = env
+            .call_static_method(
+                "net/minestom/server/tag/TagHandler",
+                "fromCompound",
+                "(Lnet/kyori/adventure/nbt/CompoundBinaryTag;)Lnet/minestom/server/tag/TagHandler;",
+                &[JValue::Object(&value.as_obj()?)],
+            )?
+            .l()?;
+        Ok(Self {
+            inner: JavaObject::from_env(&mut env, handler)?,
+        })
+    }
+}
+
+impl<T: TagValue> TagValue for Vec<T> {
+    fn java_tag(name: &str) -> Result<JavaObject> {
+        T::java_tag(name)?.call_object_method("list", "()Lnet/minestom/server/tag/Tag;", &[])
+    }
+
+    fn to_java(&self) -> Result<JavaObject> {
+        let mut env = get_env()?;
+        let list = env.new_object("java/util/ArrayList", "()V", &[])?;
+        for element in self {
+            env.call_method(
+                &list,
+                "add",
+                "(Ljava/lang/Object;)Z",
+                &[JValue::Object(&element.to_java()?.as_obj()?)],
+            )?;
+        }
+        JavaObject::from_env(&mut env, list)
+    }
+
+    fn from_java(value: JavaObject) -> Result<Self> {
+        let size = value.call_int_method("size", "()I", &[])?;
+        let mut list = Vec::with_capacity(size as usize);
+        for i in 0..size {
+            let element =
+                value.call_object_method("get", "(I)Ljava/lang/Object;", &[JniValue::Int(i)])?;
+            list.push(T::from_java(element)?);
+        }
+        Ok(list)
+    }
+}
+
+fn nbt_tag(name: &str) -> Result<JavaObject> {
+    simple_tag("NBT", name)
+}
+
+fn serde_to_java<T: Serialize>(value: &T) -> Result<JavaObject> {
+    // serde_json turns NaN and infinities into null, which would be silently dropped
+    value
+        .serialize(FiniteCheck)
+        .map_err(|e| MinestomError::Nbt(e.to_string()))?;
+    let json = serde_json::to_value(value).map_err(|e| MinestomError::Nbt(e.to_string()))?;
+    match json_to_nbt(json)? {
+        Some(nbt @ Nbt::Compound(_)) => nbt.to_java_compound(),
+        _ => Err(MinestomError::Nbt(
+            "serde tags must be serialized as a struct or a map".to_string(),
+        )),
+    }
+}
+
+fn serde_from_java<T: DeserializeOwned>(value: JavaObject) -> Result<T> {
+    let json = nbt_to_json(Nbt::from_java_compound(&value)?);
+    serde_json::from_value(json).map_err(|e| MinestomError::Nbt(e.to_string()))
+}
+
+/// Converts a serialized value into NBT, `None` for null values which NBT cannot hold.
+/// Booleans become bytes, integers become ints or longs when they do not fit.
+/// Numbers of a list are promoted to the widest type among them.
+fn json_to_nbt(value: Value) -> Result<Option<Nbt>> {
+    Ok(Some(match value {
+        Value::Null => return Ok(None),
+        Value::Bool(b) => Nbt::Byte(b as i8),
+        Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                match i32::try_from(i) {
+                    Ok(i) => Nbt::Int(i),
+                    Err(_) => Nbt::Long(i),
+                }
+            } else if let Some(f) = n.as_f64().filter(|_| n.is_f64()) {
+                Nbt::Double(f)
+            } else {
+                // Stored as a double, it would fail to deserialize back into an integer
+                return Err(MinestomError::Nbt(format!("{} does not fit in a long", n)));
+            }
+        }
+        Value::String(s) => Nbt::String(s),
+        Value::Array(values) => {
+            let mut list = Vec::with_capacity(values.len());
+            for value in values {
+                match json_to_nbt(value)? {
+                    Some(nbt) => list.push(nbt),
+                    None => {
+                        return Err(MinestomError::Nbt(
+                            "lists cannot contain null values".to_string(),
+                        ));
+                    }
+                }
+            }
+            unify_numbers(&mut list);
+            Nbt::List(list)
+        }
+        Value::Object(map) => {
+            let mut compound = HashMap::with_capacity(map.len());
+            for (key, value) in map {
+                // Missing keys are read back as `None`
+                if let Some(nbt) = json_to_nbt(value)? {
+                    compound.insert(key, nbt);
+                }
+            }
+            Nbt::Compound(compound)
+        }
+    }))
+}
+
+/// Promotes ints to longs and integers to doubles when a list mixes them,
+/// as NBT lists hold a single type.
+fn unify_numbers(list: &mut [Nbt]) {
+    let (mut longs, mut doubles) = (false, false);
+    for nbt in list.iter() {
+        match nbt {
+            Nbt::Int(_) => {}
+            Nbt::Long(_) => longs = true,
+            Nbt::Double(_) => doubles = true,
+            _ => return,
+        }
+    }
+    for nbt in list.iter_mut() {
+        match *nbt {
+            Nbt::Int(i) if doubles => *nbt = Nbt::Double(i as f64),
+            Nbt::Long(l) if doubles => *nbt = Nbt::Double(l as f64),
+            Nbt::Int(i) if longs => *nbt = Nbt::Long(i as i64),
+            _ => {}
+        }
+    }
+}
+
+/// Converts NBT back into a value to deserialize, bytes of 0 and 1 being booleans.
+fn nbt_to_json(nbt: Nbt) -> Value {
+    let number = |f: f64| Number::from_f64(f).map_or(Value::Null, Value::Number);
+    match nbt {
+        Nbt::Byte(b @ (0 | 1)) => Value::Bool(b == 1),
+        Nbt::Byte(b) => Value::from(b),
+        Nbt::Short(s) => Value::from(s),
+        Nbt::Int(i) => Value::from(i),
+        Nbt::Long(l) => Value::from(l),
+        Nbt::Float(f) => number(f as f64),
+        Nbt::Double(d) => number(d),
+        Nbt::String(s) => Value::String(s),
+        Nbt::ByteArray(values) => values.into_iter().map(Value::from).collect(),
+        Nbt::IntArray(values) => values.into_iter().map(Value::from).collect(),
+        Nbt::LongArray(values) => values.into_iter().map(Value::from).collect(),
+        Nbt::List(values) => values.into_iter().map(nbt_to_json).collect(),
+        Nbt::Compound(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, value)| (key, nbt_to_json(value)))
+                .collect::<Map<_, _>>(),
+        ),
+    }
+}
+
+type CheckResult = std::result::Result<(), serde_json::Error>;
+
+/// Serializer only failing on non-finite floats, which NBT tags cannot hold.
+struct FiniteCheck;
+
+impl FiniteCheck {
+    fn check(value: f64) -> CheckResult {
+        if value.is_finite() {
+            Ok(())
         } else {
-            JObject::null()
-        };
-        env.call_method(
-            self.inner.as_obj()?,
-            "setTag",
-            "(Lnet/minestom/server/tag/Tag;Ljava/lang/Object;)V",
-            &[JValue::Object(&tag.as_obj()?), JValue::Object(&jobject_val)],
-        )?;
+            Err(ser::Error::custom(format!(
+                "{} cannot be stored in a tag",
+                value
+            )))
+        }
+    }
+}
+
+macro_rules! accept {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(fn $method(self, _: $ty) -> CheckResult {
+            Ok(())
+        })*
+    };
+}
+
+impl ser::Serializer for FiniteCheck {
+    type Ok = ();
+    type Error = serde_json::Error;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    accept!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+        serialize_unit_struct(&'static str),
+    );
+
+    fn serialize_f32(self, v: f32) -> CheckResult {
+        Self::check(v as f64)
+    }
+
+    fn serialize_f64(self, v: f64) -> CheckResult {
+        Self::check(v)
+    }
+
+    fn serialize_none(self) -> CheckResult {
         Ok(())
     }
 
-    /// Removes the specified tag.
-    pub fn remove_tag(&self, key: &str) -> Result<()> {
-        let mut env = get_env()?;
-        let tag = self.make_tag(&mut env, key)?;
-        env.call_method(
-            self.inner.as_obj()?,
-            "removeTag",
-            "(Lnet/minestom/server/tag/Tag;)V",
-            &[JValue::Object(&tag.as_obj()?)],
-        )?;
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> CheckResult {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> CheckResult {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(self, _: &'static str, _: u32, _: &'static str) -> CheckResult {
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> CheckResult {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        value: &T,
+    ) -> CheckResult {
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> std::result::Result<Self, serde_json::Error> {
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _: usize) -> std::result::Result<Self, serde_json::Error> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> std::result::Result<Self, serde_json::Error> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> std::result::Result<Self, serde_json::Error> {
+        Ok(self)
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> std::result::Result<Self, serde_json::Error> {
+        Ok(self)
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> std::result::Result<Self, serde_json::Error> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> std::result::Result<Self, serde_json::Error> {
+        Ok(self)
+    }
+}
+
+impl ser::SerializeSeq for FiniteCheck {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> CheckResult {
+        value.serialize(FiniteCheck)
+    }
+
+    fn end(self) -> CheckResult {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTuple for FiniteCheck {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> CheckResult {
+        value.serialize(FiniteCheck)
+    }
+
+    fn end(self) -> CheckResult {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleStruct for FiniteCheck {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> CheckResult {
+        value.serialize(FiniteCheck)
+    }
+
+    fn end(self) -> CheckResult {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleVariant for FiniteCheck {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> CheckResult {
+        value.serialize(FiniteCheck)
+    }
+
+    fn end(self) -> CheckResult {
+        Ok(())
+    }
+}
+
+impl ser::SerializeMap for FiniteCheck {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> CheckResult {
+        key.serialize(FiniteCheck)
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> CheckResult {
+        value.serialize(FiniteCheck)
+    }
+
+    fn end(self) -> CheckResult {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStruct for FiniteCheck {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        _: &'static str,
+        value: &T,
+    ) -> CheckResult {
+        value.serialize(FiniteCheck)
+    }
+
+    fn end(self) -> CheckResult {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStructVariant for FiniteCheck {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        _: &'static str,
+        value: &T,
+    ) -> CheckResult {
+        value.serialize(FiniteCheck)
+    }
+
+    fn end(self) -> CheckResult {
         Ok(())
     }
 }
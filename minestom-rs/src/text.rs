@@ -18,6 +18,10 @@ impl Component {
         Self { inner }
     }
 
+    pub(crate) fn as_obj(&self) -> &JavaObject {
+        &self.inner
+    }
+
     pub fn text(content: &str) -> Result<Self> {
         let mut env = get_env()?;
         let component_class = env.find_class("net/kyori/adventure/text/Component")?;
@@ -0,0 +1,55 @@
+use jni::objects::JValue;
+
+use crate::Result;
+use crate::jni_utils::{JavaObject, get_env};
+
+/// Rain and thunder levels of an instance, each between 0 and 1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Weather {
+    pub rain_level: f32,
+    pub thunder_level: f32,
+}
+
+impl Weather {
+    pub const CLEAR: Weather = Weather::new(0.0, 0.0);
+    pub const RAIN: Weather = Weather::new(1.0, 0.0);
+    pub const THUNDER: Weather = Weather::new(1.0, 1.0);
+
+    pub const fn new(rain_level: f32, thunder_level: f32) -> Self {
+        Self {
+            rain_level,
+            thunder_level,
+        }
+    }
+
+    pub fn is_raining(&self) -> bool {
+        self.rain_level > 0.0
+    }
+
+    pub(crate) fn from_java(weather: &JavaObject) -> Result<Self> {
+        let mut env = get_env()?;
+        let obj = weather.as_obj()?;
+        let rain_level = env.call_method(&obj, "rainLevel", "()F", &[])?.f()?;
+        let thunder_level = env.call_method(&obj, "thunderLevel", "()F", &[])?.f()?;
+        Ok(Self::new(rain_level, thunder_level))
+    }
+
+    pub(crate) fn to_java(&self) -> Result<JavaObject> {
+        let mut env = get_env()?;
+        let weather = env.new_object(
+            "net/minestom/server/instance/Weather",
+            "(FF)V",
+            &[
+                JValue::Float(self.rain_level),
+                JValue::Float(self.thunder_level),
+            ],
+        )?;
+        JavaObject::from_env(&mut env, weather)
+    }
+}
+
+impl Default for Weather {
+    fn default() -> Self {
+        Self::CLEAR
+    }
+}
@@ -0,0 +1,169 @@
+use std::time::Duration;
+
+use jni::objects::{JObject, JValue};
+
+use crate::Result;
+use crate::entity::SendablePacket;
+use crate::jni_utils::{JavaObject, JniValue, get_env};
+
+/// The world border of an instance or of a single player.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorldBorder {
+    pub center_x: f64,
+    pub center_z: f64,
+    pub diameter: f64,
+    /// Distance in blocks from the border at which the screen turns red
+    pub warning_distance: i32,
+    /// Time in seconds before a shrinking border reaches the player at which the screen turns red
+    pub warning_time: i32,
+}
+
+impl Default for WorldBorder {
+    /// Same values as vanilla: centered at 0, 0 and practically infinite.
+    fn default() -> Self {
+        Self {
+            center_x: 0.0,
+            center_z: 0.0,
+            diameter: 59_999_968.0,
+            warning_distance: 5,
+            warning_time: 15,
+        }
+    }
+}
+
+impl WorldBorder {
+    pub fn new(center_x: f64, center_z: f64, diameter: f64) -> Self {
+        Self {
+            center_x,
+            center_z,
+            diameter,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_center(mut self, center_x: f64, center_z: f64) -> Self {
+        self.center_x = center_x;
+        self.center_z = center_z;
+        self
+    }
+
+    pub fn with_diameter(mut self, diameter: f64) -> Self {
+        self.diameter = diameter;
+        self
+    }
+
+    pub fn with_warning_distance(mut self, warning_distance: i32) -> Self {
+        self.warning_distance = warning_distance;
+        self
+    }
+
+    pub fn with_warning_time(mut self, warning_time: i32) -> Self {
+        self.warning_time = warning_time;
+        self
+    }
+
+    /// Returns true if the given coordinates are inside the border.
+    pub fn contains(&self, x: f64, z: f64) -> bool {
+        let radius = self.diameter / 2.0;
+        (x - self.center_x).abs() <= radius && (z - self.center_z).abs() <= radius
+    }
+
+    pub(crate) fn from_java(border: &JavaObject) -> Result<Self> {
+        Ok(Self {
+            center_x: border.call_double_method("centerX", "()D", &[])?,
+            center_z: border.call_double_method("centerZ", "()D", &[])?,
+            diameter: border.call_double_method("diameter", "()D", &[])?,
+            warning_distance: border.call_int_method("warningDistance", "()I", &[])?,
+            warning_time: border.call_int_method("warningTime", "()I", &[])?,
+        })
+    }
+
+    /// Builds the Java `WorldBorder` record, starting from `WorldBorder.DEFAULT_BORDER`
+    /// so the dimension teleport boundary keeps its default value.
+    pub(crate) fn to_java(&self) -> Result<JavaObject> {
+        let mut env = get_env()?;
+        let default_border = env
+            .get_static_field(
+                "net/minestom/server/instance/WorldBorder",
+                "DEFAULT_BORDER",
+                "Lnet/minestom/server/instance/WorldBorder;",
+            )?
+            .l()?;
+        let border = JavaObject::from_env(&mut env, default_border)?;
+
+        let border = border.call_object_method(
+            "withCenter",
+            "(DD)Lnet/minestom/server/instance/WorldBorder;",
+            &[
+                JniValue::Double(self.center_x),
+                JniValue::Double(self.center_z),
+            ],
+        )?;
+        let border = border.call_object_method(
+            "withDiameter",
+            "(D)Lnet/minestom/server/instance/WorldBorder;",
+            &[JniValue::Double(self.diameter)],
+        )?;
+        let border = border.call_object_method(
+            "withWarningDistance",
+            "(I)Lnet/minestom/server/instance/WorldBorder;",
+            &[JniValue::Int(self.warning_distance)],
+        )?;
+        border.call_object_method(
+            "withWarningTime",
+            "(I)Lnet/minestom/server/instance/WorldBorder;",
+            &[JniValue::Int(self.warning_time)],
+        )
+    }
+}
+
+/// Packet initializing the world border of a single player,
+/// independently from the border of the instance they are in.
+pub struct WorldBorderPacket {
+    pub border: WorldBorder,
+    /// Diameter the border moves to, starting from `border.diameter`
+    pub target_diameter: f64,
+    /// Duration of the transition to `target_diameter`
+    pub transition: Duration,
+}
+
+impl WorldBorderPacket {
+    pub fn new(border: WorldBorder) -> Self {
+        let target_diameter = border.diameter;
+        Self {
+            border,
+            target_diameter,
+            transition: Duration::ZERO,
+        }
+    }
+
+    pub fn transition(border: WorldBorder, target_diameter: f64, transition: Duration) -> Self {
+        Self {
+            border,
+            target_diameter,
+            transition,
+        }
+    }
+}
+
+impl SendablePacket for WorldBorderPacket {
+    fn to_java(&self) -> JObject<'_> {
+        let border = self
+            .border
+            .to_java()
+            .expect("Failed to construct Java WorldBorder");
+        let mut env = get_env().unwrap();
+        let border_obj = border.as_obj().expect("Failed to get Java WorldBorder");
+        env.call_method(
+            &border_obj,
+            "createInitializePacket",
+            "(DJ)Lnet/minestom/server/network/packet/server/play/InitializeWorldBorderPacket;",
+            &[
+                JValue::Double(self.target_diameter),
+                JValue::Long(self.transition.as_millis() as i64),
+            ],
+        )
+        .and_then(|packet| packet.l())
+        .expect("Failed to construct Java InitializeWorldBorderPacket")
+    }
+}